@@ -0,0 +1,2046 @@
+//! One builder per program instruction.
+//!
+//! Builders take the keys the caller has to choose and derive everything
+//! else. Optional accounts the program only keeps in sync, like the game's
+//! LiveState, are opted into with a flag.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use clicker::{accounts, instruction};
+use clicker::{
+    ArchivedSession, ChallengeRules, ConfigUpdate, GemPurchase, OfferReward, PassPayment,
+    PassSeason, PassTier, PostPayment, QuestCondition, Recipe, RecipeInput, SeasonModifiers,
+    SessionResult, SkillEffect, VoidReason,
+};
+
+use crate::pda;
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: clicker::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn session_index(game: &Pubkey, enabled: bool) -> Option<Pubkey> {
+    enabled.then(|| pda::session_index(game))
+}
+
+// Guardians co-sign recovery and unfreezing as remaining accounts
+fn with_signers(mut ix: Instruction, signers: &[Pubkey]) -> Instruction {
+    ix.accounts.extend(
+        signers
+            .iter()
+            .map(|signer| AccountMeta::new_readonly(*signer, true)),
+    );
+    ix
+}
+
+// Games and sessions
+
+/// `game` is a fresh keypair that must also sign. `payer` funds the rent
+/// and may be the player.
+pub fn initialize(game: Pubkey, player: Pubkey, payer: Pubkey) -> Instruction {
+    build(
+        accounts::Initialize {
+            game,
+            live_state: pda::live_state(&game),
+            player,
+            payer,
+            system_program: system_program::ID,
+        },
+        instruction::Initialize {},
+    )
+}
+
+/// As initialize, for a hardcore game.
+pub fn initialize_hardcore(game: Pubkey, player: Pubkey, payer: Pubkey) -> Instruction {
+    build(
+        accounts::Initialize {
+            game,
+            live_state: pda::live_state(&game),
+            player,
+            payer,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeHardcore {},
+    )
+}
+
+pub fn migrate_game(game: Pubkey, payer: Pubkey) -> Instruction {
+    build(
+        accounts::MigrateGame {
+            game,
+            payer,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateGame {},
+    )
+}
+
+pub fn click(game: Pubkey, player: Pubkey) -> Instruction {
+    build(
+        accounts::Play {
+            game,
+            player,
+            live_state: pda::live_state(&game),
+        },
+        instruction::Click {},
+    )
+}
+
+/// `session` is a fresh keypair that must also sign. When `challenge` is
+/// given, the attestor's Ed25519 instruction has to come right before this one.
+/// `indexed` passes the game's SessionIndex, which it must once it has one.
+/// `weekly_challenge_id` enters the session in a challenge the game joined.
+#[allow(clippy::too_many_arguments)]
+pub fn start_session(
+    game: Pubkey,
+    session: Pubkey,
+    player: Pubkey,
+    payer: Pubkey,
+    commitment: [u8; 32],
+    challenge: Option<[u8; 32]>,
+    indexed: bool,
+    weekly_challenge_id: Option<u32>,
+) -> Instruction {
+    build(
+        accounts::StartSession {
+            game,
+            session,
+            player,
+            payer,
+            config: pda::config(),
+            instructions: sysvar_instructions::ID,
+            system_program: system_program::ID,
+            live_state: pda::live_state(&game),
+            session_index: session_index(&game, indexed),
+            weekly_challenge: weekly_challenge_id.map(pda::weekly_challenge),
+            challenge_entry: weekly_challenge_id.map(|challenge_id| {
+                pda::challenge_entry(&pda::weekly_challenge(challenge_id), &game)
+            }),
+        },
+        instruction::StartSession {
+            commitment,
+            challenge,
+        },
+    )
+}
+
+/// As start_session, for a session lasting exactly the config's time attack
+/// window.
+#[allow(clippy::too_many_arguments)]
+pub fn start_time_attack(
+    game: Pubkey,
+    session: Pubkey,
+    player: Pubkey,
+    payer: Pubkey,
+    commitment: [u8; 32],
+    challenge: Option<[u8; 32]>,
+    indexed: bool,
+    weekly_challenge_id: Option<u32>,
+) -> Instruction {
+    build(
+        accounts::StartSession {
+            game,
+            session,
+            player,
+            payer,
+            config: pda::config(),
+            instructions: sysvar_instructions::ID,
+            system_program: system_program::ID,
+            live_state: pda::live_state(&game),
+            session_index: session_index(&game, indexed),
+            weekly_challenge: weekly_challenge_id.map(pda::weekly_challenge),
+            challenge_entry: weekly_challenge_id.map(|challenge_id| {
+                pda::challenge_entry(&pda::weekly_challenge(challenge_id), &game)
+            }),
+        },
+        instruction::StartTimeAttack {
+            commitment,
+            challenge,
+        },
+    )
+}
+
+/// Starts a session for each of two games. `host_session` and
+/// `guest_session` are fresh keypairs that must also sign, as do both players.
+#[allow(clippy::too_many_arguments)]
+pub fn start_coop_session(
+    host_game: Pubkey,
+    guest_game: Pubkey,
+    host_session: Pubkey,
+    guest_session: Pubkey,
+    host: Pubkey,
+    guest: Pubkey,
+    payer: Pubkey,
+    host_commitment: [u8; 32],
+    guest_commitment: [u8; 32],
+    host_indexed: bool,
+    guest_indexed: bool,
+) -> Instruction {
+    build(
+        accounts::StartCoopSession {
+            host_game,
+            guest_game,
+            host_session,
+            guest_session,
+            host,
+            guest,
+            payer,
+            config: pda::config(),
+            coop_session: pda::coop_session(&host_session),
+            system_program: system_program::ID,
+            host_session_index: session_index(&host_game, host_indexed),
+            guest_session_index: session_index(&guest_game, guest_indexed),
+            host_live_state: pda::live_state(&host_game),
+            guest_live_state: pda::live_state(&guest_game),
+        },
+        instruction::StartCoopSession {
+            host_commitment,
+            guest_commitment,
+        },
+    )
+}
+
+/// As start_session, for an endurance session.
+#[allow(clippy::too_many_arguments)]
+pub fn start_endurance(
+    game: Pubkey,
+    session: Pubkey,
+    player: Pubkey,
+    payer: Pubkey,
+    commitment: [u8; 32],
+    challenge: Option<[u8; 32]>,
+    indexed: bool,
+    weekly_challenge_id: Option<u32>,
+) -> Instruction {
+    build(
+        accounts::StartSession {
+            game,
+            session,
+            player,
+            payer,
+            config: pda::config(),
+            instructions: sysvar_instructions::ID,
+            system_program: system_program::ID,
+            live_state: pda::live_state(&game),
+            session_index: session_index(&game, indexed),
+            weekly_challenge: weekly_challenge_id.map(pda::weekly_challenge),
+            challenge_entry: weekly_challenge_id.map(|challenge_id| {
+                pda::challenge_entry(&pda::weekly_challenge(challenge_id), &game)
+            }),
+        },
+        instruction::StartEndurance {
+            commitment,
+            challenge,
+        },
+    )
+}
+
+/// The optional accounts `end_session` accepts.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EndSessionOptions {
+    /// Raffle to credit tickets to. Required while raffles are enabled.
+    pub raffle: Option<Pubkey>,
+    /// Registered device co-signing a large reveal. Must also sign.
+    pub device: Option<Pubkey>,
+    /// Append the session to the archive. Required once archiving is enabled.
+    pub archive: bool,
+    /// Compete for the world record.
+    pub world_record: bool,
+    /// Game currently holding the world record. Required when this reveal
+    /// takes the record from it.
+    pub previous_record_holder: Option<Pubkey>,
+    /// The game is a mentee; credits the mentorship bonus.
+    pub mentorship: bool,
+    /// Id of a world boss the game has joined, to damage it.
+    pub world_boss: Option<u32>,
+    /// Host session of the co-op run this session belongs to. Required for
+    /// co-op sessions.
+    pub coop_host_session: Option<Pubkey>,
+    /// Id of a golden window the session overlaps, for its bonus.
+    pub golden_window: Option<u32>,
+    /// The game's `partner_collection`. The partner multiplier only applies
+    /// when it's passed.
+    pub partner_collection: Option<Pubkey>,
+    /// Id of the season in `Config.modifier_season`. Required while one is
+    /// set.
+    pub modifier_season: Option<u32>,
+    /// Id of the weekly challenge the session entered. Required for those
+    /// sessions.
+    pub weekly_challenge: Option<u32>,
+}
+
+/// With `Config::REVEAL_GUARD_ENABLED`, revealing a session started with a
+/// challenge needs the attestor's Ed25519 instruction right before this one,
+/// signing (session || revealed hash).
+pub fn end_session(
+    game: Pubkey,
+    session: Pubkey,
+    player: Pubkey,
+    result: SessionResult,
+    nonce: u64,
+    max_session_duration: i64,
+    options: EndSessionOptions,
+) -> Instruction {
+    build(
+        accounts::EndSession {
+            game,
+            session,
+            player,
+            config: pda::config(),
+            global_stats: pda::global_stats(),
+            raffle: options.raffle,
+            device: options.device,
+            session_archive: options.archive.then(pda::session_archive),
+            live_state: pda::live_state(&game),
+            world_record: options.world_record.then(pda::world_record),
+            previous_record_holder: options.previous_record_holder,
+            mentorship: options.mentorship.then(|| pda::mentorship(&game)),
+            world_boss: options.world_boss.map(pda::world_boss),
+            boss_contribution: options
+                .world_boss
+                .map(|boss_id| pda::boss_contribution(&pda::world_boss(boss_id), &game)),
+            coop_session: options
+                .coop_host_session
+                .map(|host_session| pda::coop_session(&host_session)),
+            golden_window: options.golden_window.map(pda::golden_window),
+            partner_collection: options
+                .partner_collection
+                .map(|collection| pda::partner_collection(&collection)),
+            season: options.modifier_season.map(pda::pass_season),
+            weekly_challenge: options.weekly_challenge.map(pda::weekly_challenge),
+            challenge_entry: options.weekly_challenge.map(|challenge_id| {
+                pda::challenge_entry(&pda::weekly_challenge(challenge_id), &game)
+            }),
+            // Always passed: whether the reveal is guarded depends on the config
+            instructions: Some(sysvar_instructions::ID),
+            event_authority: pda::event_authority(),
+            program: clicker::ID,
+        },
+        instruction::EndSession {
+            result,
+            nonce,
+            max_session_duration,
+        },
+    )
+}
+
+pub fn cancel_session(game: Pubkey, session: Pubkey, player: Pubkey) -> Instruction {
+    build(
+        accounts::CancelSession {
+            game,
+            session,
+            player,
+            live_state: pda::live_state(&game),
+            event_authority: pda::event_authority(),
+            program: clicker::ID,
+        },
+        instruction::CancelSession {},
+    )
+}
+
+/// `authority` is the player or the rent payer. `raffle` is the session's
+/// raffle, needed only when the session holds tickets.
+pub fn close_session(
+    session: Pubkey,
+    rent_payer: Pubkey,
+    authority: Pubkey,
+    raffle: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::CloseSession {
+            session,
+            rent_payer,
+            authority,
+            raffle,
+        },
+        instruction::CloseSession {},
+    )
+}
+
+pub fn extend_session(
+    game: Pubkey,
+    session: Pubkey,
+    player: Pubkey,
+    extra_seconds: i64,
+) -> Instruction {
+    build(
+        accounts::ExtendSession {
+            game,
+            session,
+            player,
+            config: pda::config(),
+        },
+        instruction::ExtendSession { extra_seconds },
+    )
+}
+
+/// `confirmation` is the text the player typed; the program only accepts
+/// `Game::SOFT_RESET_CONFIRMATION`.
+pub fn soft_reset_game(game: Pubkey, player: Pubkey, confirmation: &str) -> Instruction {
+    build(
+        accounts::SoftResetGame {
+            game,
+            player,
+            live_state: pda::live_state(&game),
+            event_authority: pda::event_authority(),
+            program: clicker::ID,
+        },
+        instruction::SoftResetGame {
+            confirmation: confirmation.to_string(),
+        },
+    )
+}
+
+pub fn admin_void_session(
+    admin: Pubkey,
+    game: Pubkey,
+    session: Pubkey,
+    reason: VoidReason,
+) -> Instruction {
+    build(
+        accounts::AdminVoidSession {
+            config: pda::config(),
+            admin,
+            game,
+            session,
+            live_state: pda::live_state(&game),
+            event_authority: pda::event_authority(),
+            program: clicker::ID,
+        },
+        instruction::AdminVoidSession { reason },
+    )
+}
+
+/// `session` is the game's active session, which may no longer exist.
+pub fn admin_repair_game(admin: Pubkey, game: Pubkey, session: Pubkey) -> Instruction {
+    build(
+        accounts::AdminRepairGame {
+            config: pda::config(),
+            admin,
+            game,
+            session,
+            live_state: pda::live_state(&game),
+            event_authority: pda::event_authority(),
+            program: clicker::ID,
+        },
+        instruction::AdminRepairGame {},
+    )
+}
+
+pub fn pause_session(session: Pubkey, player: Pubkey) -> Instruction {
+    build(
+        accounts::PauseSession { session, player },
+        instruction::PauseSession {},
+    )
+}
+
+pub fn resume_session(session: Pubkey, player: Pubkey) -> Instruction {
+    build(
+        accounts::PauseSession { session, player },
+        instruction::ResumeSession {},
+    )
+}
+
+/// Backfills the LiveState of a game created before initialize made one.
+pub fn initialize_live_state(game: Pubkey, payer: Pubkey) -> Instruction {
+    build(
+        accounts::InitializeLiveState {
+            game,
+            live_state: pda::live_state(&game),
+            payer,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeLiveState {},
+    )
+}
+
+pub fn initialize_session_index(game: Pubkey, player: Pubkey, payer: Pubkey) -> Instruction {
+    build(
+        accounts::InitializeSessionIndex {
+            game,
+            player,
+            session_index: pda::session_index(&game),
+            payer,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeSessionIndex {},
+    )
+}
+
+pub fn gamble_session(game: Pubkey, session: Pubkey, player: Pubkey) -> Instruction {
+    build(
+        accounts::GambleSession {
+            game,
+            player,
+            session,
+            config: pda::config(),
+            live_state: pda::live_state(&game),
+        },
+        instruction::GambleSession {},
+    )
+}
+
+/// The oracle's Ed25519 instruction over (session || b"gamble" || the
+/// session's `gamble_slot` as u64 LE) has to come right before this one.
+pub fn settle_gamble(game: Pubkey, session: Pubkey) -> Instruction {
+    build(
+        accounts::SettleGamble {
+            game,
+            session,
+            config: pda::config(),
+            instructions: sysvar_instructions::ID,
+            live_state: pda::live_state(&game),
+        },
+        instruction::SettleGamble {},
+    )
+}
+
+// Devices, guardians and freezing
+
+pub fn register_device(game: Pubkey, player: Pubkey, device: Pubkey) -> Instruction {
+    build(
+        accounts::ManageDevice {
+            game,
+            player,
+            config: pda::config(),
+        },
+        instruction::RegisterDevice { device },
+    )
+}
+
+pub fn revoke_device(game: Pubkey, player: Pubkey, device: Pubkey) -> Instruction {
+    build(
+        accounts::ManageDevice {
+            game,
+            player,
+            config: pda::config(),
+        },
+        instruction::RevokeDevice { device },
+    )
+}
+
+pub fn set_guardians(
+    game: Pubkey,
+    player: Pubkey,
+    guardians: [Pubkey; 3],
+    threshold: u8,
+) -> Instruction {
+    build(
+        accounts::SetGuardians {
+            game,
+            player,
+            config: pda::config(),
+        },
+        instruction::SetGuardians {
+            guardians,
+            threshold,
+        },
+    )
+}
+
+/// Every key in `guardians` must sign.
+pub fn recover_game(game: Pubkey, new_owner: Pubkey, guardians: &[Pubkey]) -> Instruction {
+    with_signers(
+        build(
+            accounts::RecoverGame {
+                game,
+                config: pda::config(),
+            },
+            instruction::RecoverGame { new_owner },
+        ),
+        guardians,
+    )
+}
+
+pub fn cancel_recovery(game: Pubkey, player: Pubkey) -> Instruction {
+    build(
+        accounts::CancelRecovery { game, player },
+        instruction::CancelRecovery {},
+    )
+}
+
+/// `active_session` is the game's active session, if it has one.
+pub fn finalize_recovery(game: Pubkey, active_session: Option<Pubkey>) -> Instruction {
+    build(
+        accounts::FinalizeRecovery {
+            game,
+            session: active_session,
+            live_state: pda::live_state(&game),
+            event_authority: pda::event_authority(),
+            program: clicker::ID,
+        },
+        instruction::FinalizeRecovery {},
+    )
+}
+
+/// `hash` is the salted hash of the off-chain identity; the salt stays with
+/// the operator.
+pub fn bind_identity(game: Pubkey, player: Pubkey, hash: [u8; 32]) -> Instruction {
+    build(
+        accounts::ManageIdentity { game, player },
+        instruction::BindIdentity { hash },
+    )
+}
+
+pub fn unbind_identity(game: Pubkey, player: Pubkey) -> Instruction {
+    build(
+        accounts::ManageIdentity { game, player },
+        instruction::UnbindIdentity {},
+    )
+}
+
+pub fn freeze_game(game: Pubkey, player: Pubkey, unfreeze_requires_guardian: bool) -> Instruction {
+    build(
+        accounts::FreezeGame {
+            game,
+            player,
+            config: pda::config(),
+        },
+        instruction::FreezeGame {
+            unfreeze_requires_guardian,
+        },
+    )
+}
+
+/// Pass a signing guardian if the game was frozen with
+/// `unfreeze_requires_guardian`.
+pub fn unfreeze_game(game: Pubkey, player: Pubkey, guardians: &[Pubkey]) -> Instruction {
+    with_signers(
+        build(
+            accounts::FreezeGame {
+                game,
+                player,
+                config: pda::config(),
+            },
+            instruction::UnfreezeGame {},
+        ),
+        guardians,
+    )
+}
+
+// Snapshots
+
+pub fn export_snapshot(game: Pubkey, player: Pubkey, payer: Pubkey) -> Instruction {
+    build(
+        accounts::ExportSnapshot {
+            game,
+            snapshot: pda::snapshot(&game),
+            player,
+            payer,
+            config: pda::config(),
+            system_program: system_program::ID,
+        },
+        instruction::ExportSnapshot {},
+    )
+}
+
+pub fn read_snapshot(game: Pubkey) -> Instruction {
+    build(
+        accounts::ReadSnapshot {
+            snapshot: pda::snapshot(&game),
+        },
+        instruction::ReadSnapshot {},
+    )
+}
+
+// Partners and quests
+
+pub fn add_partner_collection(
+    admin: Pubkey,
+    collection: Pubkey,
+    multiplier_bps: u16,
+) -> Instruction {
+    build(
+        accounts::AddPartnerCollection {
+            config: pda::config(),
+            partner_collection: pda::partner_collection(&collection),
+            admin,
+            system_program: system_program::ID,
+        },
+        instruction::AddPartnerCollection {
+            collection,
+            multiplier_bps,
+        },
+    )
+}
+
+pub fn update_partner_collection(
+    admin: Pubkey,
+    collection: Pubkey,
+    multiplier_bps: u16,
+    active: bool,
+) -> Instruction {
+    build(
+        accounts::UpdatePartnerCollection {
+            config: pda::config(),
+            partner_collection: pda::partner_collection(&collection),
+            admin,
+        },
+        instruction::UpdatePartnerCollection {
+            multiplier_bps,
+            active,
+        },
+    )
+}
+
+/// `token_account` is the player's account holding `mint`.
+pub fn verify_partner_nft(
+    game: Pubkey,
+    player: Pubkey,
+    collection: Pubkey,
+    mint: Pubkey,
+    token_account: Pubkey,
+) -> Instruction {
+    build(
+        accounts::VerifyPartnerNft {
+            game,
+            player,
+            config: pda::config(),
+            partner_collection: pda::partner_collection(&collection),
+            partner_nft: pda::partner_nft(&mint),
+            token_account,
+            metadata: pda::metadata(&mint),
+            live_state: pda::live_state(&game),
+            system_program: system_program::ID,
+        },
+        instruction::VerifyPartnerNft { mint },
+    )
+}
+
+// Trophy cabinet
+
+pub fn initialize_trophy_cabinet(game: Pubkey, player: Pubkey, payer: Pubkey) -> Instruction {
+    build(
+        accounts::InitializeTrophyCabinet {
+            game,
+            player,
+            trophy_cabinet: pda::trophy_cabinet(&game),
+            payer,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeTrophyCabinet {},
+    )
+}
+
+/// `token_account` is the player's account holding `mint`.
+pub fn pin_trophy(
+    game: Pubkey,
+    player: Pubkey,
+    slot: u8,
+    mint: Pubkey,
+    token_account: Pubkey,
+) -> Instruction {
+    build(
+        accounts::PinTrophy {
+            game,
+            player,
+            trophy_cabinet: pda::trophy_cabinet(&game),
+            config: pda::config(),
+            token_account,
+            metadata: pda::metadata(&mint),
+        },
+        instruction::PinTrophy { slot, mint },
+    )
+}
+
+pub fn unpin_trophy(game: Pubkey, player: Pubkey, slot: u8) -> Instruction {
+    build(
+        accounts::UnpinTrophy {
+            game,
+            player,
+            trophy_cabinet: pda::trophy_cabinet(&game),
+        },
+        instruction::UnpinTrophy { slot },
+    )
+}
+
+/// `token_account` is the player's account holding `mint`.
+pub fn reverify_trophy(game: Pubkey, mint: Pubkey, token_account: Pubkey) -> Instruction {
+    build(
+        accounts::ReverifyTrophy {
+            game,
+            trophy_cabinet: pda::trophy_cabinet(&game),
+            config: pda::config(),
+            token_account,
+            metadata: pda::metadata(&mint),
+        },
+        instruction::ReverifyTrophy { mint },
+    )
+}
+
+pub fn add_partner_quest(
+    admin: Pubkey,
+    quest_id: u32,
+    condition: QuestCondition,
+    reward_clicks: u64,
+    min_level: u32,
+) -> Instruction {
+    build(
+        accounts::AddPartnerQuest {
+            config: pda::config(),
+            quest: pda::partner_quest(quest_id),
+            admin,
+            system_program: system_program::ID,
+        },
+        instruction::AddPartnerQuest {
+            quest_id,
+            condition,
+            reward_clicks,
+            min_level,
+        },
+    )
+}
+
+pub fn set_partner_quest_active(admin: Pubkey, quest_id: u32, active: bool) -> Instruction {
+    build(
+        accounts::SetPartnerQuestActive {
+            config: pda::config(),
+            quest: pda::partner_quest(quest_id),
+            admin,
+        },
+        instruction::SetPartnerQuestActive { active },
+    )
+}
+
+/// `evidence` is the account the quest's condition is checked against.
+/// `receive_items` passes the game's inventory, needed when the config
+/// grants lootboxes per quest or the quest finishes a chain. `chain_id` is
+/// required when the quest is a chain step.
+#[allow(clippy::too_many_arguments)]
+pub fn complete_partner_quest(
+    game: Pubkey,
+    player: Pubkey,
+    payer: Pubkey,
+    quest_id: u32,
+    evidence: Pubkey,
+    receive_items: bool,
+    chain_id: Option<u32>,
+) -> Instruction {
+    let quest = pda::partner_quest(quest_id);
+    let chain = chain_id.map(pda::quest_chain);
+    build(
+        accounts::CompletePartnerQuest {
+            game,
+            player,
+            payer,
+            config: pda::config(),
+            quest,
+            completion: pda::quest_completion(&quest, &game),
+            evidence,
+            system_program: system_program::ID,
+            live_state: pda::live_state(&game),
+            inventory: receive_items.then(|| pda::inventory(&game)),
+            quest_chain: chain,
+            chain_progress: chain.map(|chain| pda::quest_chain_progress(&chain, &game)),
+        },
+        instruction::CompletePartnerQuest { quest_id },
+    )
+}
+
+pub fn create_quest_chain(
+    admin: Pubkey,
+    chain_id: u32,
+    reward_item_id: u32,
+    reward_quantity: u32,
+) -> Instruction {
+    build(
+        accounts::CreateQuestChain {
+            config: pda::config(),
+            chain: pda::quest_chain(chain_id),
+            admin,
+            system_program: system_program::ID,
+        },
+        instruction::CreateQuestChain {
+            chain_id,
+            reward_item_id,
+            reward_quantity,
+        },
+    )
+}
+
+pub fn append_chain_quest(admin: Pubkey, chain_id: u32, quest_id: u32) -> Instruction {
+    build(
+        accounts::AppendChainQuest {
+            config: pda::config(),
+            chain: pda::quest_chain(chain_id),
+            quest: pda::partner_quest(quest_id),
+            admin,
+        },
+        instruction::AppendChainQuest { chain_id, quest_id },
+    )
+}
+
+pub fn start_quest_chain(
+    game: Pubkey,
+    player: Pubkey,
+    payer: Pubkey,
+    chain_id: u32,
+) -> Instruction {
+    let chain = pda::quest_chain(chain_id);
+    build(
+        accounts::StartQuestChain {
+            game,
+            player,
+            payer,
+            config: pda::config(),
+            chain,
+            progress: pda::quest_chain_progress(&chain, &game),
+            system_program: system_program::ID,
+        },
+        instruction::StartQuestChain { chain_id },
+    )
+}
+
+// Offers
+
+pub fn add_offer(
+    admin: Pubkey,
+    offer_id: u32,
+    reward: OfferReward,
+    max_redemptions: u32,
+) -> Instruction {
+    build(
+        accounts::AddOffer {
+            config: pda::config(),
+            offer: pda::offer(offer_id),
+            admin,
+            system_program: system_program::ID,
+        },
+        instruction::AddOffer {
+            offer_id,
+            reward,
+            max_redemptions,
+        },
+    )
+}
+
+pub fn set_offer_active(admin: Pubkey, offer_id: u32, active: bool) -> Instruction {
+    build(
+        accounts::SetOfferActive {
+            config: pda::config(),
+            offer: pda::offer(offer_id),
+            admin,
+        },
+        instruction::SetOfferActive { active },
+    )
+}
+
+/// The offerwall oracle's Ed25519 instruction over
+/// `Offer::attestation_message(offer_id, &game)` has to come right before
+/// this one, carrying `signature`.
+pub fn redeem_offer(
+    game: Pubkey,
+    player: Pubkey,
+    payer: Pubkey,
+    offer_id: u32,
+    signature: [u8; 64],
+) -> Instruction {
+    let offer = pda::offer(offer_id);
+    build(
+        accounts::RedeemOffer {
+            game,
+            player,
+            payer,
+            config: pda::config(),
+            offer,
+            redemption: pda::offer_redemption(&offer, &game),
+            instructions: sysvar_instructions::ID,
+            system_program: system_program::ID,
+        },
+        instruction::RedeemOffer {
+            offer_id,
+            signature,
+        },
+    )
+}
+
+// Mentorship
+
+pub fn become_mentor(game: Pubkey, player: Pubkey) -> Instruction {
+    build(
+        accounts::BecomeMentor {
+            game,
+            player,
+            config: pda::config(),
+        },
+        instruction::BecomeMentor {},
+    )
+}
+
+/// Must be signed by both players.
+pub fn accept_mentee(
+    mentor_game: Pubkey,
+    mentor: Pubkey,
+    mentee_game: Pubkey,
+    mentee: Pubkey,
+    payer: Pubkey,
+) -> Instruction {
+    build(
+        accounts::AcceptMentee {
+            config: pda::config(),
+            mentor_game,
+            mentor,
+            mentee_game,
+            mentee,
+            payer,
+            mentorship: pda::mentorship(&mentee_game),
+            system_program: system_program::ID,
+        },
+        instruction::AcceptMentee {},
+    )
+}
+
+/// `gateway_token` is the player's identity pass, required while
+/// `Config.gatekeeper_network` is set; the same goes for the other claims.
+pub fn claim_mentor_bonus(
+    mentor_game: Pubkey,
+    player: Pubkey,
+    mentee_game: Pubkey,
+    gateway_token: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::ClaimMentorBonus {
+            mentor_game,
+            player,
+            mentorship: pda::mentorship(&mentee_game),
+            config: pda::config(),
+            gateway_token,
+            live_state: pda::live_state(&mentor_game),
+        },
+        instruction::ClaimMentorBonus {},
+    )
+}
+
+// World bosses
+
+pub fn spawn_world_boss(admin: Pubkey, boss_id: u32, hp: u64, loot_clicks: u64) -> Instruction {
+    build(
+        accounts::SpawnWorldBoss {
+            config: pda::config(),
+            boss: pda::world_boss(boss_id),
+            admin,
+            system_program: system_program::ID,
+        },
+        instruction::SpawnWorldBoss {
+            boss_id,
+            hp,
+            loot_clicks,
+        },
+    )
+}
+
+pub fn despawn_world_boss(admin: Pubkey, boss_id: u32) -> Instruction {
+    build(
+        accounts::DespawnWorldBoss {
+            config: pda::config(),
+            boss: pda::world_boss(boss_id),
+            admin,
+        },
+        instruction::DespawnWorldBoss {},
+    )
+}
+
+pub fn initialize_raid_schedule(admin: Pubkey, first_boss_id: u32) -> Instruction {
+    build(
+        accounts::InitializeRaidSchedule {
+            config: pda::config(),
+            schedule: pda::raid_schedule(),
+            admin,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeRaidSchedule { first_boss_id },
+    )
+}
+
+pub fn set_raid_schedule(
+    admin: Pubkey,
+    spawn_hour: u8,
+    hp: u64,
+    loot_clicks: u64,
+    lifetime: i64,
+    enabled: bool,
+) -> Instruction {
+    build(
+        accounts::SetRaidSchedule {
+            config: pda::config(),
+            schedule: pda::raid_schedule(),
+            admin,
+        },
+        instruction::SetRaidSchedule {
+            spawn_hour,
+            hp,
+            loot_clicks,
+            lifetime,
+            enabled,
+        },
+    )
+}
+
+/// `next_boss_id` is the schedule's current `next_boss_id`.
+pub fn spawn_scheduled_boss(payer: Pubkey, next_boss_id: u32) -> Instruction {
+    build(
+        accounts::SpawnScheduledBoss {
+            config: pda::config(),
+            schedule: pda::raid_schedule(),
+            boss: pda::world_boss(next_boss_id),
+            payer,
+            system_program: system_program::ID,
+        },
+        instruction::SpawnScheduledBoss {},
+    )
+}
+
+pub fn join_world_boss(game: Pubkey, player: Pubkey, payer: Pubkey, boss_id: u32) -> Instruction {
+    let boss = pda::world_boss(boss_id);
+    build(
+        accounts::JoinWorldBoss {
+            game,
+            player,
+            payer,
+            config: pda::config(),
+            boss,
+            contribution: pda::boss_contribution(&boss, &game),
+            system_program: system_program::ID,
+        },
+        instruction::JoinWorldBoss { boss_id },
+    )
+}
+
+/// `receive_items` as for complete_partner_quest, for lootboxes per boss.
+pub fn claim_boss_loot(
+    game: Pubkey,
+    player: Pubkey,
+    boss_id: u32,
+    receive_items: bool,
+    gateway_token: Option<Pubkey>,
+) -> Instruction {
+    let boss = pda::world_boss(boss_id);
+    build(
+        accounts::ClaimBossLoot {
+            game,
+            player,
+            config: pda::config(),
+            boss,
+            contribution: pda::boss_contribution(&boss, &game),
+            live_state: pda::live_state(&game),
+            inventory: receive_items.then(|| pda::inventory(&game)),
+            gateway_token,
+        },
+        instruction::ClaimBossLoot { boss_id },
+    )
+}
+
+// Golden windows
+
+pub fn schedule_golden_window(
+    admin: Pubkey,
+    window_id: u32,
+    start_time: i64,
+    end_time: i64,
+    multiplier_bps: u16,
+) -> Instruction {
+    build(
+        accounts::ScheduleGoldenWindow {
+            config: pda::config(),
+            window: pda::golden_window(window_id),
+            admin,
+            system_program: system_program::ID,
+        },
+        instruction::ScheduleGoldenWindow {
+            window_id,
+            start_time,
+            end_time,
+            multiplier_bps,
+        },
+    )
+}
+
+/// `scheduled_by` is the admin that paid the rent. `authority` is the admin,
+/// or anyone once the window has ended.
+pub fn close_golden_window(window_id: u32, scheduled_by: Pubkey, authority: Pubkey) -> Instruction {
+    build(
+        accounts::CloseGoldenWindow {
+            config: pda::config(),
+            window: pda::golden_window(window_id),
+            scheduled_by,
+            authority,
+        },
+        instruction::CloseGoldenWindow { window_id },
+    )
+}
+
+// Weekly challenges
+
+pub fn create_weekly_challenge(
+    admin: Pubkey,
+    challenge_id: u32,
+    starts_at: i64,
+    rules: ChallengeRules,
+) -> Instruction {
+    build(
+        accounts::CreateWeeklyChallenge {
+            config: pda::config(),
+            challenge: pda::weekly_challenge(challenge_id),
+            admin,
+            system_program: system_program::ID,
+        },
+        instruction::CreateWeeklyChallenge {
+            challenge_id,
+            starts_at,
+            rules,
+        },
+    )
+}
+
+pub fn join_weekly_challenge(
+    game: Pubkey,
+    player: Pubkey,
+    payer: Pubkey,
+    challenge_id: u32,
+) -> Instruction {
+    let challenge = pda::weekly_challenge(challenge_id);
+    build(
+        accounts::JoinWeeklyChallenge {
+            game,
+            player,
+            payer,
+            config: pda::config(),
+            challenge,
+            entry: pda::challenge_entry(&challenge, &game),
+            system_program: system_program::ID,
+        },
+        instruction::JoinWeeklyChallenge { challenge_id },
+    )
+}
+
+// Skills
+
+pub fn initialize_skill_tree(admin: Pubkey) -> Instruction {
+    build(
+        accounts::InitializeSkillTree {
+            config: pda::config(),
+            skill_tree: pda::skill_tree(),
+            admin,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeSkillTree {},
+    )
+}
+
+pub fn set_skill_node(
+    admin: Pubkey,
+    node_id: u8,
+    prerequisites: u64,
+    cost: u8,
+    effect: SkillEffect,
+    magnitude_bps: u16,
+) -> Instruction {
+    build(
+        accounts::SetSkillNode {
+            config: pda::config(),
+            skill_tree: pda::skill_tree(),
+            admin,
+        },
+        instruction::SetSkillNode {
+            node_id,
+            prerequisites,
+            cost,
+            effect,
+            magnitude_bps,
+        },
+    )
+}
+
+pub fn unlock_skill(game: Pubkey, player: Pubkey, node_id: u8) -> Instruction {
+    build(
+        accounts::UnlockSkill {
+            game,
+            player,
+            config: pda::config(),
+            skill_tree: pda::skill_tree(),
+        },
+        instruction::UnlockSkill { node_id },
+    )
+}
+
+pub fn respec_skills(game: Pubkey, player: Pubkey) -> Instruction {
+    build(
+        accounts::RespecSkills {
+            game,
+            player,
+            config: pda::config(),
+            live_state: pda::live_state(&game),
+        },
+        instruction::RespecSkills {},
+    )
+}
+
+// Gifts
+
+/// `expires_at` is at most `Gift::MAX_EXPIRY` away.
+pub fn offer_gift(
+    sender: Pubkey,
+    recipient: Pubkey,
+    player: Pubkey,
+    payer: Pubkey,
+    amount: u64,
+    expires_at: i64,
+) -> Instruction {
+    build(
+        accounts::OfferGift {
+            sender,
+            recipient,
+            player,
+            payer,
+            config: pda::config(),
+            gift: pda::gift(&sender, &recipient),
+            system_program: system_program::ID,
+        },
+        instruction::OfferGift { amount, expires_at },
+    )
+}
+
+/// `rent_payer` must be whoever paid for the gift; it gets the rent back.
+pub fn accept_gift(
+    sender: Pubkey,
+    recipient: Pubkey,
+    player: Pubkey,
+    rent_payer: Pubkey,
+) -> Instruction {
+    build(
+        accounts::AcceptGift {
+            recipient,
+            player,
+            gift: pda::gift(&sender, &recipient),
+            config: pda::config(),
+            rent_payer,
+        },
+        instruction::AcceptGift {},
+    )
+}
+
+pub fn reclaim_expired_gift(sender: Pubkey, recipient: Pubkey, rent_payer: Pubkey) -> Instruction {
+    build(
+        accounts::ReclaimExpiredGift {
+            sender,
+            gift: pda::gift(&sender, &recipient),
+            rent_payer,
+        },
+        instruction::ReclaimExpiredGift {},
+    )
+}
+
+// Gems
+
+pub fn initialize_treasury(admin: Pubkey) -> Instruction {
+    build(
+        accounts::InitializeTreasury {
+            config: pda::config(),
+            treasury: pda::treasury(),
+            admin,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeTreasury {},
+    )
+}
+
+/// `purchase_count` is the game's current `purchase_count`, which seeds the
+/// receipt this purchase creates.
+pub fn buy_gems(game: Pubkey, player: Pubkey, purchase_count: u64, gems: u64) -> Instruction {
+    build(
+        accounts::BuyGems {
+            game,
+            player,
+            config: pda::config(),
+            treasury: pda::treasury(),
+            receipt: pda::receipt(&game, purchase_count),
+            system_program: system_program::ID,
+        },
+        instruction::BuyGems { gems },
+    )
+}
+
+pub fn spend_gems(game: Pubkey, player: Pubkey, purchase: GemPurchase) -> Instruction {
+    build(
+        accounts::SpendGems {
+            game,
+            player,
+            config: pda::config(),
+        },
+        instruction::SpendGems { purchase },
+    )
+}
+
+/// `pass_season_id` is the season of the premium pass the receipt is for,
+/// `None` for a gem purchase.
+pub fn refund_purchase(
+    game: Pubkey,
+    player: Pubkey,
+    receipt: Pubkey,
+    pass_season_id: Option<u32>,
+) -> Instruction {
+    let season = pass_season_id.map(pda::pass_season);
+    build(
+        accounts::RefundPurchase {
+            game,
+            player,
+            config: pda::config(),
+            treasury: pda::treasury(),
+            receipt,
+            pass: season.map(|season| pda::season_pass(&season, &game)),
+            season,
+        },
+        instruction::RefundPurchase {},
+    )
+}
+
+// Inventory and lootboxes
+
+pub fn initialize_inventory(game: Pubkey, payer: Pubkey) -> Instruction {
+    build(
+        accounts::InitializeInventory {
+            game,
+            inventory: pda::inventory(&game),
+            payer,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeInventory {},
+    )
+}
+
+pub fn consume_item(game: Pubkey, player: Pubkey, item_id: u32, quantity: u32) -> Instruction {
+    build(
+        accounts::ConsumeItem {
+            game,
+            player,
+            inventory: pda::inventory(&game),
+        },
+        instruction::ConsumeItem { item_id, quantity },
+    )
+}
+
+pub fn set_accepts_transfers(game: Pubkey, player: Pubkey, accepts: bool) -> Instruction {
+    build(
+        accounts::SetAcceptsTransfers {
+            game,
+            player,
+            inventory: pda::inventory(&game),
+        },
+        instruction::SetAcceptsTransfers { accepts },
+    )
+}
+
+/// Moves `quantity` items from the stack in `slot` to `to_game`'s inventory.
+/// `to_game` has to accept transfers.
+pub fn transfer_item(
+    game: Pubkey,
+    player: Pubkey,
+    to_game: Pubkey,
+    slot: u8,
+    quantity: u32,
+) -> Instruction {
+    build(
+        accounts::TransferItem {
+            game,
+            player,
+            inventory: pda::inventory(&game),
+            to_inventory: pda::inventory(&to_game),
+        },
+        instruction::TransferItem { slot, quantity },
+    )
+}
+
+pub fn add_recipe(
+    admin: Pubkey,
+    recipe_id: u32,
+    inputs: [RecipeInput; Recipe::MAX_INPUTS],
+    output_item_id: u32,
+    output_quantity: u32,
+) -> Instruction {
+    build(
+        accounts::AddRecipe {
+            config: pda::config(),
+            recipe: pda::recipe(recipe_id),
+            admin,
+            system_program: system_program::ID,
+        },
+        instruction::AddRecipe {
+            recipe_id,
+            inputs,
+            output_item_id,
+            output_quantity,
+        },
+    )
+}
+
+pub fn set_recipe_active(admin: Pubkey, recipe_id: u32, active: bool) -> Instruction {
+    build(
+        accounts::SetRecipeActive {
+            config: pda::config(),
+            recipe: pda::recipe(recipe_id),
+            admin,
+        },
+        instruction::SetRecipeActive { active },
+    )
+}
+
+pub fn craft(game: Pubkey, player: Pubkey, recipe_id: u32) -> Instruction {
+    build(
+        accounts::Craft {
+            game,
+            player,
+            config: pda::config(),
+            inventory: pda::inventory(&game),
+            recipe: pda::recipe(recipe_id),
+        },
+        instruction::Craft { recipe_id },
+    )
+}
+
+pub fn open_lootbox(game: Pubkey, player: Pubkey) -> Instruction {
+    build(
+        accounts::OpenLootbox {
+            game,
+            player,
+            inventory: pda::inventory(&game),
+            config: pda::config(),
+        },
+        instruction::OpenLootbox {},
+    )
+}
+
+/// The oracle's Ed25519 instruction over (game || the game's
+/// `lootboxes_opened` as u64 LE || its `lootbox_request_slot` as u64 LE)
+/// has to come right before this one.
+pub fn settle_lootbox(game: Pubkey) -> Instruction {
+    build(
+        accounts::SettleLootbox {
+            game,
+            config: pda::config(),
+            instructions: sysvar_instructions::ID,
+            live_state: pda::live_state(&game),
+        },
+        instruction::SettleLootbox {},
+    )
+}
+
+// Marketplace
+
+/// `listing_index` is the game's current `listing_count`.
+pub fn list_item(
+    game: Pubkey,
+    player: Pubkey,
+    listing_index: u64,
+    slot: u8,
+    quantity: u32,
+    price: u64,
+) -> Instruction {
+    build(
+        accounts::ListItem {
+            game,
+            player,
+            config: pda::config(),
+            inventory: pda::inventory(&game),
+            listing: pda::listing(&game, listing_index),
+            system_program: system_program::ID,
+        },
+        instruction::ListItem {
+            slot,
+            quantity,
+            price,
+        },
+    )
+}
+
+pub fn buy_listing(game: Pubkey, player: Pubkey, listing: Pubkey, seller: Pubkey) -> Instruction {
+    build(
+        accounts::BuyListing {
+            game,
+            player,
+            config: pda::config(),
+            inventory: pda::inventory(&game),
+            listing,
+            seller,
+            treasury: pda::treasury(),
+            system_program: system_program::ID,
+        },
+        instruction::BuyListing {},
+    )
+}
+
+pub fn cancel_listing(
+    game: Pubkey,
+    player: Pubkey,
+    listing: Pubkey,
+    seller: Pubkey,
+) -> Instruction {
+    build(
+        accounts::CancelListing {
+            game,
+            player,
+            inventory: pda::inventory(&game),
+            listing,
+            seller,
+        },
+        instruction::CancelListing {},
+    )
+}
+
+// Season pass
+
+pub fn create_pass_season(
+    admin: Pubkey,
+    season_id: u32,
+    starts_at: i64,
+    ends_at: i64,
+    premium_gems: u64,
+    premium_lamports: u64,
+    tiers: [PassTier; PassSeason::MAX_TIERS],
+) -> Instruction {
+    build(
+        accounts::CreatePassSeason {
+            config: pda::config(),
+            season: pda::pass_season(season_id),
+            admin,
+            system_program: system_program::ID,
+        },
+        instruction::CreatePassSeason {
+            season_id,
+            starts_at,
+            ends_at,
+            premium_gems,
+            premium_lamports,
+            tiers,
+        },
+    )
+}
+
+pub fn set_season_modifiers(
+    admin: Pubkey,
+    season_id: u32,
+    modifiers: SeasonModifiers,
+) -> Instruction {
+    build(
+        accounts::SetSeasonModifiers {
+            config: pda::config(),
+            season: pda::pass_season(season_id),
+            admin,
+        },
+        instruction::SetSeasonModifiers {
+            season_id,
+            modifiers,
+        },
+    )
+}
+
+pub fn join_season_pass(
+    game: Pubkey,
+    player: Pubkey,
+    payer: Pubkey,
+    season_id: u32,
+) -> Instruction {
+    let season = pda::pass_season(season_id);
+    build(
+        accounts::JoinSeasonPass {
+            game,
+            player,
+            payer,
+            config: pda::config(),
+            season,
+            pass: pda::season_pass(&season, &game),
+            system_program: system_program::ID,
+        },
+        instruction::JoinSeasonPass { season_id },
+    )
+}
+
+/// `purchase_count` is the game's current `purchase_count`, which seeds the
+/// receipt a purchase with lamports creates.
+pub fn buy_premium_pass(
+    game: Pubkey,
+    player: Pubkey,
+    season_id: u32,
+    payment: PassPayment,
+    purchase_count: u64,
+) -> Instruction {
+    let season = pda::pass_season(season_id);
+    let receipt = match payment {
+        PassPayment::Gems => None,
+        PassPayment::Lamports => Some(pda::receipt(&game, purchase_count)),
+    };
+    build(
+        accounts::BuyPremiumPass {
+            game,
+            player,
+            config: pda::config(),
+            season,
+            pass: pda::season_pass(&season, &game),
+            treasury: pda::treasury(),
+            receipt,
+            system_program: system_program::ID,
+        },
+        instruction::BuyPremiumPass { season_id, payment },
+    )
+}
+
+pub fn claim_pass_reward(
+    game: Pubkey,
+    player: Pubkey,
+    season_id: u32,
+    tier: u8,
+    gateway_token: Option<Pubkey>,
+) -> Instruction {
+    let season = pda::pass_season(season_id);
+    build(
+        accounts::ClaimPassReward {
+            game,
+            player,
+            season,
+            pass: pda::season_pass(&season, &game),
+            config: pda::config(),
+            gateway_token,
+            live_state: pda::live_state(&game),
+        },
+        instruction::ClaimPassReward { season_id, tier },
+    )
+}
+
+pub fn create_shoutbox(
+    admin: Pubkey,
+    season_id: u32,
+    fee_clicks: u64,
+    fee_gems: u64,
+) -> Instruction {
+    let season = pda::pass_season(season_id);
+    build(
+        accounts::CreateShoutbox {
+            config: pda::config(),
+            season,
+            shoutbox: pda::shoutbox(&season),
+            admin,
+            system_program: system_program::ID,
+        },
+        instruction::CreateShoutbox {
+            season_id,
+            fee_clicks,
+            fee_gems,
+        },
+    )
+}
+
+pub fn post_message(
+    game: Pubkey,
+    player: Pubkey,
+    season_id: u32,
+    text: String,
+    payment: PostPayment,
+) -> Instruction {
+    let season = pda::pass_season(season_id);
+    build(
+        accounts::PostMessage {
+            game,
+            player,
+            config: pda::config(),
+            season,
+            shoutbox: pda::shoutbox(&season),
+        },
+        instruction::PostMessage {
+            season_id,
+            text,
+            payment,
+        },
+    )
+}
+
+// Session archive
+
+pub fn initialize_session_archive(admin: Pubkey) -> Instruction {
+    build(
+        accounts::InitializeSessionArchive {
+            config: pda::config(),
+            session_archive: pda::session_archive(),
+            admin,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeSessionArchive {},
+    )
+}
+
+pub fn initialize_world_record(admin: Pubkey) -> Instruction {
+    build(
+        accounts::InitializeWorldRecord {
+            config: pda::config(),
+            world_record: pda::world_record(),
+            admin,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeWorldRecord {},
+    )
+}
+
+pub fn verify_archived_session(
+    record: ArchivedSession,
+    index: u64,
+    proof: Vec<[u8; 32]>,
+) -> Instruction {
+    build(
+        accounts::VerifyArchivedSession {
+            session_archive: pda::session_archive(),
+        },
+        instruction::VerifyArchivedSession {
+            record,
+            index,
+            proof,
+        },
+    )
+}
+
+// Attestations
+
+pub fn initialize_attestation(game: Pubkey, payer: Pubkey) -> Instruction {
+    build(
+        accounts::InitializeAttestation {
+            game,
+            attestation: pda::attestation(&game),
+            payer,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeAttestation {},
+    )
+}
+
+/// Returns a `ClicksAttestation` as return data. `record` also writes it to
+/// the game's Attestation account, which must exist.
+pub fn attest_total_clicks(game: Pubkey, record: bool) -> Instruction {
+    build(
+        accounts::AttestTotalClicks {
+            game,
+            attestation: record.then(|| pda::attestation(&game)),
+        },
+        instruction::AttestTotalClicks {},
+    )
+}
+
+// Lookup table
+
+/// Accounts worth putting in the program's lookup table: those almost every
+/// transaction touches, plus the singletons behind the larger instructions.
+pub fn lookup_table_addresses() -> Vec<Pubkey> {
+    vec![
+        clicker::ID,
+        pda::config(),
+        pda::global_stats(),
+        pda::session_archive(),
+        pda::world_record(),
+        pda::event_authority(),
+        sysvar_instructions::ID,
+        system_program::ID,
+    ]
+}
+
+/// `recent_slot` must still be in the SlotHashes sysvar when this lands.
+pub fn create_lookup_table(admin: Pubkey, recent_slot: u64) -> Instruction {
+    build(
+        accounts::CreateLookupTable {
+            config: pda::config(),
+            admin,
+            lookup_table: pda::lookup_table(recent_slot),
+            address_lookup_table_program: solana_address_lookup_table_interface::program::ID,
+            system_program: system_program::ID,
+        },
+        instruction::CreateLookupTable { recent_slot },
+    )
+}
+
+/// `lookup_table` is the config's `lookup_table`.
+pub fn extend_lookup_table(
+    admin: Pubkey,
+    lookup_table: Pubkey,
+    addresses: Vec<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::ExtendLookupTable {
+            config: pda::config(),
+            admin,
+            lookup_table,
+            address_lookup_table_program: solana_address_lookup_table_interface::program::ID,
+            system_program: system_program::ID,
+        },
+        instruction::ExtendLookupTable { addresses },
+    )
+}
+
+// Config and global state
+
+/// Must be signed by the program's upgrade authority. `cluster` is one of
+/// `Config::CLUSTER_*`, fixed from then on.
+pub fn initialize_config(admin: Pubkey, vrf_oracle: Pubkey, cluster: u8) -> Instruction {
+    build(
+        accounts::InitializeConfig {
+            config: pda::config(),
+            admin,
+            program: clicker::ID,
+            program_data: pda::program_data(),
+            system_program: system_program::ID,
+        },
+        instruction::InitializeConfig {
+            vrf_oracle,
+            cluster,
+        },
+    )
+}
+
+pub fn update_config(admin: Pubkey, update: ConfigUpdate) -> Instruction {
+    build(
+        accounts::UpdateConfig {
+            config: pda::config(),
+            admin,
+        },
+        instruction::UpdateConfig { update },
+    )
+}
+
+/// Records the current admin and upgrade authority; signed by the admin.
+pub fn initialize_admin_record(admin: Pubkey) -> Instruction {
+    build(
+        accounts::InitializeAdminRecord {
+            config: pda::config(),
+            admin_record: pda::admin_record(),
+            admin,
+            program: clicker::ID,
+            program_data: pda::program_data(),
+            system_program: system_program::ID,
+        },
+        instruction::InitializeAdminRecord {},
+    )
+}
+
+pub fn propose_admin(admin: Pubkey, new_admin: Pubkey) -> Instruction {
+    build(
+        accounts::ProposeAdmin {
+            config: pda::config(),
+            admin_record: pda::admin_record(),
+            admin,
+        },
+        instruction::ProposeAdmin { new_admin },
+    )
+}
+
+pub fn accept_admin(new_admin: Pubkey) -> Instruction {
+    build(
+        accounts::AcceptAdmin {
+            config: pda::config(),
+            admin_record: pda::admin_record(),
+            new_admin,
+        },
+        instruction::AcceptAdmin {},
+    )
+}
+
+/// Signed by the current upgrade authority, before the loader moves it.
+pub fn propose_upgrade_authority(authority: Pubkey, new_authority: Pubkey) -> Instruction {
+    build(
+        accounts::ProposeUpgradeAuthority {
+            admin_record: pda::admin_record(),
+            program: clicker::ID,
+            program_data: pda::program_data(),
+            authority,
+        },
+        instruction::ProposeUpgradeAuthority { new_authority },
+    )
+}
+
+/// Signed by the new upgrade authority, after the loader has moved it.
+pub fn accept_upgrade_authority(new_authority: Pubkey) -> Instruction {
+    build(
+        accounts::AcceptUpgradeAuthority {
+            admin_record: pda::admin_record(),
+            program: clicker::ID,
+            program_data: pda::program_data(),
+            new_authority,
+        },
+        instruction::AcceptUpgradeAuthority {},
+    )
+}
+
+pub fn initialize_global_stats(admin: Pubkey) -> Instruction {
+    build(
+        accounts::InitializeGlobalStats {
+            config: pda::config(),
+            global_stats: pda::global_stats(),
+            admin,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeGlobalStats {},
+    )
+}
+
+pub fn adjust_difficulty() -> Instruction {
+    build(
+        accounts::AdjustDifficulty {
+            config: pda::config(),
+            global_stats: pda::global_stats(),
+        },
+        instruction::AdjustDifficulty {},
+    )
+}
+
+// Raffles
+
+pub fn open_raffle(payer: Pubkey, period: u64) -> Instruction {
+    build(
+        accounts::OpenRaffle {
+            config: pda::config(),
+            raffle: pda::raffle(period),
+            payer,
+            system_program: system_program::ID,
+        },
+        instruction::OpenRaffle { period },
+    )
+}
+
+pub fn fund_raffle(funder: Pubkey, period: u64, amount: u64) -> Instruction {
+    build(
+        accounts::FundRaffle {
+            config: pda::config(),
+            raffle: pda::raffle(period),
+            funder,
+            system_program: system_program::ID,
+        },
+        instruction::FundRaffle { amount },
+    )
+}
+
+/// Stops ticket sales for a raffle whose period is over. Anyone can send it.
+pub fn close_raffle(period: u64) -> Instruction {
+    build(
+        accounts::CloseRaffle {
+            config: pda::config(),
+            raffle: pda::raffle(period),
+        },
+        instruction::CloseRaffle {},
+    )
+}
+
+/// The oracle's Ed25519 instruction over (raffle address || draw slot) has
+/// to come right before this one.
+pub fn draw_raffle(period: u64, winning_session: Pubkey, winner: Pubkey) -> Instruction {
+    build(
+        accounts::DrawRaffle {
+            config: pda::config(),
+            raffle: pda::raffle(period),
+            winning_session,
+            winner,
+            instructions: sysvar_instructions::ID,
+            event_authority: pda::event_authority(),
+            program: clicker::ID,
+        },
+        instruction::DrawRaffle {},
+    )
+}
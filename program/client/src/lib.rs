@@ -0,0 +1,25 @@
+//! Rust client for the clicker program.
+//!
+//! Builders here return plain `Instruction`s so they work with any RPC or
+//! transaction library. Account addresses the program derives itself are
+//! filled in from [`pda`], and the reveal commitment comes from the program
+//! crate so clients hash exactly what `end_session` checks.
+
+use anchor_lang::prelude::Pubkey;
+use clicker::SessionResult;
+
+pub mod instructions;
+
+pub use clicker::{pda, ID};
+
+/// The commitment to pass to `start_session` for a later reveal of
+/// `result` and `nonce`. `challenge` must be the same one given to
+/// `start_session`, if any.
+pub fn build_commitment(
+    result: &SessionResult,
+    nonce: u64,
+    player: &Pubkey,
+    challenge: Option<&[u8; 32]>,
+) -> [u8; 32] {
+    clicker::commitment::reveal_commitment(result, nonce, player, challenge)
+}
@@ -0,0 +1,61 @@
+//! WASM bindings for clicker-commitment, so the web client hashes reveals
+//! with the same code as the program.
+//!
+//! Build with `wasm-pack build commitment-wasm --target web`.
+
+use wasm_bindgen::prelude::*;
+
+/// The values revealed in `end_session`, built on the JS side.
+#[wasm_bindgen]
+pub struct SessionResult(clicker_types::SessionResult);
+
+#[wasm_bindgen]
+impl SessionResult {
+    /// `minuteClicks` holds up to 30 per-minute counts; missing minutes are 0.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        clicks: u32,
+        combos: u32,
+        max_combo: u32,
+        max_cps_observed: u16,
+        misses: u32,
+        minute_clicks: &[u16],
+    ) -> Result<SessionResult, JsError> {
+        let mut result = clicker_types::SessionResult {
+            clicks,
+            combos,
+            max_combo,
+            max_cps_observed,
+            misses,
+            ..Default::default()
+        };
+        result
+            .minute_clicks
+            .get_mut(..minute_clicks.len())
+            .ok_or_else(|| JsError::new("minuteClicks has more than 30 entries"))?
+            .copy_from_slice(minute_clicks);
+        Ok(SessionResult(result))
+    }
+}
+
+/// `player` and `challenge` are 32-byte arrays; `nonce` is a BigInt.
+#[wasm_bindgen(js_name = buildCommitment)]
+pub fn build_commitment(
+    result: &SessionResult,
+    nonce: u64,
+    player: &[u8],
+    challenge: Option<Vec<u8>>,
+) -> Result<Vec<u8>, JsError> {
+    let player: &[u8; 32] = player
+        .try_into()
+        .map_err(|_| JsError::new("player must be 32 bytes"))?;
+    let challenge: Option<[u8; 32]> = challenge
+        .map(|challenge| challenge.try_into())
+        .transpose()
+        .map_err(|_| JsError::new("challenge must be 32 bytes"))?;
+
+    Ok(
+        clicker_commitment::commitment(&result.0.to_bytes(), nonce, player, challenge.as_ref())
+            .to_vec(),
+    )
+}
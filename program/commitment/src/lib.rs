@@ -0,0 +1,71 @@
+//! The session commitment preimage, shared by the on-chain program, the Rust
+//! client and (compiled to WASM) the web client, so there is exactly one
+//! definition of the byte layout.
+//!
+//! The preimage is the Borsh-encoded session result || nonce (u64 LE) ||
+//! player, followed by the attestor's challenge when the session was started
+//! with one. The commitment is its SHA-256. The result's fields are defined
+//! by `SessionResult` in clicker-types, which produces the encoding.
+//!
+//! The crate is `no_std` and allocation-free. The web bindings live in
+//! clicker-commitment-wasm.
+
+#![no_std]
+
+/// Buckets in `SessionResult`'s per-minute click histogram.
+pub const HISTOGRAM_MINUTES: usize = 30;
+
+/// Length of a Borsh-encoded `SessionResult`.
+pub const RESULT_LEN: usize = 4 + 4 + 4 + 2 + 4 + 2 * HISTOGRAM_MINUTES;
+
+pub const MAX_PREIMAGE_LEN: usize = RESULT_LEN + 8 + 32 + 32;
+
+/// A preimage on the stack; only the first `len` bytes are used.
+pub struct Preimage {
+    bytes: [u8; MAX_PREIMAGE_LEN],
+    len: usize,
+}
+
+impl Preimage {
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+pub fn preimage(
+    result: &[u8; RESULT_LEN],
+    nonce: u64,
+    player: &[u8; 32],
+    challenge: Option<&[u8; 32]>,
+) -> Preimage {
+    const NONCE: usize = RESULT_LEN;
+    const PLAYER: usize = NONCE + 8;
+    const CHALLENGE: usize = PLAYER + 32;
+
+    let mut bytes = [0u8; MAX_PREIMAGE_LEN];
+    bytes[..NONCE].copy_from_slice(result);
+    bytes[NONCE..PLAYER].copy_from_slice(&nonce.to_le_bytes());
+    bytes[PLAYER..CHALLENGE].copy_from_slice(player);
+
+    let len = match challenge {
+        Some(challenge) => {
+            bytes[CHALLENGE..].copy_from_slice(challenge);
+            MAX_PREIMAGE_LEN
+        }
+        None => CHALLENGE,
+    };
+
+    Preimage { bytes, len }
+}
+
+#[cfg(feature = "sha2")]
+pub fn commitment(
+    result: &[u8; RESULT_LEN],
+    nonce: u64,
+    player: &[u8; 32],
+    challenge: Option<&[u8; 32]>,
+) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+
+    Sha256::digest(preimage(result, nonce, player, challenge).as_bytes()).into()
+}
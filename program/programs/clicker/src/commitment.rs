@@ -0,0 +1,21 @@
+//! The session commitment players publish in start_session and open in
+//! end_session. The preimage layout lives in the clicker-commitment crate,
+//! which clients share.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::state::SessionResult;
+
+pub use clicker_commitment::preimage;
+
+/// Hashes the reveal values into the commitment end_session expects. The
+/// preimage is built on the stack and hashed with the sha256 syscall.
+pub fn reveal_commitment(
+    result: &SessionResult,
+    nonce: u64,
+    player: &Pubkey,
+    challenge: Option<&[u8; 32]>,
+) -> [u8; 32] {
+    hash(preimage(&result.to_bytes(), nonce, &player.to_bytes(), challenge).as_bytes()).to_bytes()
+}
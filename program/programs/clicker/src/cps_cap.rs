@@ -0,0 +1,158 @@
+//! The click rate a reveal is held to.
+//!
+//! Every bonus to the cap goes through CpsCap::for_game, so there is one
+//! place to audit what a game may reveal. The cap starts at the config's
+//! base CPS, falls as global difficulty rises, and is scaled by the game's
+//! CPS skills and reputation band. Hardcore games then get their share of
+//! it, and a running season scales the result by its modifier. A weekly
+//! challenge may then hold a session below it with at_most.
+
+use crate::constants::BPS_ONE;
+use crate::math::Fixed;
+use crate::state::{Config, Game, GlobalStats};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CpsCap {
+    // clicks per second = numerator / denominator, kept apart so nothing
+    // rounds until a cap is asked for
+    numerator: u128,
+    denominator: u128,
+}
+
+impl CpsCap {
+    /// `season` is the factor apply_modifiers gives for SeasonStat::CpsCap.
+    pub fn for_game(config: &Config, global_stats: &GlobalStats, game: &Game, season: Fixed) -> Self {
+        let base_cps = match config.base_cps {
+            0 => GlobalStats::BASE_CPS,
+            base_cps => base_cps,
+        };
+        let bonus_bps =
+            game.skill_cps_bonus_bps as u128 + config.reputation_cps_bonus_bps(game.reputation) as u128;
+        let hardcore_bps = match config.hardcore_cps_cap_bps {
+            cap if game.is_hardcore() && cap > 0 => cap as u128,
+            _ => BPS_ONE as u128,
+        };
+
+        CpsCap {
+            numerator: base_cps as u128
+                * GlobalStats::DIFFICULTY_ONE as u128
+                * (BPS_ONE as u128 + bonus_bps)
+                * hardcore_bps
+                * season.to_bits() as u128,
+            denominator: (global_stats.difficulty as u128 * BPS_ONE as u128 * BPS_ONE as u128)
+                << Fixed::FRACTIONAL_BITS,
+        }
+    }
+
+    /// This cap, or `cps` clicks per second if that is lower.
+    pub fn at_most(self, cps: u64) -> Self {
+        if self.numerator > cps as u128 * self.denominator {
+            CpsCap { numerator: cps as u128, denominator: 1 }
+        } else {
+            self
+        }
+    }
+
+    /// Most clicks `duration` seconds may reveal.
+    pub fn max_clicks(&self, duration: i64) -> u64 {
+        (duration.max(0) as u128 * self.numerator / self.denominator) as u64
+    }
+
+    /// Most clicks a single second may show. Rounded up, since a session
+    /// averaging the cap needs some seconds above its floor.
+    pub fn max_cps(&self) -> u64 {
+        self.numerator.div_ceil(self.denominator) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::ReputationBand;
+
+    fn stats(difficulty: u64) -> GlobalStats {
+        GlobalStats { difficulty, ..GlobalStats::default() }
+    }
+
+    fn cap(config: &Config, difficulty: u64, game: &Game) -> CpsCap {
+        CpsCap::for_game(config, &stats(difficulty), game, Fixed::ONE)
+    }
+
+    #[test]
+    fn base_cps_at_base_difficulty() {
+        let game = Game::default();
+        let base = cap(&Config::default(), GlobalStats::DIFFICULTY_ONE, &game);
+        assert_eq!(base.max_cps(), GlobalStats::BASE_CPS);
+        assert_eq!(base.max_clicks(60), 60 * GlobalStats::BASE_CPS);
+        assert_eq!(base.max_clicks(0), 0);
+        assert_eq!(base.max_clicks(-5), 0);
+
+        let config = Config { base_cps: 20, ..Config::default() };
+        assert_eq!(cap(&config, GlobalStats::DIFFICULTY_ONE, &game).max_cps(), 20);
+    }
+
+    #[test]
+    fn difficulty_lowers_the_cap_without_early_rounding() {
+        let game = Game::default();
+        let config = Config::default();
+        assert_eq!(cap(&config, 2 * GlobalStats::DIFFICULTY_ONE, &game).max_cps(), 5);
+
+        // 10 / 3 clicks per second
+        let third = cap(&config, 3 * GlobalStats::DIFFICULTY_ONE, &game);
+        assert_eq!(third.max_clicks(1), 3);
+        assert_eq!(third.max_clicks(3), 10);
+        assert_eq!(third.max_cps(), 4);
+    }
+
+    #[test]
+    fn skill_and_reputation_bonuses_add_up() {
+        let mut config = Config::default();
+        config.reputation_bands[0] = ReputationBand { min_reputation: 100, cps_bonus_bps: 2_500 };
+        let mut game = Game { skill_cps_bonus_bps: 2_500, reputation: 99, ..Game::default() };
+
+        // 12.5 clicks per second just below the band
+        let below = cap(&config, GlobalStats::DIFFICULTY_ONE, &game);
+        assert_eq!(below.max_clicks(2), 25);
+        assert_eq!(below.max_cps(), 13);
+
+        game.reputation = 100;
+        assert_eq!(cap(&config, GlobalStats::DIFFICULTY_ONE, &game).max_cps(), 15);
+    }
+
+    #[test]
+    fn hardcore_share_only_applies_to_hardcore_games() {
+        let mut config = Config { hardcore_cps_cap_bps: 5_000, ..Config::default() };
+        let hardcore = Game { flags: Game::FLAG_HARDCORE, ..Game::default() };
+        assert_eq!(cap(&config, GlobalStats::DIFFICULTY_ONE, &hardcore).max_cps(), 5);
+        assert_eq!(cap(&config, GlobalStats::DIFFICULTY_ONE, &Game::default()).max_cps(), 10);
+
+        config.hardcore_cps_cap_bps = 0;
+        assert_eq!(cap(&config, GlobalStats::DIFFICULTY_ONE, &hardcore).max_cps(), 10);
+    }
+
+    #[test]
+    fn season_modifier_scales_the_cap() {
+        let season = CpsCap::for_game(
+            &Config::default(),
+            &stats(GlobalStats::DIFFICULTY_ONE),
+            &Game::default(),
+            Fixed::from_bps(15_000),
+        );
+        assert_eq!(season.max_cps(), 15);
+        assert_eq!(season.max_clicks(10), 150);
+    }
+
+    #[test]
+    fn at_most_only_ever_lowers_the_cap() {
+        let game = Game::default();
+        let base = cap(&Config::default(), GlobalStats::DIFFICULTY_ONE, &game);
+        assert_eq!(base.at_most(11), base);
+        assert_eq!(base.at_most(10).max_clicks(60), 600);
+        assert_eq!(base.at_most(9).max_cps(), 9);
+        assert_eq!(base.at_most(0).max_clicks(60), 0);
+
+        let third = cap(&Config::default(), 3 * GlobalStats::DIFFICULTY_ONE, &game);
+        assert_eq!(third.at_most(4), third);
+        assert_eq!(third.at_most(3).max_clicks(3), 9);
+    }
+}
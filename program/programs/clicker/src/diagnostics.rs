@@ -0,0 +1,41 @@
+//! Context for failed reveals, passed back to clients as return data.
+//!
+//! A failing instruction can't return a value, but return data set before the
+//! error is still reported by simulation and transaction metadata. The tag
+//! keeps it distinguishable from the clicks a successful reveal returns.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RevealDiagnostics {
+    pub expected_commitment: [u8; 32],
+    pub provided_hash: [u8; 32],
+    pub clicks: u32,
+    pub max_clicks: u64,
+    pub session_duration: i64,
+    pub max_session_duration: i64,
+}
+
+impl RevealDiagnostics {
+    pub const TAG: [u8; 4] = *b"diag";
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut data = Self::TAG.to_vec();
+        // serializing into a Vec can't fail
+        self.serialize(&mut data).unwrap();
+        data
+    }
+
+    /// Decodes return data written by `publish`, or `None` if it is anything else.
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        match data.strip_prefix(&Self::TAG) {
+            Some(mut body) => Self::deserialize(&mut body).ok(),
+            None => None,
+        }
+    }
+
+    pub fn publish(&self) {
+        set_return_data(&self.encode());
+    }
+}
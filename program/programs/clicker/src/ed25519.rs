@@ -0,0 +1,89 @@
+//! Instruction introspection for the native Ed25519 signature program.
+//!
+//! The runtime verifies the signature when the Ed25519 instruction executes, so
+//! all we need to check here is that the instruction exists, that it verified
+//! exactly the signer and message we expect, and that it carried its own data
+//! rather than pointing into another instruction.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+use crate::errors::ClickerError;
+
+const HEADER_SIZE: usize = 2;
+const OFFSETS_SIZE: usize = 14;
+const PUBKEY_SIZE: usize = 32;
+const SIGNATURE_SIZE: usize = 64;
+
+/// Checks that the instruction immediately before the current one verified a
+/// signature by `signer` over `message`, and returns that signature.
+pub fn verify_preceding_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<[u8; 64]> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    if current_index == 0 {
+        msg!("no instruction precedes this one to carry the Ed25519 signature");
+        return Err(error!(ClickerError::InvalidSignatureInstruction));
+    }
+
+    let ix = load_instruction_at_checked((current_index - 1) as usize, instructions_sysvar)?;
+    if ix.program_id != ed25519_program::ID || !ix.accounts.is_empty() {
+        msg!(
+            "preceding instruction is for {} with {} accounts, expected the Ed25519 program",
+            ix.program_id,
+            ix.accounts.len()
+        );
+        return Err(error!(ClickerError::InvalidSignatureInstruction));
+    }
+
+    let data = &ix.data;
+    if data.len() < HEADER_SIZE + OFFSETS_SIZE || data[0] != 1 {
+        msg!(
+            "Ed25519 instruction has {} bytes and {} signatures, expected exactly one",
+            data.len(),
+            data.first().copied().unwrap_or(0)
+        );
+        return Err(error!(ClickerError::InvalidSignatureInstruction));
+    }
+
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+    let signature_offset = read_u16(2) as usize;
+    let signature_ix_index = read_u16(4);
+    let pubkey_offset = read_u16(6) as usize;
+    let pubkey_ix_index = read_u16(8);
+    let message_offset = read_u16(10) as usize;
+    let message_size = read_u16(12) as usize;
+    let message_ix_index = read_u16(14);
+
+    // all three pieces must live inside the Ed25519 instruction itself
+    if signature_ix_index != u16::MAX || pubkey_ix_index != u16::MAX || message_ix_index != u16::MAX {
+        msg!(
+            "Ed25519 data points at instructions {}, {} and {}",
+            signature_ix_index,
+            pubkey_ix_index,
+            message_ix_index
+        );
+        return Err(error!(ClickerError::InvalidSignatureInstruction));
+    }
+
+    let signed_pubkey = data.get(pubkey_offset..pubkey_offset + PUBKEY_SIZE);
+    let signed_message = data.get(message_offset..message_offset + message_size);
+    let signature = data.get(signature_offset..signature_offset + SIGNATURE_SIZE);
+
+    match (signed_pubkey, signed_message, signature) {
+        (Some(pubkey), Some(msg), Some(sig)) if pubkey == signer.as_ref() && msg == message => {
+            let mut out = [0u8; SIGNATURE_SIZE];
+            out.copy_from_slice(sig);
+            Ok(out)
+        }
+        _ => {
+            msg!("Ed25519 instruction did not verify {} over the expected message", signer);
+            Err(error!(ClickerError::InvalidSignatureInstruction))
+        }
+    }
+}
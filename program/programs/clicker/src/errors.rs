@@ -0,0 +1,325 @@
+use anchor_lang::error_code;
+
+#[error_code]
+pub enum ClickerError {
+    #[msg("Signer is not the game's player")]
+    InvalidPlayer,
+    #[msg("The game already has an active session")]
+    SessionAlreadyActive,
+    #[msg("Session is not the game's active session")]
+    InvalidSession,
+    #[msg("Session was already revealed with different values")]
+    SessionAlreadyRevealed,
+    #[msg("Session ran longer than the maximum duration")]
+    SessionTooLong,
+    #[msg("Revealed clicks and nonce do not match the session commitment")]
+    InvalidCommitment,
+    #[msg("Revealed clicks exceed the cap for the session duration")]
+    UnrealisticClickRate,
+    #[msg("Signer is not allowed to perform this action")]
+    Unauthorized,
+    #[msg("Preceding Ed25519 instruction is missing or malformed")]
+    InvalidSignatureInstruction,
+    #[msg("Raffle period has already begun")]
+    InvalidRafflePeriod,
+    #[msg("Raffle is not open")]
+    RaffleNotActive,
+    #[msg("Raffle cannot be drawn before it closes")]
+    RaffleStillOpen,
+    #[msg("Raffle was already drawn")]
+    RaffleAlreadyDrawn,
+    #[msg("Raffle has no tickets to draw from")]
+    RaffleHasNoTickets,
+    #[msg("Session does not hold the winning ticket")]
+    WinningTicketMismatch,
+    #[msg("Difficulty epoch has not finished yet")]
+    EpochNotFinished,
+    #[msg("Session needs an attestor-signed challenge")]
+    ChallengeRequired,
+    #[msg("Every device slot is in use")]
+    DeviceLimitReached,
+    #[msg("Device is already registered")]
+    DeviceAlreadyRegistered,
+    #[msg("Device is not registered")]
+    DeviceNotRegistered,
+    #[msg("Reveal needs a registered device co-signature")]
+    DeviceSignatureRequired,
+    #[msg("Guardian threshold exceeds the number of guardians")]
+    InvalidGuardianThreshold,
+    #[msg("Not enough guardians signed")]
+    InsufficientGuardianSignatures,
+    #[msg("A recovery is already pending")]
+    RecoveryAlreadyPending,
+    #[msg("No recovery is pending")]
+    NoRecoveryPending,
+    #[msg("Recovery is still timelocked")]
+    RecoveryTimelocked,
+    #[msg("Game is frozen")]
+    GameFrozen,
+    #[msg("Game is not frozen")]
+    GameNotFrozen,
+    #[msg("Feature is disabled in the program config")]
+    FeatureDisabled,
+    #[msg("Multiplier is out of range")]
+    InvalidMultiplier,
+    #[msg("Partner collection is inactive")]
+    PartnerCollectionInactive,
+    #[msg("Player does not hold the NFT")]
+    NftNotHeld,
+    #[msg("NFT is not a verified member of the collection")]
+    CollectionNotVerified,
+    #[msg("Quest is inactive")]
+    QuestInactive,
+    #[msg("Quest condition is not met")]
+    QuestConditionNotMet,
+    #[msg("Happy hour window is invalid")]
+    InvalidHappyHour,
+    #[msg("Player level is too low")]
+    LevelTooLow,
+    #[msg("Skill node is invalid")]
+    InvalidSkillNode,
+    #[msg("Skill is already unlocked")]
+    SkillAlreadyUnlocked,
+    #[msg("Skill prerequisites are not unlocked")]
+    SkillPrerequisitesMissing,
+    #[msg("Not enough skill points")]
+    NotEnoughSkillPoints,
+    #[msg("Respec is on cooldown")]
+    RespecOnCooldown,
+    #[msg("Not enough clicks")]
+    InsufficientClicks,
+    #[msg("Gems are not for sale")]
+    GemsNotForSale,
+    #[msg("Not enough gems")]
+    InsufficientGems,
+    #[msg("Cosmetic is invalid")]
+    InvalidCosmetic,
+    #[msg("Refund window has closed")]
+    RefundWindowClosed,
+    #[msg("Gem purchase cap reached")]
+    PurchaseCapReached,
+    #[msg("Session archive is full")]
+    ArchiveFull,
+    #[msg("Session archive account is required")]
+    ArchiveRequired,
+    #[msg("Archive proof does not match a known root")]
+    InvalidArchiveProof,
+    #[msg("Revealed session metrics are inconsistent")]
+    InvalidSessionResult,
+    #[msg("Session is paused")]
+    SessionPaused,
+    #[msg("Session is not paused")]
+    SessionNotPaused,
+    #[msg("Session has used up its pause allowance")]
+    PauseLimitReached,
+    #[msg("Session extensions are not for sale")]
+    ExtensionsNotForSale,
+    #[msg("Session extension exceeds the limit")]
+    ExtensionLimitReached,
+    #[msg("Confirmation text does not match")]
+    ConfirmationMismatch,
+    #[msg("Previous world record holder's game is required")]
+    RecordHolderRequired,
+    #[msg("Game is not eligible to mentor")]
+    NotEligibleMentor,
+    #[msg("Game is not eligible to be mentored")]
+    NotEligibleMentee,
+    #[msg("Mentorship belongs to another game")]
+    InvalidMentorship,
+    #[msg("World boss parameters are invalid")]
+    InvalidWorldBoss,
+    #[msg("World boss is not active")]
+    BossNotActive,
+    #[msg("World boss has not been defeated")]
+    BossNotDefeated,
+    #[msg("Loot was already claimed")]
+    LootAlreadyClaimed,
+    #[msg("No scheduled raid is due")]
+    RaidNotDue,
+    #[msg("Lookup table address does not match")]
+    InvalidLookupTable,
+    #[msg("Game already has the fixed layout")]
+    GameAlreadyMigrated,
+    #[msg("Session must be revealed, cancelled or voided first")]
+    SessionNotFinished,
+    #[msg("Session holds tickets in a raffle that is not drawn yet")]
+    RaffleNotDrawn,
+    #[msg("Offer is not active")]
+    OfferInactive,
+    #[msg("Offer has no redemptions left")]
+    OfferSoldOut,
+    #[msg("Daily offer limit reached")]
+    OfferLimitReached,
+    #[msg("Offer reward is invalid")]
+    InvalidOfferReward,
+    #[msg("An identity is already bound")]
+    IdentityAlreadyBound,
+    #[msg("No identity is bound")]
+    NoIdentityBound,
+    #[msg("Identity hash is invalid")]
+    InvalidIdentityHash,
+    #[msg("Identity cannot be unbound yet")]
+    IdentityCooldown,
+    #[msg("Season pass season is invalid")]
+    InvalidPassSeason,
+    #[msg("Season is not running")]
+    SeasonNotActive,
+    #[msg("Season pass tier does not exist")]
+    InvalidPassTier,
+    #[msg("Not enough xp for this tier")]
+    PassTierLocked,
+    #[msg("Tier was already claimed")]
+    TierAlreadyClaimed,
+    #[msg("Tier needs the premium pass")]
+    PremiumPassRequired,
+    #[msg("Pass is already premium")]
+    PremiumPassOwned,
+    #[msg("Premium pass is not for sale this way")]
+    PremiumNotForSale,
+    #[msg("No lootboxes to open")]
+    NoLootboxes,
+    #[msg("Lootbox reward table is empty")]
+    LootboxTableEmpty,
+    #[msg("Not enough items")]
+    InsufficientItems,
+    #[msg("Inventory is full")]
+    InventoryFull,
+    #[msg("Inventory does not match")]
+    InvalidInventory,
+    #[msg("An inventory is needed to receive items")]
+    InventoryRequired,
+    #[msg("Recipe is invalid")]
+    InvalidRecipe,
+    #[msg("Recipe is inactive")]
+    RecipeInactive,
+    #[msg("Marketplace fee is over 100%")]
+    InvalidMarketplaceFee,
+    #[msg("Listing price must be positive")]
+    InvalidListingPrice,
+    #[msg("Listed items have expired")]
+    ListingExpired,
+    #[msg("Can't buy your own listing")]
+    OwnListing,
+    #[msg("Hardcore CPS cap can't exceed the usual cap")]
+    InvalidHardcoreCap,
+    #[msg("Game has fallen")]
+    GameFallen,
+    #[msg("Hardcore games take no boosts")]
+    HardcoreNoBoosts,
+    #[msg("Time attacks are switched off")]
+    TimeAttackDisabled,
+    #[msg("Time attack was revealed before its window ended")]
+    TimeAttackTooShort,
+    #[msg("Time attacks can't be paused or extended")]
+    TimeAttackFixed,
+    #[msg("Endurance floor can't exceed full credit")]
+    InvalidEnduranceFloor,
+    #[msg("Co-op session does not match")]
+    InvalidCoopSession,
+    #[msg("Co-op pair went over its shared click cap")]
+    CoopCapExceeded,
+    #[msg("Multiplier overflowed")]
+    MultiplierOverflow,
+    #[msg("Game keeps a session index, which must be passed")]
+    SessionIndexRequired,
+    #[msg("Signer has no handover to accept")]
+    NoPendingHandover,
+    #[msg("Upgrade authority hasn't moved on the loader yet")]
+    UpgradeAuthorityNotMoved,
+    #[msg("Game has no dangling session to repair")]
+    NothingToRepair,
+    #[msg("Session credited nothing to gamble")]
+    NothingToGamble,
+    #[msg("Session was already gambled")]
+    AlreadyGambled,
+    #[msg("Gamble window for this session has closed")]
+    GambleWindowClosed,
+    #[msg("Game already gambled today")]
+    GambleUsedToday,
+    #[msg("Session has no pending gamble")]
+    GambleNotPending,
+    #[msg("Session has a gamble waiting to be settled")]
+    GamblePending,
+    #[msg("Golden window must end after it starts, at a multiplier of at least 1x")]
+    InvalidGoldenWindow,
+    #[msg("Golden window hasn't ended yet")]
+    GoldenWindowNotOver,
+    #[msg("Season modifying reveals must be passed")]
+    ModifierSeasonRequired,
+    #[msg("Trophy cabinet has no such slot")]
+    InvalidTrophySlot,
+    #[msg("NFT is already pinned in the trophy cabinet")]
+    TrophyAlreadyPinned,
+    #[msg("No trophy pinned there")]
+    TrophySlotEmpty,
+    #[msg("Quest is already a step of a chain")]
+    QuestAlreadyChained,
+    #[msg("Quest chain has no room for another step")]
+    QuestChainFull,
+    #[msg("Quest is a chain step; the chain and the game's progress must be passed")]
+    QuestChainRequired,
+    #[msg("Quest is locked until the chain's earlier steps are done")]
+    QuestLocked,
+    #[msg("Weekly challenge isn't running")]
+    ChallengeNotRunning,
+    #[msg("Game has entered all the sessions the challenge allows")]
+    ChallengeSessionLimit,
+    #[msg("Session entered a weekly challenge; it and the game's entry must be passed")]
+    ChallengeEntryRequired,
+    #[msg("Claiming rewards needs a gateway token")]
+    GatewayTokenRequired,
+    #[msg("Gateway token is invalid, expired or for another wallet or network")]
+    InvalidGatewayToken,
+    #[msg("Cluster tag is not a known cluster")]
+    InvalidCluster,
+    #[msg("Config is already tagged with its cluster")]
+    ClusterAlreadyTagged,
+    #[msg("Features paying real tokens only run on mainnet")]
+    RealValueOffMainnet,
+    #[msg("Guarded reveals need the instructions sysvar")]
+    InstructionsSysvarRequired,
+    #[msg("Transaction holds an instruction not allowed alongside this reveal")]
+    UnexpectedInstruction,
+    #[msg("Gift needs clicks, another game and an expiry within Gift::MAX_EXPIRY")]
+    InvalidGift,
+    #[msg("Gift has expired")]
+    GiftExpired,
+    #[msg("Gift can still be accepted")]
+    GiftNotExpired,
+    #[msg("Message must be 1 to ShoutboxMessage::MAX_LEN bytes")]
+    InvalidMessage,
+    #[msg("Posting again too soon")]
+    PostCooldown,
+    #[msg("Shoutbox doesn't take that payment")]
+    PaymentNotAccepted,
+    #[msg("A lootbox is already waiting to be settled")]
+    LootboxPending,
+    #[msg("No lootbox is waiting to be settled")]
+    NoPendingLootbox,
+    #[msg("The same guardian is listed more than once")]
+    DuplicateGuardian,
+    #[msg("NFT is already boosting another game")]
+    NftAlreadyBound,
+    #[msg("Golden window doesn't cover the session")]
+    GoldenWindowNotActive,
+    #[msg("A game has already unlocked this skill node")]
+    SkillNodeUnlocked,
+    #[msg("Raffles are enabled but the current raffle wasn't passed")]
+    RaffleRequired,
+    #[msg("Raffle ticket sales were already closed")]
+    RaffleAlreadyClosed,
+    #[msg("The default key can't be registered as a device")]
+    InvalidDevice,
+    #[msg("Treasury can't pay this out and stay rent exempt")]
+    TreasuryUnderfunded,
+    #[msg("A receipt account is required for a SOL purchase")]
+    ReceiptRequired,
+    #[msg("Refunding a premium pass needs its pass and season")]
+    PassRequired,
+    #[msg("Premium rewards already claimed can't be refunded")]
+    PremiumRewardClaimed,
+    #[msg("Item stack would overflow")]
+    ItemStackOverflow,
+    #[msg("Inventory doesn't accept transfers")]
+    TransfersNotAccepted,
+}
@@ -0,0 +1,434 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{AdminRole, ArchivedSession, OfferReward, PersonalRecord, VoidReason};
+
+// Events that indexers need to rebuild state are emitted with emit_cpi!, which
+// records them as inner instruction data instead of logs that RPC nodes may
+// truncate. The rest use plain emit!.
+//
+// Discriminators are pinned to the values Anchor derived from the original
+// event names (sha256("event:<Name>")[..8]) so renaming a struct never breaks
+// an indexer. Every event starts with schema_version; bump
+// EVENT_SCHEMA_VERSION whenever a field is added, removed or reordered, and
+// regenerate tests/fixtures/event-schema.json. tests/events.test.ts fails if
+// the layout changes without a bump.
+
+#[constant]
+pub const EVENT_SCHEMA_VERSION: u8 = 1;
+
+#[event(discriminator = [58, 51, 229, 78, 240, 232, 236, 18])]
+pub struct SessionEnded {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub session: Pubkey,
+    pub player: Pubkey,
+    pub clicks: u32,
+    pub duration: i64,
+    pub cancelled: bool,
+}
+
+#[event(discriminator = [54, 230, 157, 27, 217, 99, 82, 57])]
+pub struct SessionVoided {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub session: Pubkey,
+    pub player: Pubkey,
+    pub admin: Pubkey,
+    pub reason: VoidReason,
+}
+
+#[event(discriminator = [93, 182, 173, 144, 136, 37, 168, 68])]
+pub struct ItemConsumed {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub item_id: u32,
+    pub quantity: u32,
+}
+
+#[event(discriminator = [255, 94, 24, 179, 130, 139, 115, 73])]
+pub struct ItemTransferred {
+    pub schema_version: u8,
+    pub from_game: Pubkey,
+    pub to_game: Pubkey,
+    pub item_id: u32,
+    pub quantity: u32,
+}
+
+#[event(discriminator = [19, 69, 109, 223, 116, 34, 30, 27])]
+pub struct ItemCrafted {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub recipe_id: u32,
+    pub item_id: u32,
+    pub quantity: u32,
+}
+
+#[event(discriminator = [51, 193, 103, 51, 201, 26, 211, 113])]
+pub struct ItemListed {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub listing: Pubkey,
+    pub item_id: u32,
+    pub quantity: u32,
+    pub price: u64,
+}
+
+#[event(discriminator = [199, 212, 98, 147, 91, 49, 85, 138])]
+pub struct ListingSold {
+    pub schema_version: u8,
+    pub listing: Pubkey,
+    pub seller_game: Pubkey,
+    pub buyer_game: Pubkey,
+    pub item_id: u32,
+    pub quantity: u32,
+    pub price: u64,
+    pub fee: u64,
+}
+
+#[event(discriminator = [11, 46, 163, 10, 103, 80, 139, 194])]
+pub struct ListingCancelled {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub listing: Pubkey,
+}
+
+#[event(discriminator = [168, 36, 170, 195, 175, 63, 101, 225])]
+pub struct LootboxRequested {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub slot: u64,
+}
+
+#[event(discriminator = [51, 64, 145, 224, 240, 229, 211, 56])]
+pub struct LootboxOpened {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub index: u8,
+    pub reward_gems: u64,
+    pub reward_clicks: u64,
+    pub rare: bool,
+    pub pity: bool,
+}
+
+#[event(discriminator = [148, 47, 204, 246, 27, 111, 118, 49])]
+pub struct RaffleClosed {
+    pub schema_version: u8,
+    pub raffle: Pubkey,
+    pub period: u64,
+    pub total_tickets: u64,
+    pub draw_slot: u64,
+}
+
+#[event(discriminator = [17, 181, 213, 34, 171, 64, 191, 170])]
+pub struct RaffleDrawn {
+    pub schema_version: u8,
+    pub raffle: Pubkey,
+    pub period: u64,
+    pub winning_ticket: u64,
+    pub winner: Pubkey,
+    pub prize: u64,
+}
+
+#[event(discriminator = [169, 245, 31, 134, 59, 140, 0, 145])]
+pub struct DifficultyAdjusted {
+    pub schema_version: u8,
+    pub epoch_clicks: u64,
+    pub previous_difficulty: u64,
+    pub difficulty: u64,
+}
+
+#[event(discriminator = [176, 232, 67, 200, 172, 112, 1, 22])]
+pub struct LevelUp {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub player: Pubkey,
+    pub level: u32,
+    pub xp: u64,
+}
+
+#[event(discriminator = [82, 123, 4, 102, 157, 0, 49, 23])]
+pub struct NewPersonalRecord {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub player: Pubkey,
+    pub record: PersonalRecord,
+    pub previous: u64,
+    pub value: u64,
+}
+
+#[event(discriminator = [43, 10, 237, 53, 169, 168, 141, 10])]
+pub struct MentorshipStarted {
+    pub schema_version: u8,
+    pub mentorship: Pubkey,
+    pub mentor_game: Pubkey,
+    pub mentee_game: Pubkey,
+    pub expires_at: i64,
+}
+
+#[event(discriminator = [254, 130, 223, 107, 182, 166, 107, 216])]
+pub struct WorldBossSpawned {
+    pub schema_version: u8,
+    pub boss: Pubkey,
+    pub boss_id: u32,
+    pub hp: u64,
+    pub despawn_at: i64,
+}
+
+#[event(discriminator = [185, 186, 165, 71, 53, 38, 111, 220])]
+pub struct WorldBossDefeated {
+    pub schema_version: u8,
+    pub boss: Pubkey,
+    pub boss_id: u32,
+    pub final_blow: Pubkey,
+    pub defeated_at: i64,
+}
+
+#[event(discriminator = [113, 134, 88, 187, 13, 89, 124, 153])]
+pub struct WorldRecordBroken {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub player: Pubkey,
+    pub session: Pubkey,
+    pub clicks: u32,
+    pub previous_game: Pubkey,
+    pub previous_player: Pubkey,
+    pub previous_clicks: u32,
+}
+
+#[event(discriminator = [56, 30, 155, 254, 216, 227, 173, 223])]
+pub struct PartnerQuestCompleted {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub quest_id: u32,
+    pub reward_clicks: u64,
+}
+
+#[event(discriminator = [241, 143, 126, 221, 152, 240, 70, 46])]
+pub struct OfferRedeemed {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub offer_id: u32,
+    pub reward: OfferReward,
+}
+
+#[event(discriminator = [221, 90, 2, 153, 72, 98, 71, 181])]
+pub struct DeviceRegistered {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub device: Pubkey,
+}
+
+#[event(discriminator = [63, 57, 33, 75, 105, 242, 126, 44])]
+pub struct DeviceRevoked {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub device: Pubkey,
+}
+
+#[event(discriminator = [170, 195, 145, 167, 147, 234, 156, 155])]
+pub struct RecoveryStarted {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub new_owner: Pubkey,
+    pub unlock_time: i64,
+}
+
+#[event(discriminator = [191, 25, 236, 86, 25, 77, 117, 96])]
+pub struct RecoveryCancelled {
+    pub schema_version: u8,
+    pub game: Pubkey,
+}
+
+#[event(discriminator = [183, 169, 144, 11, 110, 67, 103, 46])]
+pub struct IdentityBound {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub identity_hash: [u8; 32],
+}
+
+#[event(discriminator = [90, 72, 232, 214, 50, 144, 160, 139])]
+pub struct IdentityUnbound {
+    pub schema_version: u8,
+    pub game: Pubkey,
+}
+
+#[event(discriminator = [193, 246, 35, 126, 104, 142, 252, 12])]
+pub struct GameRecovered {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+#[event(discriminator = [243, 30, 117, 149, 33, 169, 203, 27])]
+pub struct SessionArchived {
+    pub schema_version: u8,
+    pub index: u64,
+    pub record: ArchivedSession,
+}
+
+/// The archive was full, so the session was revealed without a leaf.
+#[event(discriminator = [241, 162, 0, 233, 175, 116, 87, 98])]
+pub struct SessionNotArchived {
+    pub schema_version: u8,
+    pub session: Pubkey,
+    pub game: Pubkey,
+}
+
+#[event(discriminator = [86, 244, 97, 241, 150, 41, 176, 14])]
+pub struct PremiumPassPurchased {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub season_id: u32,
+    pub gems: u64,
+    pub lamports: u64,
+}
+
+#[event(discriminator = [148, 242, 63, 101, 74, 192, 1, 126])]
+pub struct PassRewardClaimed {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub season_id: u32,
+    pub tier: u8,
+    pub reward_gems: u64,
+    pub reward_clicks: u64,
+}
+
+#[event(discriminator = [26, 20, 84, 246, 99, 112, 128, 143])]
+pub struct GemsPurchased {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub player: Pubkey,
+    pub gems: u64,
+    pub lamports: u64,
+}
+
+#[event(discriminator = [181, 111, 228, 8, 192, 68, 247, 37])]
+pub struct PurchaseRefunded {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub receipt: Pubkey,
+    pub gems: u64,
+    pub lamports: u64,
+}
+
+#[event(discriminator = [196, 61, 67, 22, 121, 25, 50, 173])]
+pub struct GameSoftReset {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub player: Pubkey,
+    pub previous_total_clicks: u64,
+    pub previous_level: u32,
+}
+
+#[event(discriminator = [227, 159, 38, 192, 191, 60, 17, 151])]
+pub struct GameFallen {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub player: Pubkey,
+    pub session: Pubkey,
+}
+
+#[event(discriminator = [164, 125, 88, 133, 163, 209, 130, 194])]
+pub struct GameFreezeChanged {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub frozen: bool,
+}
+
+#[event(discriminator = [210, 152, 97, 225, 31, 45, 253, 223])]
+pub struct AdminHandoverProposed {
+    pub schema_version: u8,
+    pub role: AdminRole,
+    pub from: Pubkey,
+    pub to: Pubkey,
+}
+
+#[event(discriminator = [33, 88, 89, 111, 59, 224, 22, 80])]
+pub struct AdminHandoverAccepted {
+    pub schema_version: u8,
+    pub role: AdminRole,
+    pub from: Pubkey,
+    pub to: Pubkey,
+}
+
+#[event(discriminator = [170, 15, 157, 250, 25, 223, 199, 151])]
+pub struct GameRepaired {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub admin: Pubkey,
+    pub session: Pubkey,
+    pub session_exists: bool,
+}
+
+#[event(discriminator = [109, 132, 74, 62, 173, 37, 209, 116])]
+pub struct SessionGambled {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub session: Pubkey,
+    pub stake: u64,
+}
+
+#[event(discriminator = [248, 49, 209, 127, 254, 126, 29, 105])]
+pub struct GambleSettled {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub session: Pubkey,
+    pub stake: u64,
+    pub won: bool,
+    pub payout: u64,
+}
+
+#[event(discriminator = [220, 23, 125, 255, 120, 193, 232, 89])]
+pub struct GoldenWindowScheduled {
+    pub schema_version: u8,
+    pub window: Pubkey,
+    pub window_id: u32,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub multiplier_bps: u16,
+}
+
+#[event(discriminator = [78, 23, 147, 82, 163, 70, 122, 98])]
+pub struct QuestChainCompleted {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub chain_id: u32,
+    pub reward_item_id: u32,
+    pub reward_quantity: u32,
+}
+
+#[event(discriminator = [244, 94, 134, 220, 141, 250, 197, 204])]
+pub struct ChallengeScored {
+    pub schema_version: u8,
+    pub game: Pubkey,
+    pub challenge_id: u32,
+    pub score: u64,
+    pub rank: Option<u8>,
+}
+
+#[event(discriminator = [5, 13, 107, 57, 11, 198, 74, 154])]
+pub struct GiftOffered {
+    pub schema_version: u8,
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub expires_at: i64,
+}
+
+#[event(discriminator = [186, 69, 33, 166, 235, 236, 118, 249])]
+pub struct GiftAccepted {
+    pub schema_version: u8,
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event(discriminator = [11, 28, 144, 13, 232, 160, 251, 5])]
+pub struct MessagePosted {
+    pub schema_version: u8,
+    pub season: Pubkey,
+    pub game: Pubkey,
+    pub index: u64,
+    pub text: String,
+}
@@ -0,0 +1,190 @@
+//! Identity pass (Civic-style gateway token) checks for reward claims.
+//!
+//! A gatekeeper network issues gateway tokens to wallets that passed its
+//! checks. When Config.gatekeeper_network is set, claiming rewards needs an
+//! active, unexpired token from that network for the player's wallet;
+//! playing never does. The token account belongs to the gateway program, so
+//! we only read its Borsh layout rather than pulling in its crate.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::pubkey;
+
+use crate::errors::ClickerError;
+use crate::state::Config;
+
+pub const GATEWAY_PROGRAM_ID: Pubkey = pubkey!("gatem74V238djXdzWnJf94Wo1DcnuGkfijbf3AuBhfs");
+
+#[derive(AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+enum GatewayTokenState {
+    Active,
+    Frozen,
+    Revoked,
+}
+
+// The gateway program's token account, in its field order
+#[derive(AnchorDeserialize)]
+struct GatewayToken {
+    _features: u8,
+    _parent_gateway_token: Option<Pubkey>,
+    owner_wallet: Pubkey,
+    _owner_identity: Option<Pubkey>,
+    gatekeeper_network: Pubkey,
+    _issuing_gatekeeper: Pubkey,
+    state: GatewayTokenState,
+    expire_time: Option<i64>,
+}
+
+/// Checks `wallet` holds a valid pass when the config asks for one. The
+/// token may be left out while Config.gatekeeper_network is unset.
+pub fn require_pass(config: &Config, wallet: &Pubkey, gateway_token: Option<&AccountInfo>, now: i64) -> Result<()> {
+    if config.gatekeeper_network == Pubkey::default() {
+        return Ok(());
+    }
+    let Some(account) = gateway_token else {
+        msg!("rewards need a gateway token from network {}", config.gatekeeper_network);
+        return Err(error!(ClickerError::GatewayTokenRequired));
+    };
+
+    if account.owner != &GATEWAY_PROGRAM_ID {
+        msg!("gateway token {} is owned by {}, not the gateway program", account.key, account.owner);
+        return Err(error!(ClickerError::InvalidGatewayToken));
+    }
+    let data = account.try_borrow_data()?;
+    let token = GatewayToken::deserialize(&mut &data[..]).map_err(|_| {
+        msg!("gateway token {} doesn't parse", account.key);
+        error!(ClickerError::InvalidGatewayToken)
+    })?;
+
+    if &token.owner_wallet != wallet || token.gatekeeper_network != config.gatekeeper_network {
+        msg!(
+            "gateway token is for {} on network {}, expected {} on {}",
+            token.owner_wallet,
+            token.gatekeeper_network,
+            wallet,
+            config.gatekeeper_network
+        );
+        return Err(error!(ClickerError::InvalidGatewayToken));
+    }
+    if token.state != GatewayTokenState::Active || token.expire_time.is_some_and(|expires| expires <= now) {
+        msg!("gateway token is {:?}, expiring {:?}, now is {}", token.state, token.expire_time, now);
+        return Err(error!(ClickerError::InvalidGatewayToken));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WALLET: Pubkey = Pubkey::new_from_array([1; 32]);
+    const NETWORK: Pubkey = Pubkey::new_from_array([2; 32]);
+    const NOW: i64 = 1_000;
+
+    // Active, Frozen and Revoked in GatewayTokenState order
+    const ACTIVE: u8 = 0;
+    const FROZEN: u8 = 1;
+    const REVOKED: u8 = 2;
+
+    fn config() -> Config {
+        Config { gatekeeper_network: NETWORK, ..Config::default() }
+    }
+
+    // Laid out by hand, so the test also pins the gateway program's layout
+    fn token(wallet: Pubkey, network: Pubkey, state: u8, expire_time: Option<i64>) -> Vec<u8> {
+        let mut data = vec![0]; // features
+        data.push(1);
+        data.extend([3; 32]); // parent_gateway_token
+        data.extend(wallet.to_bytes());
+        data.push(0); // owner_identity
+        data.extend(network.to_bytes());
+        data.extend([4; 32]); // issuing_gatekeeper
+        data.push(state);
+        match expire_time {
+            Some(expires) => {
+                data.push(1);
+                data.extend(expires.to_le_bytes());
+            }
+            None => data.push(0),
+        }
+        data
+    }
+
+    fn check(config: &Config, mut data: Vec<u8>, owner: &Pubkey, now: i64) -> Result<()> {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let account = AccountInfo::new(&key, false, false, &mut lamports, &mut data, owner, false, 0);
+        require_pass(config, &WALLET, Some(&account), now)
+    }
+
+    fn invalid() -> Result<()> {
+        Err(ClickerError::InvalidGatewayToken.into())
+    }
+
+    #[test]
+    fn no_network_needs_no_token() {
+        assert_eq!(require_pass(&Config::default(), &WALLET, None, NOW), Ok(()));
+    }
+
+    #[test]
+    fn network_needs_a_token() {
+        assert_eq!(
+            require_pass(&config(), &WALLET, None, NOW),
+            Err(ClickerError::GatewayTokenRequired.into())
+        );
+    }
+
+    #[test]
+    fn parses_an_active_token() {
+        let config = config();
+        assert_eq!(check(&config, token(WALLET, NETWORK, ACTIVE, None), &GATEWAY_PROGRAM_ID, NOW), Ok(()));
+        assert_eq!(
+            check(&config, token(WALLET, NETWORK, ACTIVE, Some(NOW + 1)), &GATEWAY_PROGRAM_ID, NOW),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn rejects_a_token_not_owned_by_the_gateway_program() {
+        let data = token(WALLET, NETWORK, ACTIVE, None);
+        assert_eq!(check(&config(), data, &crate::ID, NOW), invalid());
+    }
+
+    #[test]
+    fn rejects_a_token_that_does_not_parse() {
+        let mut data = token(WALLET, NETWORK, ACTIVE, None);
+        data.truncate(data.len() - 2);
+        assert_eq!(check(&config(), data, &GATEWAY_PROGRAM_ID, NOW), invalid());
+
+        let unknown_state = token(WALLET, NETWORK, 3, None);
+        assert_eq!(check(&config(), unknown_state, &GATEWAY_PROGRAM_ID, NOW), invalid());
+    }
+
+    #[test]
+    fn rejects_another_wallet_or_network() {
+        let other = Pubkey::new_from_array([9; 32]);
+        let config = config();
+        assert_eq!(check(&config, token(other, NETWORK, ACTIVE, None), &GATEWAY_PROGRAM_ID, NOW), invalid());
+        assert_eq!(check(&config, token(WALLET, other, ACTIVE, None), &GATEWAY_PROGRAM_ID, NOW), invalid());
+    }
+
+    #[test]
+    fn rejects_a_frozen_or_revoked_token() {
+        let config = config();
+        assert_eq!(check(&config, token(WALLET, NETWORK, FROZEN, None), &GATEWAY_PROGRAM_ID, NOW), invalid());
+        assert_eq!(check(&config, token(WALLET, NETWORK, REVOKED, None), &GATEWAY_PROGRAM_ID, NOW), invalid());
+    }
+
+    #[test]
+    fn rejects_a_token_from_its_expire_time() {
+        let config = config();
+        assert_eq!(
+            check(&config, token(WALLET, NETWORK, ACTIVE, Some(NOW)), &GATEWAY_PROGRAM_ID, NOW),
+            invalid()
+        );
+        assert_eq!(
+            check(&config, token(WALLET, NETWORK, ACTIVE, Some(NOW - 1)), &GATEWAY_PROGRAM_ID, NOW),
+            invalid()
+        );
+    }
+}
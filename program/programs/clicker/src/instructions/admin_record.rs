@@ -0,0 +1,179 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ClickerError;
+use crate::events::{AdminHandoverAccepted, AdminHandoverProposed, EVENT_SCHEMA_VERSION};
+use crate::program::Clicker;
+use crate::state::{AdminRole, Config, ProgramAdminRecord};
+
+/// Starts the record from the current admin and upgrade authority.
+pub fn handle_initialize_admin_record(ctx: Context<InitializeAdminRecord>) -> Result<()> {
+    let record: &mut Account<ProgramAdminRecord> = &mut ctx.accounts.admin_record;
+
+    record.admin = ctx.accounts.config.admin;
+    record.upgrade_authority = ctx.accounts.program_data.upgrade_authority_address.unwrap_or_default();
+    record.updated_at = Clock::get()?.unix_timestamp;
+    record.bump = ctx.bumps.admin_record;
+
+    Ok(())
+}
+
+/// Names the next config admin, who takes over with accept_admin. Proposing
+/// Pubkey::default() withdraws a proposal.
+pub fn handle_propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+    let record: &mut Account<ProgramAdminRecord> = &mut ctx.accounts.admin_record;
+
+    record.pending_admin = new_admin;
+    record.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(AdminHandoverProposed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        role: AdminRole::ConfigAdmin,
+        from: ctx.accounts.admin.key(),
+        to: new_admin,
+    });
+
+    Ok(())
+}
+
+pub fn handle_accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let config: &mut Account<Config> = &mut ctx.accounts.config;
+    let record: &mut Account<ProgramAdminRecord> = &mut ctx.accounts.admin_record;
+    let new_admin = ctx.accounts.new_admin.key();
+
+    if record.pending_admin == Pubkey::default() || record.pending_admin != new_admin {
+        msg!("signer {} is not the proposed admin {}", new_admin, record.pending_admin);
+        return Err(error!(ClickerError::NoPendingHandover));
+    }
+
+    let previous = config.admin;
+    config.admin = new_admin;
+    record.admin = new_admin;
+    record.pending_admin = Pubkey::default();
+    record.handovers += 1;
+    record.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(AdminHandoverAccepted {
+        schema_version: EVENT_SCHEMA_VERSION,
+        role: AdminRole::ConfigAdmin,
+        from: previous,
+        to: new_admin,
+    });
+
+    Ok(())
+}
+
+/// Announces the next upgrade authority before the loader's SetAuthority
+/// moves it. Proposing Pubkey::default() withdraws a proposal.
+pub fn handle_propose_upgrade_authority(
+    ctx: Context<ProposeUpgradeAuthority>,
+    new_authority: Pubkey
+) -> Result<()> {
+    let record: &mut Account<ProgramAdminRecord> = &mut ctx.accounts.admin_record;
+
+    record.pending_upgrade_authority = new_authority;
+    record.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(AdminHandoverProposed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        role: AdminRole::UpgradeAuthority,
+        from: ctx.accounts.authority.key(),
+        to: new_authority,
+    });
+
+    Ok(())
+}
+
+/// Signed by the proposed upgrade authority once the loader has moved the
+/// authority to it.
+pub fn handle_accept_upgrade_authority(ctx: Context<AcceptUpgradeAuthority>) -> Result<()> {
+    let record: &mut Account<ProgramAdminRecord> = &mut ctx.accounts.admin_record;
+    let new_authority = ctx.accounts.new_authority.key();
+
+    if record.pending_upgrade_authority == Pubkey::default() || record.pending_upgrade_authority != new_authority {
+        msg!(
+            "signer {} is not the proposed upgrade authority {}",
+            new_authority,
+            record.pending_upgrade_authority
+        );
+        return Err(error!(ClickerError::NoPendingHandover));
+    }
+    let loader_authority = ctx.accounts.program_data.upgrade_authority_address;
+    if loader_authority != Some(new_authority) {
+        msg!("loader still has upgrade authority {:?}", loader_authority);
+        return Err(error!(ClickerError::UpgradeAuthorityNotMoved));
+    }
+
+    let previous = record.upgrade_authority;
+    record.upgrade_authority = new_authority;
+    record.pending_upgrade_authority = Pubkey::default();
+    record.handovers += 1;
+    record.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(AdminHandoverAccepted {
+        schema_version: EVENT_SCHEMA_VERSION,
+        role: AdminRole::UpgradeAuthority,
+        from: previous,
+        to: new_authority,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeAdminRecord<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + ProgramAdminRecord::MAXIMUM_SIZE,
+        seeds = [ProgramAdminRecord::SEED],
+        bump
+    )]
+    pub admin_record: Account<'info, ProgramAdminRecord>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Clicker>,
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [ProgramAdminRecord::SEED], bump = admin_record.bump)]
+    pub admin_record: Account<'info, ProgramAdminRecord>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(mut, seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [ProgramAdminRecord::SEED], bump = admin_record.bump)]
+    pub admin_record: Account<'info, ProgramAdminRecord>,
+    pub new_admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeUpgradeAuthority<'info> {
+    #[account(mut, seeds = [ProgramAdminRecord::SEED], bump = admin_record.bump)]
+    pub admin_record: Account<'info, ProgramAdminRecord>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Clicker>,
+    #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()) @ ClickerError::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptUpgradeAuthority<'info> {
+    #[account(mut, seeds = [ProgramAdminRecord::SEED], bump = admin_record.bump)]
+    pub admin_record: Account<'info, ProgramAdminRecord>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Clicker>,
+    pub program_data: Account<'info, ProgramData>,
+    pub new_authority: Signer<'info>,
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ClickerError;
+use crate::state::{merkle_root_from_proof, ArchivedSession, Config, SessionArchive};
+
+pub fn handle_initialize_session_archive(ctx: Context<InitializeSessionArchive>) -> Result<()> {
+    let mut archive = ctx.accounts.session_archive.load_init()?;
+    archive.initialize();
+    archive.bump = ctx.bumps.session_archive;
+    Ok(())
+}
+
+/// Succeeds only if `record` sits at `index` in one of the archive's recent
+/// roots, letting other programs gate on a player's past sessions.
+pub fn handle_verify_archived_session(
+    ctx: Context<VerifyArchivedSession>,
+    record: ArchivedSession,
+    index: u64,
+    proof: Vec<[u8; 32]>
+) -> Result<()> {
+    let archive = ctx.accounts.session_archive.load()?;
+
+    if proof.len() != SessionArchive::DEPTH || index >= archive.next_index {
+        msg!(
+            "proof of {} nodes for leaf {}, archive has depth {} and {} leaves",
+            proof.len(),
+            index,
+            SessionArchive::DEPTH,
+            archive.next_index
+        );
+        return Err(error!(ClickerError::InvalidArchiveProof));
+    }
+
+    let root = merkle_root_from_proof(record.leaf(), index, &proof);
+    if !archive.is_known_root(&root) {
+        msg!("proof for leaf {} does not lead to a recent root", index);
+        return Err(error!(ClickerError::InvalidArchiveProof));
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeSessionArchive<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + SessionArchive::MAXIMUM_SIZE,
+        seeds = [SessionArchive::SEED],
+        bump
+    )]
+    pub session_archive: AccountLoader<'info, SessionArchive>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyArchivedSession<'info> {
+    #[account(seeds = [SessionArchive::SEED], bump = session_archive.load()?.bump)]
+    pub session_archive: AccountLoader<'info, SessionArchive>,
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Attestation, ClicksAttestation, Game};
+
+/// Creates the game's Attestation account. Anyone can pay for it.
+pub fn handle_initialize_attestation(ctx: Context<InitializeAttestation>) -> Result<()> {
+    let attestation: &mut Account<Attestation> = &mut ctx.accounts.attestation;
+
+    attestation.game = ctx.accounts.game.key();
+    attestation.bump = ctx.bumps.attestation;
+
+    Ok(())
+}
+
+/// Attests the game's lifetime clicks, which only ever grow through verified
+/// reveals and rewards; spending comes out of spendable_clicks instead.
+/// Callers get it as return data; a CPI caller must check the return data
+/// came from this program. Passing the Attestation account also records it
+/// there. Anyone can attest any game.
+pub fn handle_attest_total_clicks(ctx: Context<AttestTotalClicks>) -> Result<ClicksAttestation> {
+    let game: &Account<Game> = &ctx.accounts.game;
+
+    let attestation = ClicksAttestation {
+        game: game.key(),
+        player: game.player,
+        total_clicks: game.lifetime_clicks,
+        slot: Clock::get()?.slot,
+        created_at_slot: game.created_at_slot,
+        first_session_slot: game.first_session_slot,
+    };
+
+    if let Some(account) = ctx.accounts.attestation.as_mut() {
+        account.record(&attestation);
+    }
+
+    Ok(attestation)
+}
+
+#[derive(Accounts)]
+pub struct InitializeAttestation<'info> {
+    pub game: Account<'info, Game>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Attestation::MAXIMUM_SIZE,
+        seeds = [Attestation::SEED, game.key().as_ref()],
+        bump
+    )]
+    pub attestation: Account<'info, Attestation>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AttestTotalClicks<'info> {
+    pub game: Account<'info, Game>,
+    #[account(mut, has_one = game)]
+    pub attestation: Option<Account<'info, Attestation>>,
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ClickerError;
+use crate::events::{EVENT_SCHEMA_VERSION, SessionEnded};
+use crate::state::{Game, LiveState, Session};
+
+/// Ends the active session without crediting clicks. Pause exempt.
+pub fn handle_cancel_session(ctx: Context<CancelSession>) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+    let session: &mut Account<Session> = &mut ctx.accounts.session;
+
+    // Verify player ownership
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    // Verify this is the active session
+    if game.active_session() != Some(session.key()) {
+        msg!(
+            "session {} is not the active session {:?}",
+            session.key(),
+            game.active_session()
+        );
+        return Err(error!(ClickerError::InvalidSession));
+    }
+
+    // Clear active session
+    game.set_active_session(None);
+
+    // Mark session as cancelled (no clicks awarded)
+    session.revealed = true;
+    session.actual_clicks = 0;
+    session.end_time = Clock::get()?.unix_timestamp;
+
+    // Only cancelling in time is free; an expired session was abandoned
+    if session.is_expired(session.end_time) {
+        game.lose_reputation(Game::EXPIRED_SESSION_PENALTY);
+    }
+
+    ctx.accounts.live_state.sync(game, session.end_time);
+
+    emit_cpi!(SessionEnded {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+        session: session.key(),
+        player: game.player,
+        clicks: 0,
+        duration: session.end_time - session.start_time,
+        cancelled: true,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelSession<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    #[account(mut)]
+    pub session: Account<'info, Session>,
+    pub player: Signer<'info>,
+    #[account(mut, seeds = [LiveState::SEED, game.key().as_ref()], bump = live_state.bump)]
+    pub live_state: Account<'info, LiveState>,
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ClickerError;
+use crate::state::{Game, LiveState};
+
+pub fn handle_click(ctx: Context<Play>) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+
+    // only allow player to increment their own account
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    game.clicks += 1;
+
+    ctx.accounts.live_state.sync(game, Clock::get()?.unix_timestamp);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Play<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(mut, seeds = [LiveState::SEED, game.key().as_ref()], bump = live_state.bump)]
+    pub live_state: Account<'info, LiveState>,
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ClickerError;
+use crate::state::{GambleState, Raffle, Session};
+
+/// Closes a finished session and refunds its rent to whoever paid for it,
+/// which may be a sponsor rather than the player. Either of them can close
+/// it. A session holding raffle tickets stays until the raffle is drawn,
+/// since draw_raffle reads the winning session, and one with a pending
+/// gamble stays until settle_gamble resolves it. Pause exempt.
+pub fn handle_close_session(ctx: Context<CloseSession>) -> Result<()> {
+    let session: &Account<Session> = &ctx.accounts.session;
+    let authority = ctx.accounts.authority.key;
+
+    if authority != &session.player && authority != &session.rent_payer {
+        msg!("signer {} is neither player {} nor rent payer {}", authority, session.player, session.rent_payer);
+        return Err(error!(ClickerError::Unauthorized));
+    }
+
+    if !session.revealed {
+        msg!("session {} is still open", session.key());
+        return Err(error!(ClickerError::SessionNotFinished));
+    }
+
+    if session.gamble == Some(GambleState::Pending) {
+        msg!("session {} has a pending gamble", session.key());
+        return Err(error!(ClickerError::GamblePending));
+    }
+
+    if session.raffle_tickets > 0 {
+        match ctx.accounts.raffle.as_ref() {
+            Some(raffle) if raffle.drawn => {}
+            Some(raffle) => {
+                msg!("raffle {} is not drawn yet", raffle.period);
+                return Err(error!(ClickerError::RaffleNotDrawn));
+            }
+            None => {
+                msg!("session {} holds tickets in raffle {}", session.key(), session.raffle);
+                return Err(error!(ClickerError::RaffleNotDrawn));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseSession<'info> {
+    #[account(mut, close = rent_payer, has_one = rent_payer)]
+    pub session: Account<'info, Session>,
+    /// CHECK: receives the rent, must be the account that paid it
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+    // Only needed when the session holds raffle tickets
+    #[account(address = session.raffle)]
+    pub raffle: Option<Account<'info, Raffle>>,
+}
@@ -0,0 +1,258 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::BPS_ONE;
+use crate::errors::ClickerError;
+use crate::program::Clicker;
+use crate::state::{Config, HappyHour, LootboxReward, ReputationBand};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ConfigUpdate {
+    pub vrf_oracle: Option<Pubkey>,
+    pub target_epoch_clicks: Option<u64>,
+    pub attestor: Option<Pubkey>,
+    pub require_challenge: Option<bool>,
+    pub device_cosign_threshold: Option<u32>,
+    pub features: Option<u64>,
+    pub happy_hours: Option<[HappyHour; Config::MAX_HAPPY_HOURS]>,
+    pub lamports_per_gem: Option<u64>,
+    pub cosmetic_gem_price: Option<u64>,
+    pub refund_window: Option<i64>,
+    pub max_gems_per_day: Option<u64>,
+    pub max_gems_lifetime: Option<u64>,
+    pub max_session_duration: Option<i64>,
+    pub extension_gems_per_minute: Option<u64>,
+    pub mentor_min_level: Option<u32>,
+    pub mentorship_days: Option<u32>,
+    pub mentorship_bonus_bps: Option<u16>,
+    pub reputation_bands: Option<[ReputationBand; Config::MAX_REPUTATION_BANDS]>,
+    pub offerwall_oracle: Option<Pubkey>,
+    pub max_offers_per_day: Option<u32>,
+    pub lootbox_table: Option<[LootboxReward; Config::MAX_LOOTBOX_REWARDS]>,
+    pub lootbox_pity_threshold: Option<u32>,
+    pub lootboxes_per_quest: Option<u32>,
+    pub lootboxes_per_boss: Option<u32>,
+    pub marketplace_fee_bps: Option<u16>,
+    pub hardcore_cps_cap_bps: Option<u16>,
+    pub time_attack_seconds: Option<i64>,
+    pub time_attack_grace: Option<i64>,
+    pub endurance_optimal_seconds: Option<i64>,
+    pub endurance_decay_step: Option<i64>,
+    pub endurance_decay_bps: Option<u16>,
+    pub endurance_floor_bps: Option<u16>,
+    pub base_cps: Option<u64>,
+    pub coop_cap_bps: Option<u16>,
+    pub gamble_window: Option<i64>,
+    pub combo_bonus_bps: Option<u16>,
+    pub trophy_collection: Option<Pubkey>,
+    pub gatekeeper_network: Option<Pubkey>,
+    // Only tags a config that has none, see handle_update_config
+    pub cluster: Option<u8>,
+}
+
+/// `cluster` is the Config::CLUSTER_* tag of the cluster being deployed to.
+/// It can't be changed later, so a devnet config can't be talked into
+/// paying mainnet rewards.
+pub fn handle_initialize_config(ctx: Context<InitializeConfig>, vrf_oracle: Pubkey, cluster: u8) -> Result<()> {
+    let config: &mut Account<Config> = &mut ctx.accounts.config;
+
+    require_cluster_tag(cluster)?;
+
+    config.admin = *ctx.accounts.admin.key;
+    config.vrf_oracle = vrf_oracle;
+    config.cluster = cluster;
+    config.bump = ctx.bumps.config;
+
+    Ok(())
+}
+
+/// The admin itself only changes through propose_admin and accept_admin,
+/// which keep ProgramAdminRecord's trail. The cluster tag can only be set on
+/// a config created before it existed, and real-value features can only be
+/// switched on where the tag allows them.
+pub fn handle_update_config(ctx: Context<UpdateConfig>, update: ConfigUpdate) -> Result<()> {
+    let config: &mut Account<Config> = &mut ctx.accounts.config;
+
+    if let Some(cluster) = update.cluster {
+        if config.cluster != Config::CLUSTER_UNTAGGED {
+            msg!("config is already tagged cluster {}", config.cluster);
+            return Err(error!(ClickerError::ClusterAlreadyTagged));
+        }
+        require_cluster_tag(cluster)?;
+        config.cluster = cluster;
+    }
+
+    if let Some(vrf_oracle) = update.vrf_oracle {
+        config.vrf_oracle = vrf_oracle;
+    }
+    if let Some(target_epoch_clicks) = update.target_epoch_clicks {
+        config.target_epoch_clicks = target_epoch_clicks;
+    }
+    if let Some(attestor) = update.attestor {
+        config.attestor = attestor;
+    }
+    if let Some(require_challenge) = update.require_challenge {
+        config.require_challenge = require_challenge;
+    }
+    if let Some(device_cosign_threshold) = update.device_cosign_threshold {
+        config.device_cosign_threshold = device_cosign_threshold;
+    }
+    if let Some(features) = update.features {
+        if features & Config::REAL_VALUE_FEATURES != 0 && !config.pays_real_value() {
+            msg!(
+                "features {:#x} pay real tokens, cluster {} doesn't allow them",
+                features & Config::REAL_VALUE_FEATURES,
+                config.cluster
+            );
+            return Err(error!(ClickerError::RealValueOffMainnet));
+        }
+        config.features = features;
+    }
+    if let Some(happy_hours) = update.happy_hours {
+        if let Some(window) = happy_hours.iter().find(|window| !window.is_valid()) {
+            msg!(
+                "happy hour {}h..{}h at {} bps is invalid",
+                window.start_hour,
+                window.end_hour,
+                window.multiplier_bps
+            );
+            return Err(error!(ClickerError::InvalidHappyHour));
+        }
+        config.happy_hours = happy_hours;
+    }
+    if let Some(lamports_per_gem) = update.lamports_per_gem {
+        config.lamports_per_gem = lamports_per_gem;
+    }
+    if let Some(cosmetic_gem_price) = update.cosmetic_gem_price {
+        config.cosmetic_gem_price = cosmetic_gem_price;
+    }
+    if let Some(refund_window) = update.refund_window {
+        config.refund_window = refund_window;
+    }
+    if let Some(max_gems_per_day) = update.max_gems_per_day {
+        config.max_gems_per_day = max_gems_per_day;
+    }
+    if let Some(max_gems_lifetime) = update.max_gems_lifetime {
+        config.max_gems_lifetime = max_gems_lifetime;
+    }
+    if let Some(max_session_duration) = update.max_session_duration {
+        config.max_session_duration = max_session_duration;
+    }
+    if let Some(extension_gems_per_minute) = update.extension_gems_per_minute {
+        config.extension_gems_per_minute = extension_gems_per_minute;
+    }
+    if let Some(mentor_min_level) = update.mentor_min_level {
+        config.mentor_min_level = mentor_min_level;
+    }
+    if let Some(mentorship_days) = update.mentorship_days {
+        config.mentorship_days = mentorship_days;
+    }
+    if let Some(mentorship_bonus_bps) = update.mentorship_bonus_bps {
+        config.mentorship_bonus_bps = mentorship_bonus_bps;
+    }
+    if let Some(reputation_bands) = update.reputation_bands {
+        config.reputation_bands = reputation_bands;
+    }
+    if let Some(offerwall_oracle) = update.offerwall_oracle {
+        config.offerwall_oracle = offerwall_oracle;
+    }
+    if let Some(max_offers_per_day) = update.max_offers_per_day {
+        config.max_offers_per_day = max_offers_per_day;
+    }
+    if let Some(lootbox_table) = update.lootbox_table {
+        config.lootbox_table = lootbox_table;
+    }
+    if let Some(lootbox_pity_threshold) = update.lootbox_pity_threshold {
+        config.lootbox_pity_threshold = lootbox_pity_threshold;
+    }
+    if let Some(lootboxes_per_quest) = update.lootboxes_per_quest {
+        config.lootboxes_per_quest = lootboxes_per_quest;
+    }
+    if let Some(lootboxes_per_boss) = update.lootboxes_per_boss {
+        config.lootboxes_per_boss = lootboxes_per_boss;
+    }
+    if let Some(marketplace_fee_bps) = update.marketplace_fee_bps {
+        if marketplace_fee_bps > BPS_ONE {
+            msg!("marketplace fee of {} bps is over {}", marketplace_fee_bps, BPS_ONE);
+            return Err(error!(ClickerError::InvalidMarketplaceFee));
+        }
+        config.marketplace_fee_bps = marketplace_fee_bps;
+    }
+    if let Some(hardcore_cps_cap_bps) = update.hardcore_cps_cap_bps {
+        if hardcore_cps_cap_bps > BPS_ONE {
+            msg!("hardcore CPS cap of {} bps is over the usual cap", hardcore_cps_cap_bps);
+            return Err(error!(ClickerError::InvalidHardcoreCap));
+        }
+        config.hardcore_cps_cap_bps = hardcore_cps_cap_bps;
+    }
+    if let Some(time_attack_seconds) = update.time_attack_seconds {
+        config.time_attack_seconds = time_attack_seconds;
+    }
+    if let Some(time_attack_grace) = update.time_attack_grace {
+        config.time_attack_grace = time_attack_grace;
+    }
+    if let Some(endurance_optimal_seconds) = update.endurance_optimal_seconds {
+        config.endurance_optimal_seconds = endurance_optimal_seconds;
+    }
+    if let Some(endurance_decay_step) = update.endurance_decay_step {
+        config.endurance_decay_step = endurance_decay_step;
+    }
+    if let Some(endurance_decay_bps) = update.endurance_decay_bps {
+        config.endurance_decay_bps = endurance_decay_bps;
+    }
+    if let Some(endurance_floor_bps) = update.endurance_floor_bps {
+        if endurance_floor_bps > BPS_ONE {
+            msg!("endurance floor of {} bps is over full credit", endurance_floor_bps);
+            return Err(error!(ClickerError::InvalidEnduranceFloor));
+        }
+        config.endurance_floor_bps = endurance_floor_bps;
+    }
+    if let Some(base_cps) = update.base_cps {
+        config.base_cps = base_cps;
+    }
+    if let Some(coop_cap_bps) = update.coop_cap_bps {
+        config.coop_cap_bps = coop_cap_bps;
+    }
+    if let Some(gamble_window) = update.gamble_window {
+        config.gamble_window = gamble_window;
+    }
+    if let Some(combo_bonus_bps) = update.combo_bonus_bps {
+        config.combo_bonus_bps = combo_bonus_bps;
+    }
+    if let Some(trophy_collection) = update.trophy_collection {
+        config.trophy_collection = trophy_collection;
+    }
+    if let Some(gatekeeper_network) = update.gatekeeper_network {
+        config.gatekeeper_network = gatekeeper_network;
+    }
+
+    Ok(())
+}
+
+fn require_cluster_tag(cluster: u8) -> Result<()> {
+    if cluster == Config::CLUSTER_UNTAGGED || cluster > Config::CLUSTER_MAINNET {
+        msg!("cluster tag {} is not one of Config::CLUSTER_*", cluster);
+        return Err(error!(ClickerError::InvalidCluster));
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(init, payer = admin, space = 8 + Config::MAXIMUM_SIZE, seeds = [Config::SEED], bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    // only the program's upgrade authority may claim the admin role
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Clicker>,
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ ClickerError::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(mut, seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+}
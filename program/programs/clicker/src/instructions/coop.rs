@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ClickerError;
+use crate::instructions::{begin_session, index_session};
+use crate::state::{CoopSession, Config, FeatureGate, Game, LiveState, Session, SessionIndex};
+
+/// Starts a session for each of two games at once, signed by both players.
+/// Each commits to their own result and reveals it with end_session. Co-op
+/// runs take no attestor challenge, so they are off while one is required.
+pub fn handle_start_coop_session(
+    ctx: Context<StartCoopSession>,
+    host_commitment: [u8; 32],
+    guest_commitment: [u8; 32]
+) -> Result<()> {
+    let accounts = ctx.accounts;
+    let config: &Account<Config> = &accounts.config;
+
+    config.require_feature(Config::COOP_ENABLED)?;
+
+    let payer = accounts.payer.key();
+    begin_session(
+        &mut accounts.host_game,
+        &mut accounts.host_session,
+        accounts.host.key,
+        &payer,
+        config,
+        host_commitment,
+        None,
+    )?;
+    begin_session(
+        &mut accounts.guest_game,
+        &mut accounts.guest_session,
+        accounts.guest.key,
+        &payer,
+        config,
+        guest_commitment,
+        None,
+    )?;
+
+    index_session(
+        &accounts.host_game,
+        accounts.host_session_index.as_mut(),
+        accounts.host_session.key(),
+        &accounts.payer,
+        &accounts.system_program,
+    )?;
+    index_session(
+        &accounts.guest_game,
+        accounts.guest_session_index.as_mut(),
+        accounts.guest_session.key(),
+        &accounts.payer,
+        &accounts.system_program,
+    )?;
+
+    let coop: &mut Account<CoopSession> = &mut accounts.coop_session;
+    coop.games = [accounts.host_game.key(), accounts.guest_game.key()];
+    coop.sessions = [accounts.host_session.key(), accounts.guest_session.key()];
+    coop.bump = ctx.bumps.coop_session;
+
+    accounts.host_session.coop = coop.key();
+    accounts.guest_session.coop = coop.key();
+
+    let current_time = Clock::get()?.unix_timestamp;
+    accounts.host_live_state.sync(&accounts.host_game, current_time);
+    accounts.guest_live_state.sync(&accounts.guest_game, current_time);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct StartCoopSession<'info> {
+    #[account(mut)]
+    pub host_game: Account<'info, Game>,
+    #[account(mut, constraint = guest_game.key() != host_game.key() @ ClickerError::InvalidCoopSession)]
+    pub guest_game: Account<'info, Game>,
+    #[account(init, payer = payer, space = 8 + Session::MAXIMUM_SIZE)]
+    pub host_session: Account<'info, Session>,
+    #[account(init, payer = payer, space = 8 + Session::MAXIMUM_SIZE)]
+    pub guest_session: Account<'info, Session>,
+    pub host: Signer<'info>,
+    pub guest: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CoopSession::MAXIMUM_SIZE,
+        seeds = [CoopSession::SEED, host_session.key().as_ref()],
+        bump
+    )]
+    pub coop_session: Account<'info, CoopSession>,
+    pub system_program: Program<'info, System>,
+    // Each required once its game keeps one
+    #[account(mut, seeds = [SessionIndex::SEED, host_game.key().as_ref()], bump = host_session_index.bump)]
+    pub host_session_index: Option<Account<'info, SessionIndex>>,
+    #[account(mut, seeds = [SessionIndex::SEED, guest_game.key().as_ref()], bump = guest_session_index.bump)]
+    pub guest_session_index: Option<Account<'info, SessionIndex>>,
+    #[account(mut, seeds = [LiveState::SEED, host_game.key().as_ref()], bump = host_live_state.bump)]
+    pub host_live_state: Account<'info, LiveState>,
+    #[account(mut, seeds = [LiveState::SEED, guest_game.key().as_ref()], bump = guest_live_state.bump)]
+    pub guest_live_state: Account<'info, LiveState>,
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ClickerError;
+use crate::events::{EVENT_SCHEMA_VERSION, ItemCrafted};
+use crate::state::{Config, FeatureGate, FreezeGuard, Game, Inventory, Recipe, RecipeInput};
+
+pub fn handle_add_recipe(
+    ctx: Context<AddRecipe>,
+    recipe_id: u32,
+    inputs: [RecipeInput; Recipe::MAX_INPUTS],
+    output_item_id: u32,
+    output_quantity: u32
+) -> Result<()> {
+    let recipe: &mut Account<Recipe> = &mut ctx.accounts.recipe;
+
+    if inputs.iter().all(|input| input.quantity == 0) || output_quantity == 0 {
+        msg!("recipe {} has to use up and grant at least one item", recipe_id);
+        return Err(error!(ClickerError::InvalidRecipe));
+    }
+
+    recipe.recipe_id = recipe_id;
+    recipe.inputs = inputs;
+    recipe.output_item_id = output_item_id;
+    recipe.output_quantity = output_quantity;
+    recipe.active = true;
+    recipe.bump = ctx.bumps.recipe;
+
+    Ok(())
+}
+
+pub fn handle_set_recipe_active(ctx: Context<SetRecipeActive>, active: bool) -> Result<()> {
+    ctx.accounts.recipe.active = active;
+    Ok(())
+}
+
+/// Consumes the recipe's inputs, soonest to expire first, and grants its
+/// output without an expiry.
+pub fn handle_craft(ctx: Context<Craft>, recipe_id: u32) -> Result<()> {
+    let game = &ctx.accounts.game;
+    let recipe = &ctx.accounts.recipe;
+    let mut inventory = ctx.accounts.inventory.load_mut()?;
+
+    ctx.accounts.config.require_feature(Config::CRAFTING_ENABLED)?;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+    game.require_unfrozen()?;
+
+    if !recipe.active {
+        msg!("recipe {} is inactive", recipe_id);
+        return Err(error!(ClickerError::RecipeInactive));
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    for input in recipe.inputs.iter().filter(|input| input.quantity > 0) {
+        if !inventory.consume(input.item_id, input.quantity, current_time) {
+            msg!(
+                "recipe {} needs {} of item {}, inventory holds {}",
+                recipe_id,
+                input.quantity,
+                input.item_id,
+                inventory.count(input.item_id, current_time)
+            );
+            return Err(error!(ClickerError::InsufficientItems));
+        }
+    }
+
+    inventory.grant(recipe.output_item_id, recipe.output_quantity, 0, current_time)?;
+
+    emit!(ItemCrafted {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+        recipe_id,
+        item_id: recipe.output_item_id,
+        quantity: recipe.output_quantity,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(recipe_id: u32)]
+pub struct AddRecipe<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Recipe::MAXIMUM_SIZE,
+        seeds = [Recipe::SEED, &recipe_id.to_le_bytes()],
+        bump
+    )]
+    pub recipe: Account<'info, Recipe>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetRecipeActive<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [Recipe::SEED, &recipe.recipe_id.to_le_bytes()], bump = recipe.bump)]
+    pub recipe: Account<'info, Recipe>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(recipe_id: u32)]
+pub struct Craft<'info> {
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, has_one = game)]
+    pub inventory: AccountLoader<'info, Inventory>,
+    #[account(seeds = [Recipe::SEED, &recipe_id.to_le_bytes()], bump = recipe.bump)]
+    pub recipe: Account<'info, Recipe>,
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ClickerError;
+use crate::events::{EVENT_SCHEMA_VERSION, DeviceRegistered, DeviceRevoked};
+use crate::state::{Config, FeatureGate, FreezeGuard, Game};
+
+pub fn handle_register_device(ctx: Context<ManageDevice>, device: Pubkey) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+
+    ctx.accounts.config.require_feature(Config::DEVICES_ENABLED)?;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    game.require_unfrozen()?;
+
+    // Pubkey::default() marks a free slot, so it can't be a device itself
+    if device == Pubkey::default() {
+        msg!("device key is the default key");
+        return Err(error!(ClickerError::InvalidDevice));
+    }
+
+    if game.has_device(&device) {
+        msg!("device {} is already registered", device);
+        return Err(error!(ClickerError::DeviceAlreadyRegistered));
+    }
+
+    let slot = game
+        .devices
+        .iter_mut()
+        .find(|slot| **slot == Pubkey::default())
+        .ok_or_else(|| {
+            msg!("all {} device slots are in use", Game::MAX_DEVICES);
+            error!(ClickerError::DeviceLimitReached)
+        })?;
+    *slot = device;
+
+    emit!(DeviceRegistered {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+        device,
+    });
+
+    Ok(())
+}
+
+pub fn handle_revoke_device(ctx: Context<ManageDevice>, device: Pubkey) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    if !game.has_device(&device) {
+        msg!("device {} is not registered", device);
+        return Err(error!(ClickerError::DeviceNotRegistered));
+    }
+
+    for slot in game.devices.iter_mut().filter(|slot| **slot == device) {
+        *slot = Pubkey::default();
+    }
+
+    emit!(DeviceRevoked {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+        device,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ManageDevice<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
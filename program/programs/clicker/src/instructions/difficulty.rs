@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ClickerError;
+use crate::events::{EVENT_SCHEMA_VERSION, DifficultyAdjusted};
+use crate::state::{Config, FeatureGate, GlobalStats};
+
+pub fn handle_initialize_global_stats(ctx: Context<InitializeGlobalStats>) -> Result<()> {
+    let mut stats = ctx.accounts.global_stats.load_init()?;
+
+    stats.epoch_start = Clock::get()?.unix_timestamp;
+    stats.difficulty = GlobalStats::DIFFICULTY_ONE;
+    stats.bump = ctx.bumps.global_stats;
+
+    Ok(())
+}
+
+pub fn handle_adjust_difficulty(ctx: Context<AdjustDifficulty>) -> Result<()> {
+    let mut stats = ctx.accounts.global_stats.load_mut()?;
+
+    ctx.accounts.config.require_feature(Config::DIFFICULTY_ENABLED)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    if current_time < stats.epoch_start + GlobalStats::EPOCH_SECONDS {
+        msg!(
+            "epoch ends at {}, now is {}",
+            stats.epoch_start + GlobalStats::EPOCH_SECONDS,
+            current_time
+        );
+        return Err(error!(ClickerError::EpochNotFinished));
+    }
+
+    let previous_difficulty = stats.difficulty;
+    let epoch_clicks = stats.epoch_clicks;
+    // epoch_clicks covers every epoch since the last crank, not just one
+    let elapsed_epochs = (current_time - stats.epoch_start) / GlobalStats::EPOCH_SECONDS;
+
+    stats.difficulty = stats.retarget(
+        ctx.accounts.config.target_epoch_clicks,
+        elapsed_epochs as u64,
+    );
+    stats.epoch_clicks = 0;
+    // skip over any epochs nobody cranked so the boundaries stay aligned
+    stats.epoch_start += elapsed_epochs * GlobalStats::EPOCH_SECONDS;
+
+    emit!(DifficultyAdjusted {
+        schema_version: EVENT_SCHEMA_VERSION,
+        epoch_clicks,
+        previous_difficulty,
+        difficulty: stats.difficulty,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeGlobalStats<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + GlobalStats::MAXIMUM_SIZE,
+        seeds = [GlobalStats::SEED],
+        bump
+    )]
+    pub global_stats: AccountLoader<'info, GlobalStats>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AdjustDifficulty<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [GlobalStats::SEED], bump = global_stats.load()?.bump)]
+    pub global_stats: AccountLoader<'info, GlobalStats>,
+}
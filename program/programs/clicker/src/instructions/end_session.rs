@@ -0,0 +1,709 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+
+use crate::commitment::reveal_commitment;
+use crate::constants::{BPS_ONE, SECONDS_PER_DAY};
+use crate::cps_cap::CpsCap;
+use crate::diagnostics::RevealDiagnostics;
+use crate::errors::ClickerError;
+use crate::events::{
+    ChallengeScored, EVENT_SCHEMA_VERSION, GameFallen, LevelUp, NewPersonalRecord, SessionArchived, SessionEnded,
+    SessionNotArchived, WorldBossDefeated, WorldRecordBroken,
+};
+use crate::math::Fixed;
+use crate::modifiers::{apply_modifiers, SeasonStat};
+use crate::reveal_guard::{verify_attested_reveal, verify_composition};
+use crate::state::{
+    ArchivedSession, BossContribution, ChallengeEntry, Config, CoopSession, FeatureGate, Game, GlobalStats,
+    GoldenWindow, LiveState, Mentorship, PartnerCollection, PassSeason, PersonalRecord, Raffle, Session,
+    SessionArchive, SessionResult, WeeklyChallenge, WorldBoss, WorldRecord,
+};
+
+/// Returns the clicks credited for the session, boosts included. Repeating a
+/// reveal that already landed returns the same value without touching any
+/// state.
+///
+/// Everything here is judged by the clock when the reveal executes, never by
+/// a recent blockhash or slot hash, so a reveal can be signed as soon as the
+/// session starts and sent later with a durable nonce. Keep it that way:
+/// anything bound to a slot should use the session's start.
+pub fn handle_end_session(
+    ctx: Context<EndSession>,
+    result: SessionResult,
+    nonce: u64,
+    max_session_duration: i64
+) -> Result<u64> {
+    let clicks = result.clicks;
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+    let session: &mut Account<Session> = &mut ctx.accounts.session;
+    let mut global_stats = ctx.accounts.global_stats.load_mut()?;
+
+    // Verify player ownership
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    // Verify the commitment matches the revealed values
+    let revealed_hash = reveal_commitment(
+        &result,
+        nonce,
+        ctx.accounts.player.key,
+        session.challenge.as_ref(),
+    );
+    let commitment_matches = revealed_hash == session.commitment;
+
+    // A resubmitted reveal whose first attempt landed is a successful no-op
+    if session.revealed {
+        if session.game == game.key() && commitment_matches && session.result == result {
+            return Ok(session.credited_clicks);
+        }
+        msg!(
+            "session already revealed {} clicks, got {}",
+            session.actual_clicks,
+            clicks
+        );
+        return Err(error!(ClickerError::SessionAlreadyRevealed));
+    }
+
+    // Verify this is the active session
+    if game.active_session() != Some(session.key()) {
+        msg!(
+            "session {} is not the active session {:?}",
+            session.key(),
+            game.active_session()
+        );
+        return Err(error!(ClickerError::InvalidSession));
+    }
+
+    // A co-op session reveals alongside its CoopSession, which keeps the
+    // pair's clicks for the shared cap
+    let coop_slot = match (session.is_coop(), ctx.accounts.coop_session.as_ref()) {
+        (false, None) => None,
+        (true, Some(coop)) if coop.key() == session.coop => coop.position(&session.key()),
+        _ => {
+            msg!("session belongs to co-op session {}, which must be passed", session.coop);
+            return Err(error!(ClickerError::InvalidCoopSession));
+        }
+    };
+
+    // The season modifying reveals has to be passed, so leaving it out can't
+    // dodge a nerf
+    let modifier_season = ctx.accounts.config.modifier_season;
+    let season = match ctx.accounts.season.as_ref() {
+        Some(season) if season.key() == modifier_season => Some(&**season),
+        None if modifier_season == Pubkey::default() => None,
+        _ => {
+            msg!("season {} modifies reveals and must be passed", modifier_season);
+            return Err(error!(ClickerError::ModifierSeasonRequired));
+        }
+    };
+
+    // A session entered in a weekly challenge plays by its rules, so the
+    // challenge and the game's entry have to come along
+    let challenge_rules = match (ctx.accounts.weekly_challenge.as_ref(), ctx.accounts.challenge_entry.as_ref()) {
+        (Some(weekly_challenge), Some(entry))
+            if weekly_challenge.key() == session.weekly_challenge && entry.challenge == session.weekly_challenge =>
+        {
+            Some(weekly_challenge.rules)
+        }
+        (None, None) if !session.in_weekly_challenge() => None,
+        _ => {
+            msg!("session entered challenge {}, which must be passed with the game's entry", session.weekly_challenge);
+            return Err(error!(ClickerError::ChallengeEntryRequired));
+        }
+    };
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let session_duration = current_time - session.start_time;
+    // Pauses (up to the allowance) don't earn clicking time, and a time
+    // attack only clicks for its window however late the reveal lands
+    let (active_duration, clicking_duration) = if session.is_time_attack() {
+        (session.time_attack_seconds, session.time_attack_seconds)
+    } else {
+        (session_duration - session.paused_seconds(current_time), session_duration)
+    };
+    let cps_cap = CpsCap::for_game(
+        &ctx.accounts.config,
+        &global_stats,
+        game,
+        apply_modifiers(season, game, current_time, SeasonStat::CpsCap),
+    );
+    let cps_cap = match challenge_rules {
+        Some(rules) if rules.max_cps > 0 => cps_cap.at_most(rules.max_cps as u64),
+        _ => cps_cap,
+    };
+    let max_clicks = cps_cap.max_clicks(active_duration);
+    let max_cps = cps_cap.max_cps();
+    // Bought extensions stretch the caller's limit as well as the deadline
+    let max_session_duration = max_session_duration.saturating_add(session.extended_seconds);
+
+    // Published ahead of any failure below so clients can see why
+    let diagnostics = RevealDiagnostics {
+        expected_commitment: session.commitment,
+        provided_hash: revealed_hash,
+        clicks,
+        max_clicks,
+        session_duration,
+        max_session_duration,
+    };
+
+    // Enforce maximum session duration (prevents infinite offline clicking).
+    // The program-wide limit was fixed into the deadline at start; callers
+    // can still ask for a tighter one.
+    if session_duration > max_session_duration || session.is_expired(current_time) {
+        msg!(
+            "session lasted {}s, max is {}s with {}s extension, deadline was {}",
+            session_duration,
+            max_session_duration,
+            session.extended_seconds,
+            session.deadline
+        );
+        diagnostics.publish();
+        return Err(error!(ClickerError::SessionTooLong));
+    }
+
+    if session.is_time_attack() && session_duration < session.time_attack_seconds {
+        msg!(
+            "time attack of {}s revealed after {}s",
+            session.time_attack_seconds,
+            session_duration
+        );
+        return Err(error!(ClickerError::TimeAttackTooShort));
+    }
+
+    if !commitment_matches {
+        msg!("reveal of {} clicks does not match the commitment", clicks);
+        diagnostics.publish();
+        return Err(error!(ClickerError::InvalidCommitment));
+    }
+
+    // High-value reveals must be co-signed by one of the player's registered devices
+    let threshold = ctx.accounts.config.device_cosign_threshold;
+    if threshold > 0 && clicks >= threshold {
+        match &ctx.accounts.device {
+            Some(device) if game.has_device(device.key) => {}
+            _ => {
+                msg!("{} clicks needs a device co-signer, threshold is {}", clicks, threshold);
+                return Err(error!(ClickerError::DeviceSignatureRequired));
+            }
+        }
+    }
+
+    // Attested and high-stakes reveals are checked against the transaction
+    // carrying them, see reveal_guard
+    let high_stakes = threshold > 0 && clicks >= threshold;
+    if ctx.accounts.config.has_feature(Config::REVEAL_GUARD_ENABLED) && (session.challenge.is_some() || high_stakes) {
+        let Some(instructions) = ctx.accounts.instructions.as_ref() else {
+            msg!("reveal is guarded and needs the instructions sysvar");
+            return Err(error!(ClickerError::InstructionsSysvarRequired));
+        };
+        if session.challenge.is_some() {
+            verify_attested_reveal(instructions, &ctx.accounts.config.attestor, &session.key(), &revealed_hash)?;
+        }
+        verify_composition(instructions)?;
+    }
+
+    // The partner's revealed clicks count against the pair's cap
+    let coop_cap = match (ctx.accounts.coop_session.as_ref(), coop_slot) {
+        (Some(coop), Some(slot)) if ctx.accounts.config.coop_cap_bps > 0 => Some((
+            coop.clicks[1 - slot],
+            max_clicks * ctx.accounts.config.coop_cap_bps as u64 / BPS_ONE as u64,
+        )),
+        _ => None,
+    };
+
+    // A hardcore game falls on the first reveal failing these rather than
+    // getting to retry it, so they return Ok with the game locked
+    let check_result = || -> Result<()> {
+        // Enforce reasonable clicking rate (the game's CpsCap, 10 CPS at base
+        // difficulty before bonuses). Misses are taps too.
+        if clicks as u64 + result.misses as u64 > max_clicks {
+            msg!(
+                "{} clicks and {} misses in {}s active exceeds the cap of {}",
+                clicks,
+                result.misses,
+                active_duration,
+                max_clicks
+            );
+            diagnostics.publish();
+            return Err(error!(ClickerError::UnrealisticClickRate));
+        }
+
+        // Bursts can't hide in the average: every minute gets the cap for the
+        // seconds it covers. Minutes are wall-clock, so paused ones are just empty.
+        if result.histogram_total() != clicks as u64 {
+            msg!(
+                "histogram adds up to {} clicks, revealed {}",
+                result.histogram_total(),
+                clicks
+            );
+            return Err(error!(ClickerError::InvalidSessionResult));
+        }
+        for (minute, (minute_clicks, seconds)) in result.minutes(clicking_duration).enumerate() {
+            let minute_cap = cps_cap.max_clicks(seconds);
+            if minute_clicks as u64 > minute_cap {
+                msg!(
+                    "{} clicks in minute {} ({}s) exceeds the cap of {}",
+                    minute_clicks,
+                    minute,
+                    seconds,
+                    minute_cap
+                );
+                diagnostics.publish();
+                return Err(error!(ClickerError::UnrealisticClickRate));
+            }
+        }
+
+        // The fastest second can't beat the per-second cap
+        if result.max_cps_observed as u64 > max_cps {
+            msg!(
+                "peak of {} clicks/s exceeds the cap of {}",
+                result.max_cps_observed,
+                max_cps
+            );
+            diagnostics.publish();
+            return Err(error!(ClickerError::UnrealisticClickRate));
+        }
+
+        // ...nor can the session average beat its fastest second
+        if clicks as u64 > result.max_cps_observed as u64 * active_duration.max(0) as u64 {
+            msg!(
+                "{} clicks in {}s active averages above the reported peak of {} clicks/s",
+                clicks,
+                active_duration,
+                result.max_cps_observed
+            );
+            return Err(error!(ClickerError::InvalidSessionResult));
+        }
+
+        if result.combos > clicks {
+            msg!("{} combos from only {} clicks", result.combos, clicks);
+            return Err(error!(ClickerError::InvalidSessionResult));
+        }
+
+        // A streak is consecutive combos, so it is bounded by them and,
+        // through the clicks, by the rate cap above
+        if result.max_combo > result.combos {
+            msg!("streak of {} combos out of only {}", result.max_combo, result.combos);
+            return Err(error!(ClickerError::InvalidSessionResult));
+        }
+
+        if let Some((partner_clicks, shared_cap)) = coop_cap {
+            if partner_clicks as u64 + clicks as u64 > shared_cap {
+                msg!(
+                    "{} clicks on top of the partner's {} exceeds the pair's cap of {}",
+                    clicks,
+                    partner_clicks,
+                    shared_cap
+                );
+                return Err(error!(ClickerError::CoopCapExceeded));
+            }
+        }
+
+        Ok(())
+    };
+    if let Err(err) = check_result() {
+        if !game.is_hardcore() {
+            return Err(err);
+        }
+        game.fall();
+        session.revealed = true;
+        session.actual_clicks = 0;
+        session.end_time = current_time;
+        ctx.accounts.live_state.sync(game, current_time);
+        emit!(GameFallen {
+            schema_version: EVENT_SCHEMA_VERSION,
+            game: game.key(),
+            player: game.player,
+            session: session.key(),
+        });
+        return Ok(0);
+    }
+
+    // Update game state; boosts apply to the player's total, not the global stats
+    let happy_hour = if game.is_hardcore() {
+        Some(Fixed::ONE)
+    } else {
+        ctx.accounts.config.happy_hour_multiplier(session.start_time, current_time)
+    };
+    // Endurance sessions lose credit for running past the optimal duration
+    let endurance = if session.endurance {
+        ctx.accounts.config.endurance_multiplier(session_duration)
+    } else {
+        Fixed::ONE
+    };
+    // Sustained combo streaks pay a bonus; the reveal checks bound the streak
+    let combo = if game.is_hardcore() {
+        Some(Fixed::ONE)
+    } else {
+        ctx.accounts.config.combo_multiplier(result.max_combo, clicks)
+    };
+    // A golden window pays its bonus on the part of the session it covers
+    let golden = match ctx.accounts.golden_window.as_ref() {
+        Some(window) => {
+            ctx.accounts.config.require_feature(Config::GOLDEN_WINDOWS_ENABLED)?;
+            if game.is_hardcore() {
+                msg!("hardcore game {} takes no golden window", game.key());
+                return Err(error!(ClickerError::HardcoreNoBoosts));
+            }
+            if window.overlap(session.start_time, current_time) == 0 {
+                msg!(
+                    "window {} runs {}..{}, session ran {}..{}",
+                    window.window_id,
+                    window.start_time,
+                    window.end_time,
+                    session.start_time,
+                    current_time
+                );
+                return Err(error!(ClickerError::GoldenWindowNotActive));
+            }
+            window.multiplier(session.start_time, current_time)
+        }
+        None => Some(Fixed::ONE),
+    };
+    // Multipliers chain before anything rounds, then the clicks round down once
+    // A deactivated collection stops boosting games that verified with it
+    let partner_active = ctx
+        .accounts
+        .partner_collection
+        .as_ref()
+        .is_some_and(|partner| partner.active);
+    let mut credited_clicks = game
+        .click_multiplier(current_time, partner_active)
+        .zip(happy_hour)
+        .and_then(|(boosts, happy_hour)| boosts.checked_mul(happy_hour))
+        .zip(golden)
+        .and_then(|(multiplier, golden)| multiplier.checked_mul(golden))
+        .zip(combo)
+        .and_then(|(multiplier, combo)| multiplier.checked_mul(combo))
+        .and_then(|multiplier| {
+            multiplier.checked_mul(apply_modifiers(season, game, current_time, SeasonStat::Clicks))
+        })
+        .and_then(|multiplier| multiplier.checked_mul(endurance))
+        .and_then(|multiplier| multiplier.apply(clicks as u64))
+        .ok_or_else(|| {
+            msg!("multipliers on {} clicks overflow", clicks);
+            error!(ClickerError::MultiplierOverflow)
+        })?;
+
+    // A mentored player and their mentor each earn the mentorship bonus; the
+    // mentor's share waits on the Mentorship account for claim_mentor_bonus
+    if let Some(mentorship) = ctx.accounts.mentorship.as_mut() {
+        ctx.accounts.config.require_feature(Config::MENTORSHIP_ENABLED)?;
+        if game.is_hardcore() {
+            msg!("hardcore game {} takes no mentorship bonus", game.key());
+            return Err(error!(ClickerError::HardcoreNoBoosts));
+        }
+        let bonus = mentorship.bonus_clicks(clicks as u64, current_time).ok_or_else(|| {
+            msg!("mentorship bonus on {} clicks overflows", clicks);
+            error!(ClickerError::MultiplierOverflow)
+        })?;
+        credited_clicks += bonus;
+        mentorship.mentor_bonus_clicks += bonus;
+    }
+    game.credit_clicks(credited_clicks);
+    let xp = apply_modifiers(season, game, current_time, SeasonStat::Xp)
+        .apply(credited_clicks)
+        .ok_or_else(|| {
+            msg!("season xp modifier on {} clicks overflows", credited_clicks);
+            error!(ClickerError::MultiplierOverflow)
+        })?;
+    if let Some(level) = game.award_xp(xp) {
+        emit!(LevelUp {
+            schema_version: EVENT_SCHEMA_VERSION,
+            game: game.key(),
+            player: game.player,
+            level,
+            xp: game.xp,
+        });
+    }
+    let day = current_time.div_euclid(SECONDS_PER_DAY);
+    for (record, previous, value) in game.update_records(&result, day).into_iter().flatten() {
+        emit!(NewPersonalRecord {
+            schema_version: EVENT_SCHEMA_VERSION,
+            game: game.key(),
+            player: game.player,
+            record,
+            previous,
+            value,
+        });
+    }
+    if session.is_time_attack() && clicks > game.best_time_attack_clicks {
+        emit!(NewPersonalRecord {
+            schema_version: EVENT_SCHEMA_VERSION,
+            game: game.key(),
+            player: game.player,
+            record: PersonalRecord::TimeAttackClicks,
+            previous: game.best_time_attack_clicks as u64,
+            value: clicks as u64,
+        });
+        game.best_time_attack_clicks = clicks;
+    }
+    // Boosts are left out, so the endurance board compares decay alone. Decay
+    // is at most 1x, so the clicks still fit a u32.
+    let endurance_clicks = endurance.apply(clicks as u64).unwrap_or_default() as u32;
+    if session.endurance && endurance_clicks > game.best_endurance_clicks {
+        emit!(NewPersonalRecord {
+            schema_version: EVENT_SCHEMA_VERSION,
+            game: game.key(),
+            player: game.player,
+            record: PersonalRecord::EnduranceClicks,
+            previous: game.best_endurance_clicks as u64,
+            value: endurance_clicks as u64,
+        });
+        game.best_endurance_clicks = endurance_clicks;
+    }
+
+    // Challenges score the play itself, before any boosts. The week has to
+    // still be running when the reveal lands.
+    if let (Some(rules), Some(weekly_challenge), Some(entry)) = (
+        challenge_rules,
+        ctx.accounts.weekly_challenge.as_mut(),
+        ctx.accounts.challenge_entry.as_mut(),
+    ) {
+        let score = rules.score(clicks, result.max_combo);
+        if !weekly_challenge.is_running(current_time) {
+            msg!("challenge {} ended at {}, score not recorded", weekly_challenge.challenge_id, weekly_challenge.ends_at);
+        } else if score > entry.best_score {
+            entry.best_score = score;
+            let rank = weekly_challenge.record(game.key(), score);
+            emit!(ChallengeScored {
+                schema_version: EVENT_SCHEMA_VERSION,
+                game: game.key(),
+                challenge_id: weekly_challenge.challenge_id,
+                score,
+                rank,
+            });
+        }
+    }
+
+    // Take the world record; a different previous holder is marked on their game
+    if let Some(world_record) = ctx.accounts.world_record.as_mut() {
+        if clicks > world_record.clicks {
+            if world_record.has_holder() && world_record.game != game.key() {
+                match ctx.accounts.previous_record_holder.as_mut() {
+                    Some(holder) if holder.key() == world_record.game => {
+                        holder.former_record_holder = true;
+                    }
+                    _ => {
+                        msg!("world record is held by game {}, its account must be passed", world_record.game);
+                        return Err(error!(ClickerError::RecordHolderRequired));
+                    }
+                }
+            }
+
+            emit!(WorldRecordBroken {
+                schema_version: EVENT_SCHEMA_VERSION,
+                game: game.key(),
+                player: game.player,
+                session: session.key(),
+                clicks,
+                previous_game: world_record.game,
+                previous_player: world_record.player,
+                previous_clicks: world_record.clicks,
+            });
+            world_record.clicks = clicks;
+            world_record.game = game.key();
+            world_record.player = game.player;
+            world_record.session = session.key();
+            world_record.set_at = current_time;
+        }
+    }
+
+    // Revealed clicks hit the world boss. A boss that died or left mid-session,
+    // or a game locked out for the day, just deals no damage rather than
+    // failing the reveal.
+    match (ctx.accounts.world_boss.as_mut(), ctx.accounts.boss_contribution.as_mut()) {
+        (Some(boss), Some(contribution)) => {
+            ctx.accounts.config.require_feature(Config::WORLD_BOSS_ENABLED)?;
+            if contribution.boss != boss.key() {
+                msg!("contribution is for boss {}, not {}", contribution.boss, boss.key());
+                return Err(error!(ClickerError::InvalidWorldBoss));
+            }
+
+            if !boss.is_alive(current_time) {
+                msg!("boss {} is gone, no damage dealt", boss.boss_id);
+            } else if contribution.last_attack_day == day {
+                // one attack window per boss per day, so a whale can't solo it
+                msg!("game already attacked boss {} today", boss.boss_id);
+            } else {
+                contribution.last_attack_day = day;
+                contribution.damage += boss.take_damage(clicks as u64);
+                if boss.is_defeated() {
+                    boss.defeated_at = current_time;
+                    emit!(WorldBossDefeated {
+                        schema_version: EVENT_SCHEMA_VERSION,
+                        boss: boss.key(),
+                        boss_id: boss.boss_id,
+                        final_blow: game.key(),
+                        defeated_at: current_time,
+                    });
+                }
+            }
+        }
+        (None, None) => {}
+        _ => {
+            msg!("world boss and contribution must be passed together");
+            return Err(error!(ClickerError::InvalidWorldBoss));
+        }
+    }
+
+    game.reputation = game.reputation.saturating_add(Game::REPUTATION_PER_REVEAL);
+    game.last_session_end = current_time;
+    game.set_active_session(None);
+
+    global_stats.total_clicks += clicks as u64;
+    global_stats.epoch_clicks += clicks as u64;
+
+    if let (Some(coop), Some(slot)) = (ctx.accounts.coop_session.as_mut(), coop_slot) {
+        coop.clicks[slot] = clicks;
+    }
+
+    // Mark session as revealed
+    session.revealed = true;
+    session.actual_clicks = clicks;
+    session.credited_clicks = credited_clicks;
+    session.result = result;
+    session.end_time = current_time;
+
+    // Credit raffle tickets for this period; the session keeps its ticket range.
+    // Best effort: between periods, or once a raffle is closed, there is no
+    // raffle to credit and the reveal mustn't fail for it.
+    let raffles_enabled = ctx.accounts.config.has_feature(Config::RAFFLES_ENABLED);
+    match ctx.accounts.raffle.as_mut() {
+        Some(raffle) if raffles_enabled && raffle.is_open(current_time) => {
+            let tickets = clicks as u64 / Raffle::CLICKS_PER_TICKET;
+            if tickets > 0 {
+                session.raffle = raffle.key();
+                session.raffle_ticket_start = raffle.total_tickets;
+                session.raffle_tickets = tickets;
+                raffle.total_tickets += tickets;
+            }
+        }
+        Some(raffle) => {
+            msg!(
+                "raffle {} is open {}..{}, now is {}; no tickets credited",
+                raffle.period,
+                raffle.start_time,
+                raffle.end_time,
+                current_time
+            );
+        }
+        None => {}
+    }
+
+    // Append the reveal to the archive so it stays provable without this account
+    match ctx.accounts.session_archive.as_ref() {
+        Some(session_archive) => {
+            let record = ArchivedSession {
+                session: session.key(),
+                game: game.key(),
+                player: game.player,
+                clicks,
+                start_time: session.start_time,
+                end_time: current_time,
+            };
+            // A full archive mustn't stop reveals; the session just goes unarchived
+            match session_archive.load_mut()?.append(record.leaf()) {
+                Some(index) => {
+                    emit_cpi!(SessionArchived {
+                        schema_version: EVENT_SCHEMA_VERSION,
+                        index,
+                        record,
+                    });
+                }
+                None => {
+                    msg!("session archive is full at {} leaves", 1u64 << SessionArchive::DEPTH);
+                    emit_cpi!(SessionNotArchived {
+                        schema_version: EVENT_SCHEMA_VERSION,
+                        session: session.key(),
+                        game: game.key(),
+                    });
+                }
+            }
+        }
+        None if ctx.accounts.config.has_feature(Config::ARCHIVE_ENABLED) => {
+            msg!("archiving is enabled but no session archive was passed");
+            return Err(error!(ClickerError::ArchiveRequired));
+        }
+        None => {}
+    }
+
+    ctx.accounts.live_state.sync(game, current_time);
+
+    emit_cpi!(SessionEnded {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+        session: session.key(),
+        player: game.player,
+        clicks,
+        duration: session_duration,
+        cancelled: false,
+    });
+
+    Ok(credited_clicks)
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct EndSession<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    #[account(mut)]
+    pub session: Account<'info, Session>,
+    pub player: Signer<'info>,
+    // Config and GlobalStats can only ever be created at their singleton PDAs,
+    // so the owner and discriminator checks already pin the address. Skipping
+    // the seeds re-derivation saves a create_program_address per account on
+    // the hottest instruction.
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub global_stats: AccountLoader<'info, GlobalStats>,
+    // The current period's raffle, credited with tickets while it is open
+    #[account(mut, seeds = [Raffle::SEED, &raffle.period.to_le_bytes()], bump = raffle.bump)]
+    pub raffle: Option<Account<'info, Raffle>>,
+    pub device: Option<Signer<'info>>,
+    // Singleton like Config, see above
+    #[account(mut)]
+    pub session_archive: Option<AccountLoader<'info, SessionArchive>>,
+    #[account(mut, seeds = [LiveState::SEED, game.key().as_ref()], bump = live_state.bump)]
+    pub live_state: Account<'info, LiveState>,
+    // Singleton like Config, see above
+    #[account(mut)]
+    pub world_record: Option<Account<'info, WorldRecord>>,
+    // Needed only when the reveal takes the world record from another game.
+    // Passing the revealing game twice would let one write clobber the other.
+    #[account(mut, constraint = previous_record_holder.key() != game.key() @ ClickerError::RecordHolderRequired)]
+    pub previous_record_holder: Option<Account<'info, Game>>,
+    #[account(mut, constraint = mentorship.mentee_game == game.key() @ ClickerError::InvalidMentorship)]
+    pub mentorship: Option<Account<'info, Mentorship>>,
+    #[account(mut)]
+    pub world_boss: Option<Account<'info, WorldBoss>>,
+    #[account(mut, has_one = game)]
+    pub boss_contribution: Option<Account<'info, BossContribution>>,
+    #[account(mut)]
+    pub coop_session: Option<Account<'info, CoopSession>>,
+    #[account(
+        seeds = [GoldenWindow::SEED, &golden_window.window_id.to_le_bytes()],
+        bump = golden_window.bump
+    )]
+    pub golden_window: Option<Account<'info, GoldenWindow>>,
+    // The game's partner_collection; without it the partner boost doesn't apply
+    #[account(
+        seeds = [PartnerCollection::SEED, game.partner_collection.as_ref()],
+        bump = partner_collection.bump
+    )]
+    pub partner_collection: Option<Account<'info, PartnerCollection>>,
+    // Required while Config.modifier_season is set
+    pub season: Option<Account<'info, PassSeason>>,
+    // Both required for a session entered in a weekly challenge
+    #[account(mut)]
+    pub weekly_challenge: Option<Account<'info, WeeklyChallenge>>,
+    #[account(mut, has_one = game)]
+    pub challenge_entry: Option<Account<'info, ChallengeEntry>>,
+    /// CHECK: the instructions sysvar, checked by address. Required for
+    /// reveals reveal_guard checks.
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ClickerError;
+use crate::state::{Config, FeatureGate, FreezeGuard, Game, Session};
+
+/// Buys `extra_seconds` more for an open session with gems, charged per
+/// started minute. Pushes back both the deadline and the caller's own
+/// max_session_duration in end_session.
+pub fn handle_extend_session(ctx: Context<ExtendSession>, extra_seconds: i64) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+    let session: &mut Account<Session> = &mut ctx.accounts.session;
+    let config = &ctx.accounts.config;
+
+    config.require_feature(Config::GEMS_ENABLED)?;
+
+    // Verify player ownership
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+    game.require_unfrozen()?;
+
+    // Verify this is the active session
+    if game.active_session() != Some(session.key()) {
+        msg!(
+            "session {} is not the active session {:?}",
+            session.key(),
+            game.active_session()
+        );
+        return Err(error!(ClickerError::InvalidSession));
+    }
+
+    if config.extension_gems_per_minute == 0 {
+        msg!("session extensions are switched off");
+        return Err(error!(ClickerError::ExtensionsNotForSale));
+    }
+
+    if session.is_time_attack() {
+        msg!("session {} is a time attack", session.key());
+        return Err(error!(ClickerError::TimeAttackFixed));
+    }
+
+    if extra_seconds <= 0
+        || session.extended_seconds + extra_seconds > Session::MAX_EXTENSION_SECONDS
+    {
+        msg!(
+            "extending by {}s after {}s already bought exceeds the {}s limit",
+            extra_seconds,
+            session.extended_seconds,
+            Session::MAX_EXTENSION_SECONDS
+        );
+        return Err(error!(ClickerError::ExtensionLimitReached));
+    }
+
+    let price = (extra_seconds as u64).div_ceil(60) * config.extension_gems_per_minute;
+    if game.gems < price {
+        msg!("costs {} gems, player has {}", price, game.gems);
+        return Err(error!(ClickerError::InsufficientGems));
+    }
+    game.gems -= price;
+
+    session.extended_seconds += extra_seconds;
+    session.deadline = session.deadline.saturating_add(extra_seconds);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExtendSession<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    #[account(mut)]
+    pub session: Account<'info, Session>,
+    pub player: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
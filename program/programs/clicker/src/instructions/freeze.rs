@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ClickerError;
+use crate::events::{EVENT_SCHEMA_VERSION, GameFreezeChanged};
+use crate::state::{Config, FeatureGate, Game};
+
+/// Lets an owner who suspects their key is compromised lock the game. With
+/// `unfreeze_requires_guardian` set, the stolen key alone can't undo it.
+pub fn handle_freeze_game(ctx: Context<FreezeGame>, unfreeze_requires_guardian: bool) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+
+    ctx.accounts.config.require_feature(Config::FREEZE_ENABLED)?;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    if game.frozen {
+        msg!("game {} is already frozen", game.key());
+        return Err(error!(ClickerError::GameFrozen));
+    }
+
+    if unfreeze_requires_guardian && game.guardian_threshold == 0 {
+        msg!("guardian unfreeze requested but the game has no guardians");
+        return Err(error!(ClickerError::InvalidGuardianThreshold));
+    }
+
+    game.frozen = true;
+    game.unfreeze_requires_guardian = unfreeze_requires_guardian;
+
+    emit!(GameFreezeChanged {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+        frozen: true,
+    });
+
+    Ok(())
+}
+
+/// Guardian co-signers, when required, are passed as remaining accounts.
+pub fn handle_unfreeze_game(ctx: Context<FreezeGame>) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    if !game.frozen {
+        msg!("game {} is not frozen", game.key());
+        return Err(error!(ClickerError::GameNotFrozen));
+    }
+
+    if game.unfreeze_requires_guardian
+        && !ctx
+            .remaining_accounts
+            .iter()
+            .any(|account| account.is_signer && game.is_guardian(account.key))
+    {
+        msg!("unfreezing game {} needs a guardian signature", game.key());
+        return Err(error!(ClickerError::InsufficientGuardianSignatures));
+    }
+
+    game.frozen = false;
+    game.unfreeze_requires_guardian = false;
+
+    emit!(GameFreezeChanged {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+        frozen: false,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FreezeGame<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
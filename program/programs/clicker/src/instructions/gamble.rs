@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+
+use crate::constants::SECONDS_PER_DAY;
+use crate::errors::ClickerError;
+use crate::events::{EVENT_SCHEMA_VERSION, GambleSettled, SessionGambled};
+use crate::state::{Config, FeatureGate, FreezeGuard, GambleState, Game, LiveState, Session};
+use crate::oracle::oracle_randomness;
+
+/// Stakes the clicks a reveal just credited on double or nothing, once per
+/// UTC day and within gamble_window of the reveal. The clicks leave the
+/// game's balance until settle_gamble resolves the stake, while its
+/// lifetime score keeps them either way; staking before the
+/// oracle signs means a player can't see the outcome and only gamble winners.
+/// XP stays with the play that earned it.
+pub fn handle_gamble_session(ctx: Context<GambleSession>) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+    let session: &mut Account<Session> = &mut ctx.accounts.session;
+    let config: &Account<Config> = &ctx.accounts.config;
+
+    config.require_feature(Config::GAMBLE_ENABLED | Config::VRF_ENABLED)?;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+    game.require_unfrozen()?;
+    if game.is_hardcore() {
+        msg!("hardcore game {} can't gamble", game.key());
+        return Err(error!(ClickerError::HardcoreNoBoosts));
+    }
+    if !session.revealed || session.void_reason.is_some() || session.credited_clicks == 0 {
+        msg!("session {} credited nothing to gamble", session.key());
+        return Err(error!(ClickerError::NothingToGamble));
+    }
+    if session.gamble.is_some() {
+        msg!("session {} was already gambled", session.key());
+        return Err(error!(ClickerError::AlreadyGambled));
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    if current_time > session.end_time + config.gamble_window {
+        msg!(
+            "gamble window closed at {}, now is {}",
+            session.end_time + config.gamble_window,
+            current_time
+        );
+        return Err(error!(ClickerError::GambleWindowClosed));
+    }
+    let day = current_time.div_euclid(SECONDS_PER_DAY);
+    if game.last_gamble_day == day {
+        msg!("game {} already gambled on day {}", game.key(), day);
+        return Err(error!(ClickerError::GambleUsedToday));
+    }
+
+    let stake = session.credited_clicks;
+    if !game.spend_clicks(stake) {
+        msg!("stake is {} clicks, player has {}", stake, game.spendable_clicks);
+        return Err(error!(ClickerError::InsufficientClicks));
+    }
+
+    game.last_gamble_day = day;
+    session.gamble = Some(GambleState::Pending);
+    session.gamble_slot = Clock::get()?.slot;
+
+    ctx.accounts.live_state.sync(game, current_time);
+
+    emit!(SessionGambled {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+        session: session.key(),
+        stake,
+    });
+
+    Ok(())
+}
+
+/// Resolves a pending gamble. The trusted oracle signs (session ||
+/// "gamble" || gamble slot), see oracle.rs, so no player can know the
+/// outcome before the gamble lands; anyone may submit its signature. An even draw
+/// pays back twice the stake, an odd one keeps it. Pause exempt, so a
+/// stake already taken is never stuck.
+pub fn handle_settle_gamble(ctx: Context<SettleGamble>) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+    let session: &mut Account<Session> = &mut ctx.accounts.session;
+    let config: &Account<Config> = &ctx.accounts.config;
+
+    if session.gamble != Some(GambleState::Pending) {
+        msg!("session {} has no pending gamble", session.key());
+        return Err(error!(ClickerError::GambleNotPending));
+    }
+
+    let mut seed = [0u8; 46];
+    seed[..32].copy_from_slice(session.key().as_ref());
+    seed[32..38].copy_from_slice(b"gamble");
+    seed[38..].copy_from_slice(&session.gamble_slot.to_le_bytes());
+    let randomness = oracle_randomness(&ctx.accounts.instructions, &config.vrf_oracle, &seed)?;
+
+    let won = randomness & 1 == 0;
+    let payout = if won { session.credited_clicks * 2 } else { 0 };
+    game.return_clicks(payout);
+    session.gamble = Some(if won { GambleState::Won } else { GambleState::Lost });
+
+    ctx.accounts.live_state.sync(game, Clock::get()?.unix_timestamp);
+
+    emit!(GambleSettled {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+        session: session.key(),
+        stake: session.credited_clicks,
+        won,
+        payout,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct GambleSession<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(mut, has_one = game)]
+    pub session: Account<'info, Session>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [LiveState::SEED, game.key().as_ref()], bump = live_state.bump)]
+    pub live_state: Account<'info, LiveState>,
+}
+
+#[derive(Accounts)]
+pub struct SettleGamble<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    #[account(mut, has_one = game)]
+    pub session: Account<'info, Session>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    /// CHECK: the instructions sysvar, checked by address
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    #[account(mut, seeds = [LiveState::SEED, game.key().as_ref()], bump = live_state.bump)]
+    pub live_state: Account<'info, LiveState>,
+}
@@ -0,0 +1,279 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::constants::SECONDS_PER_DAY;
+use crate::errors::ClickerError;
+use crate::events::{EVENT_SCHEMA_VERSION, GemsPurchased, PurchaseRefunded};
+use crate::state::{Config, FeatureGate, FreezeGuard, Game, PassSeason, Receipt, SeasonPass, Treasury};
+
+/// Things gems can be spent on. Gems are only ever bought, never earned,
+/// so they are kept apart from the click balance.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GemPurchase {
+    Cosmetic { id: u8 },
+}
+
+pub fn handle_initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+    ctx.accounts.treasury.bump = ctx.bumps.treasury;
+    Ok(())
+}
+
+pub fn handle_buy_gems(ctx: Context<BuyGems>, gems: u64) -> Result<()> {
+    let config = &ctx.accounts.config;
+
+    config.require_feature(Config::GEMS_ENABLED)?;
+
+    if &ctx.accounts.game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, ctx.accounts.game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    if config.lamports_per_gem == 0 {
+        msg!("gem sales are switched off");
+        return Err(error!(ClickerError::GemsNotForSale));
+    }
+
+    let lamports = gems
+        .checked_mul(config.lamports_per_gem)
+        .ok_or_else(|| {
+            msg!("{} gems at {} lamports each overflows", gems, config.lamports_per_gem);
+            error!(ClickerError::GemsNotForSale)
+        })?;
+
+    // Caps keep paying players from running away with the leaderboard
+    let current_time = Clock::get()?.unix_timestamp;
+    let day = current_time.div_euclid(SECONDS_PER_DAY);
+    let bought_today = ctx.accounts.game.gems_bought_on(day) + gems;
+    let bought_lifetime = ctx.accounts.game.gems_bought_lifetime + gems;
+    if (config.max_gems_per_day > 0 && bought_today > config.max_gems_per_day)
+        || (config.max_gems_lifetime > 0 && bought_lifetime > config.max_gems_lifetime)
+    {
+        msg!(
+            "{} gems would bring today to {} of {} and lifetime to {} of {}",
+            gems,
+            bought_today,
+            config.max_gems_per_day,
+            bought_lifetime,
+            config.max_gems_lifetime
+        );
+        return Err(error!(ClickerError::PurchaseCapReached));
+    }
+
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.player.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+            },
+        ),
+        lamports,
+    )?;
+
+    ctx.accounts.treasury.total_received += lamports;
+    ctx.accounts.game.gems += gems;
+    ctx.accounts.game.purchase_count += 1;
+    ctx.accounts.game.gem_purchase_day = day;
+    ctx.accounts.game.gems_bought_today = bought_today;
+    ctx.accounts.game.gems_bought_lifetime = bought_lifetime;
+
+    let receipt: &mut Account<Receipt> = &mut ctx.accounts.receipt;
+    receipt.game = ctx.accounts.game.key();
+    receipt.buyer = *ctx.accounts.player.key;
+    receipt.gems = gems;
+    receipt.lamports = lamports;
+    receipt.purchased_at = current_time;
+    receipt.bump = ctx.bumps.receipt;
+
+    emit!(GemsPurchased {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: ctx.accounts.game.key(),
+        player: *ctx.accounts.player.key,
+        gems,
+        lamports,
+    });
+
+    Ok(())
+}
+
+pub fn handle_spend_gems(ctx: Context<SpendGems>, purchase: GemPurchase) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+    let config = &ctx.accounts.config;
+
+    config.require_feature(Config::GEMS_ENABLED)?;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+    game.require_unfrozen()?;
+
+    let price = match purchase {
+        GemPurchase::Cosmetic { id } => {
+            if id >= 64 {
+                msg!("cosmetic id {} is out of range", id);
+                return Err(error!(ClickerError::InvalidCosmetic));
+            }
+            config.cosmetic_gem_price
+        }
+    };
+
+    if game.gems < price {
+        msg!("costs {} gems, player has {}", price, game.gems);
+        return Err(error!(ClickerError::InsufficientGems));
+    }
+    game.gems -= price;
+
+    match purchase {
+        GemPurchase::Cosmetic { id } => game.cosmetics |= 1 << id,
+    }
+
+    Ok(())
+}
+
+/// Takes the granted gems back and returns the payment from the treasury to
+/// the game's current player, who after a guardian recovery is no longer
+/// the receipt's buyer. Gems already spent can't be refunded. A premium pass
+/// bought with SOL loses its premium track, unless a premium tier was
+/// already claimed. Pause exempt.
+pub fn handle_refund_purchase(ctx: Context<RefundPurchase>) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+    let receipt: &Account<Receipt> = &ctx.accounts.receipt;
+    let refund_window = ctx.accounts.config.refund_window;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+    game.require_unfrozen()?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    if refund_window == 0 || current_time > receipt.purchased_at + refund_window {
+        msg!(
+            "purchased at {} with a {}s refund window, now is {}",
+            receipt.purchased_at,
+            refund_window,
+            current_time
+        );
+        return Err(error!(ClickerError::RefundWindowClosed));
+    }
+
+    if game.gems < receipt.gems {
+        msg!("refund takes back {} gems, player has {}", receipt.gems, game.gems);
+        return Err(error!(ClickerError::InsufficientGems));
+    }
+    game.gems -= receipt.gems;
+
+    if receipt.pass != Pubkey::default() {
+        let (Some(pass), Some(season)) = (ctx.accounts.pass.as_mut(), ctx.accounts.season.as_ref()) else {
+            msg!("receipt is for pass {}, which wasn't passed with its season", receipt.pass);
+            return Err(error!(ClickerError::PassRequired));
+        };
+        if pass.key() != receipt.pass || season.key() != pass.season {
+            msg!("receipt is for pass {}, got pass {} of season {}", receipt.pass, pass.key(), season.key());
+            return Err(error!(ClickerError::PassRequired));
+        }
+        let claimed_premium = season
+            .tiers
+            .iter()
+            .enumerate()
+            .any(|(tier, reward)| reward.premium_only && pass.is_claimed(tier as u8));
+        if claimed_premium {
+            msg!("pass {} already claimed a premium tier", pass.key());
+            return Err(error!(ClickerError::PremiumRewardClaimed));
+        }
+        pass.premium = false;
+    }
+
+    // a refunded purchase no longer counts towards the caps
+    game.gems_bought_lifetime = game.gems_bought_lifetime.saturating_sub(receipt.gems);
+    if game.gem_purchase_day == receipt.purchased_at.div_euclid(SECONDS_PER_DAY) {
+        game.gems_bought_today = game.gems_bought_today.saturating_sub(receipt.gems);
+    }
+
+    // The treasury must stay rent exempt after paying out
+    let treasury_info = ctx.accounts.treasury.to_account_info();
+    let rent_exempt = Rent::get()?.minimum_balance(treasury_info.data_len());
+    let available = treasury_info.lamports().saturating_sub(rent_exempt);
+    if available < receipt.lamports {
+        msg!("refund of {} lamports, treasury has {} above rent", receipt.lamports, available);
+        return Err(error!(ClickerError::TreasuryUnderfunded));
+    }
+    **treasury_info.try_borrow_mut_lamports()? -= receipt.lamports;
+    **ctx.accounts.player.try_borrow_mut_lamports()? += receipt.lamports;
+
+    emit!(PurchaseRefunded {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+        receipt: receipt.key(),
+        gems: receipt.gems,
+        lamports: receipt.lamports,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Treasury::MAXIMUM_SIZE,
+        seeds = [Treasury::SEED],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BuyGems<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [Treasury::SEED], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(
+        init,
+        payer = player,
+        space = 8 + Receipt::MAXIMUM_SIZE,
+        seeds = [Receipt::SEED, game.key().as_ref(), &game.purchase_count.to_le_bytes()],
+        bump
+    )]
+    pub receipt: Account<'info, Receipt>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RefundPurchase<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [Treasury::SEED], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut, close = player, has_one = game)]
+    pub receipt: Account<'info, Receipt>,
+    /// Required when the receipt is for a premium pass, with its season.
+    #[account(mut, has_one = game)]
+    pub pass: Option<Account<'info, SeasonPass>>,
+    pub season: Option<Account<'info, PassSeason>>,
+}
+
+#[derive(Accounts)]
+pub struct SpendGems<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ClickerError;
+use crate::events::{EVENT_SCHEMA_VERSION, GiftAccepted, GiftOffered};
+use crate::state::{Config, FeatureGate, FreezeGuard, Game, Gift};
+
+/// Moves `amount` of the sender's spendable clicks into escrow for the
+/// recipient to accept before `expires_at`. One gift per pair of games can
+/// be pending. Nothing reaches the recipient without their say.
+pub fn handle_offer_gift(ctx: Context<OfferGift>, amount: u64, expires_at: i64) -> Result<()> {
+    let sender: &mut Account<Game> = &mut ctx.accounts.sender;
+    let recipient = &ctx.accounts.recipient;
+
+    ctx.accounts.config.require_feature(Config::GIFTING_ENABLED)?;
+
+    if &sender.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, sender.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+    sender.require_unfrozen()?;
+    // Hardcore games play alone: no boosts in, no propping others up
+    if sender.is_hardcore() || recipient.is_hardcore() {
+        msg!("gifts between {} and {} involve a hardcore game", sender.key(), recipient.key());
+        return Err(error!(ClickerError::HardcoreNoBoosts));
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    if amount == 0 || expires_at <= current_time || expires_at > current_time + Gift::MAX_EXPIRY {
+        msg!("gift of {} expiring at {} is invalid, now is {}", amount, expires_at, current_time);
+        return Err(error!(ClickerError::InvalidGift));
+    }
+    if !sender.spend_clicks(amount) {
+        msg!("gift is {} clicks, player has {}", amount, sender.spendable_clicks);
+        return Err(error!(ClickerError::InsufficientClicks));
+    }
+
+    let gift: &mut Account<Gift> = &mut ctx.accounts.gift;
+    gift.sender = sender.key();
+    gift.recipient = recipient.key();
+    gift.amount = amount;
+    gift.expires_at = expires_at;
+    gift.rent_payer = ctx.accounts.payer.key();
+    gift.bump = ctx.bumps.gift;
+
+    emit!(GiftOffered {
+        schema_version: EVENT_SCHEMA_VERSION,
+        sender: gift.sender,
+        recipient: gift.recipient,
+        amount,
+        expires_at,
+    });
+
+    Ok(())
+}
+
+/// Takes a pending gift into the recipient's spendable clicks. Gifted
+/// clicks were never played for, so lifetime_clicks doesn't move.
+pub fn handle_accept_gift(ctx: Context<AcceptGift>) -> Result<()> {
+    let recipient: &mut Account<Game> = &mut ctx.accounts.recipient;
+    let gift = &ctx.accounts.gift;
+
+    ctx.accounts.config.require_feature(Config::GIFTING_ENABLED)?;
+
+    if &recipient.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, recipient.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    if current_time >= gift.expires_at {
+        msg!("gift expired at {}, now is {}", gift.expires_at, current_time);
+        return Err(error!(ClickerError::GiftExpired));
+    }
+
+    recipient.return_clicks(gift.amount);
+
+    emit!(GiftAccepted {
+        schema_version: EVENT_SCHEMA_VERSION,
+        sender: gift.sender,
+        recipient: gift.recipient,
+        amount: gift.amount,
+    });
+
+    Ok(())
+}
+
+/// Returns an expired gift to the sender. Anyone can crank it, since the
+/// clicks can only go back where they came from. Pause exempt.
+pub fn handle_reclaim_expired_gift(ctx: Context<ReclaimExpiredGift>) -> Result<()> {
+    let sender: &mut Account<Game> = &mut ctx.accounts.sender;
+    let gift = &ctx.accounts.gift;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    if current_time < gift.expires_at {
+        msg!("gift can be accepted until {}, now is {}", gift.expires_at, current_time);
+        return Err(error!(ClickerError::GiftNotExpired));
+    }
+
+    sender.return_clicks(gift.amount);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct OfferGift<'info> {
+    #[account(mut)]
+    pub sender: Account<'info, Game>,
+    #[account(constraint = recipient.key() != sender.key() @ ClickerError::InvalidGift)]
+    pub recipient: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Gift::MAXIMUM_SIZE,
+        seeds = [Gift::SEED, sender.key().as_ref(), recipient.key().as_ref()],
+        bump
+    )]
+    pub gift: Account<'info, Gift>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptGift<'info> {
+    #[account(mut)]
+    pub recipient: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(mut, close = rent_payer, has_one = recipient, has_one = rent_payer)]
+    pub gift: Account<'info, Gift>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    /// CHECK: gets the gift's rent back, checked against the gift
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimExpiredGift<'info> {
+    #[account(mut)]
+    pub sender: Account<'info, Game>,
+    #[account(mut, close = rent_payer, has_one = sender, has_one = rent_payer)]
+    pub gift: Account<'info, Gift>,
+    /// CHECK: gets the gift's rent back, checked against the gift
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
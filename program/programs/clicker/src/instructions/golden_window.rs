@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::BPS_ONE;
+use crate::errors::ClickerError;
+use crate::events::{EVENT_SCHEMA_VERSION, GoldenWindowScheduled};
+use crate::state::{Config, FeatureGate, GoldenWindow};
+
+/// Schedules a golden window. Reveals that pass it get its multiplier on
+/// the part of the session it covers; it may start right away.
+pub fn handle_schedule_golden_window(
+    ctx: Context<ScheduleGoldenWindow>,
+    window_id: u32,
+    start_time: i64,
+    end_time: i64,
+    multiplier_bps: u16
+) -> Result<()> {
+    ctx.accounts.config.require_feature(Config::GOLDEN_WINDOWS_ENABLED)?;
+
+    if end_time <= start_time || multiplier_bps < BPS_ONE {
+        msg!("window {}..{} at {} bps is invalid", start_time, end_time, multiplier_bps);
+        return Err(error!(ClickerError::InvalidGoldenWindow));
+    }
+
+    let window: &mut Account<GoldenWindow> = &mut ctx.accounts.window;
+    window.window_id = window_id;
+    window.start_time = start_time;
+    window.end_time = end_time;
+    window.multiplier_bps = multiplier_bps;
+    window.scheduled_by = ctx.accounts.admin.key();
+    window.bump = ctx.bumps.window;
+
+    emit!(GoldenWindowScheduled {
+        schema_version: EVENT_SCHEMA_VERSION,
+        window: window.key(),
+        window_id,
+        start_time,
+        end_time,
+        multiplier_bps,
+    });
+
+    Ok(())
+}
+
+/// Closes a golden window and refunds its rent to the admin who scheduled
+/// it. The admin can call it off at any time; anyone can crank away a window
+/// that has ended.
+pub fn handle_close_golden_window(ctx: Context<CloseGoldenWindow>, _window_id: u32) -> Result<()> {
+    let window = &ctx.accounts.window;
+    let authority = ctx.accounts.authority.key;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    if authority != &ctx.accounts.config.admin && current_time < window.end_time {
+        msg!("window {} runs until {}, now is {}", window.window_id, window.end_time, current_time);
+        return Err(error!(ClickerError::GoldenWindowNotOver));
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(window_id: u32)]
+pub struct ScheduleGoldenWindow<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + GoldenWindow::MAXIMUM_SIZE,
+        seeds = [GoldenWindow::SEED, &window_id.to_le_bytes()],
+        bump
+    )]
+    pub window: Account<'info, GoldenWindow>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(window_id: u32)]
+pub struct CloseGoldenWindow<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        close = scheduled_by,
+        has_one = scheduled_by,
+        seeds = [GoldenWindow::SEED, &window_id.to_le_bytes()],
+        bump = window.bump
+    )]
+    pub window: Account<'info, GoldenWindow>,
+    /// CHECK: receives the rent, must be the admin that paid it
+    #[account(mut)]
+    pub scheduled_by: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ClickerError;
+use crate::events::{EVENT_SCHEMA_VERSION, IdentityBound, IdentityUnbound};
+use crate::state::{FreezeGuard, Game};
+
+/// Binds an off-chain identity (email, Discord ID) to the game by a salted
+/// hash, so support can match a player without any PII going on-chain. The
+/// operator keeps the salt; the program only stores what it is given.
+pub fn handle_bind_identity(ctx: Context<ManageIdentity>, hash: [u8; 32]) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    game.require_unfrozen()?;
+
+    if game.has_identity() {
+        msg!("game {} already has an identity bound", game.key());
+        return Err(error!(ClickerError::IdentityAlreadyBound));
+    }
+
+    if hash == [0u8; 32] {
+        msg!("identity hash is empty");
+        return Err(error!(ClickerError::InvalidIdentityHash));
+    }
+
+    game.identity_hash = hash;
+    game.identity_bound_at = Clock::get()?.unix_timestamp;
+
+    emit!(IdentityBound {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+        identity_hash: hash,
+    });
+
+    Ok(())
+}
+
+/// Only possible IDENTITY_UNBIND_COOLDOWN after binding, so a stolen key
+/// can't quickly strip the identity support would recover the game by.
+pub fn handle_unbind_identity(ctx: Context<ManageIdentity>) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    if !game.has_identity() {
+        msg!("game {} has no identity bound", game.key());
+        return Err(error!(ClickerError::NoIdentityBound));
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let unbind_at = game.identity_bound_at + Game::IDENTITY_UNBIND_COOLDOWN;
+    if current_time < unbind_at {
+        msg!("identity can be unbound at {}, now is {}", unbind_at, current_time);
+        return Err(error!(ClickerError::IdentityCooldown));
+    }
+
+    game.identity_hash = [0u8; 32];
+    game.identity_bound_at = 0;
+
+    emit!(IdentityUnbound {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ManageIdentity<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Game, LiveState};
+
+/// Creates a game along with its LiveState.
+pub fn handle_initialize(ctx: Context<Initialize>) -> Result<()> {
+    initialize_game(ctx.accounts, ctx.bumps.live_state, Game::FLAG_FIXED_LAYOUT)
+}
+
+/// Like initialize, but the game plays hardcore for its whole life.
+pub fn handle_initialize_hardcore(ctx: Context<Initialize>) -> Result<()> {
+    initialize_game(
+        ctx.accounts,
+        ctx.bumps.live_state,
+        Game::FLAG_FIXED_LAYOUT | Game::FLAG_HARDCORE,
+    )
+}
+
+fn initialize_game(accounts: &mut Initialize, live_state_bump: u8, flags: u8) -> Result<()> {
+    let game: &mut Account<Game> = &mut accounts.game;
+    let player: &Signer = &accounts.player;
+
+    game.player = *player.key;
+    game.flags = flags;
+    game.clicks = 0;
+    let clock = Clock::get()?;
+    game.last_session_end = clock.unix_timestamp;
+    game.created_at_slot = clock.slot;
+
+    let live_state: &mut Account<LiveState> = &mut accounts.live_state;
+    live_state.game = game.key();
+    live_state.bump = live_state_bump;
+    live_state.sync(game, clock.unix_timestamp);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(init, payer = payer, space = 8 + Game::MAXIMUM_SIZE)]
+    pub game: Account<'info, Game>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + LiveState::MAXIMUM_SIZE,
+        seeds = [LiveState::SEED, game.key().as_ref()],
+        bump
+    )]
+    pub live_state: Account<'info, LiveState>,
+    pub player: Signer<'info>,
+    // Rent can be sponsored, e.g. by a relayer onboarding players without SOL
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
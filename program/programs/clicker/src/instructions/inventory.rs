@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ClickerError;
+use crate::events::{EVENT_SCHEMA_VERSION, ItemConsumed, ItemTransferred};
+use crate::state::{FreezeGuard, Game, Inventory};
+
+/// Creates the game's Inventory. Anyone can pay for it. Items are granted
+/// by the instructions that reward them; there is no instruction to mint
+/// items directly.
+pub fn handle_initialize_inventory(ctx: Context<InitializeInventory>) -> Result<()> {
+    let mut inventory = ctx.accounts.inventory.load_init()?;
+    inventory.game = ctx.accounts.game.key();
+    inventory.bump = ctx.bumps.inventory;
+    Ok(())
+}
+
+/// Uses up items whose effect is applied off-chain. Items with an on-chain
+/// effect, like lootboxes, are consumed by the instruction that applies it.
+pub fn handle_consume_item(ctx: Context<ConsumeItem>, item_id: u32, quantity: u32) -> Result<()> {
+    let game = &ctx.accounts.game;
+    let mut inventory = ctx.accounts.inventory.load_mut()?;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    if !inventory.consume(item_id, quantity, current_time) {
+        msg!("needs {} of item {}, inventory holds {}", quantity, item_id, inventory.count(item_id, current_time));
+        return Err(error!(ClickerError::InsufficientItems));
+    }
+
+    emit!(ItemConsumed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+        item_id,
+        quantity,
+    });
+
+    Ok(())
+}
+
+/// Lets other games transfer items into this inventory, or stops them.
+/// Nothing arrives unasked otherwise, so a player can't be flooded with
+/// stacks that take up their slots.
+pub fn handle_set_accepts_transfers(ctx: Context<SetAcceptsTransfers>, accepts: bool) -> Result<()> {
+    let game = &ctx.accounts.game;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    ctx.accounts.inventory.load_mut()?.accepts_transfers = accepts as u8;
+    Ok(())
+}
+
+/// Moves part of the stack in `slot` to another game's inventory, keeping
+/// its expiry. The receiving inventory has to accept transfers.
+pub fn handle_transfer_item(ctx: Context<TransferItem>, slot: u8, quantity: u32) -> Result<()> {
+    let game = &ctx.accounts.game;
+    let mut from = ctx.accounts.inventory.load_mut()?;
+    let mut to = ctx.accounts.to_inventory.load_mut()?;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+    game.require_unfrozen()?;
+
+    if to.accepts_transfers == 0 {
+        msg!("inventory of game {} doesn't accept transfers", to.game);
+        return Err(error!(ClickerError::TransfersNotAccepted));
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let stack = match from.stacks.get_mut(slot as usize) {
+        Some(stack) if stack.is_live(current_time) && quantity > 0 && stack.quantity >= quantity => stack,
+        _ => {
+            msg!("slot {} doesn't hold {} live items", slot, quantity);
+            return Err(error!(ClickerError::InsufficientItems));
+        }
+    };
+
+    let (item_id, expires_at) = (stack.item_id, stack.expires_at);
+    stack.quantity -= quantity;
+    to.grant(item_id, quantity, expires_at, current_time)?;
+
+    emit!(ItemTransferred {
+        schema_version: EVENT_SCHEMA_VERSION,
+        from_game: game.key(),
+        to_game: to.game,
+        item_id,
+        quantity,
+    });
+
+    Ok(())
+}
+
+/// Grants `quantity` of an item as a reward; the inventory has to be passed
+/// whenever there is something to grant.
+pub(crate) fn grant_items(
+    inventory: Option<&AccountLoader<Inventory>>,
+    item_id: u32,
+    quantity: u32,
+    now: i64,
+) -> Result<()> {
+    if quantity == 0 {
+        return Ok(());
+    }
+    let Some(inventory) = inventory else {
+        msg!("{} of item {} are granted but no inventory was passed", quantity, item_id);
+        return Err(error!(ClickerError::InventoryRequired));
+    };
+    inventory.load_mut()?.grant(item_id, quantity, 0, now)
+}
+
+#[derive(Accounts)]
+pub struct InitializeInventory<'info> {
+    pub game: Account<'info, Game>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Inventory::MAXIMUM_SIZE,
+        seeds = [Inventory::SEED, game.key().as_ref()],
+        bump
+    )]
+    pub inventory: AccountLoader<'info, Inventory>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ConsumeItem<'info> {
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(mut, has_one = game)]
+    pub inventory: AccountLoader<'info, Inventory>,
+}
+
+#[derive(Accounts)]
+pub struct SetAcceptsTransfers<'info> {
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(mut, has_one = game)]
+    pub inventory: AccountLoader<'info, Inventory>,
+}
+
+#[derive(Accounts)]
+pub struct TransferItem<'info> {
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(mut, has_one = game)]
+    pub inventory: AccountLoader<'info, Inventory>,
+    #[account(mut, constraint = to_inventory.key() != inventory.key() @ ClickerError::InvalidInventory)]
+    pub to_inventory: AccountLoader<'info, Inventory>,
+}
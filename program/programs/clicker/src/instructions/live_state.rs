@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Game, LiveState};
+
+/// Creates the LiveState of a game from before initialize created one with
+/// every game, e.g. one brought over by migrate_game. Anyone can pay for it:
+/// the PDA is fixed by the game and only ever copies its state, and every
+/// instruction that changes what it mirrors requires it.
+pub fn handle_initialize_live_state(ctx: Context<InitializeLiveState>) -> Result<()> {
+    let live_state: &mut Account<LiveState> = &mut ctx.accounts.live_state;
+
+    live_state.game = ctx.accounts.game.key();
+    live_state.bump = ctx.bumps.live_state;
+    live_state.sync(&ctx.accounts.game, Clock::get()?.unix_timestamp);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeLiveState<'info> {
+    pub game: Account<'info, Game>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + LiveState::MAXIMUM_SIZE,
+        seeds = [LiveState::SEED, game.key().as_ref()],
+        bump
+    )]
+    pub live_state: Account<'info, LiveState>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
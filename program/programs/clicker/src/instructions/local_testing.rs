@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::state::{Config, GlobalStats, Treasury};
+
+/// Lamports initialize_for_test seeds the treasury with, so refunds work
+/// before anything has been bought.
+pub const LOCAL_TREASURY_LAMPORTS: u64 = 1_000_000_000;
+
+/// Sets up Config, GlobalStats and a funded Treasury in one call, with the
+/// signer in every privileged role. Only `features` are switched on, so a
+/// test opts into exactly the Config feature bits it exercises. Only built
+/// with the `local-testing` feature: unlike initialize_config it doesn't
+/// check the upgrade authority, so it must never reach a real cluster.
+pub fn handle_initialize_for_test(ctx: Context<InitializeForTest>, features: u64) -> Result<()> {
+    let admin = ctx.accounts.admin.key();
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let config: &mut Account<Config> = &mut ctx.accounts.config;
+    config.admin = admin;
+    config.vrf_oracle = admin;
+    config.attestor = admin;
+    config.offerwall_oracle = admin;
+    config.features = features;
+    config.cluster = Config::CLUSTER_LOCALNET;
+    config.lamports_per_gem = 1_000_000;
+    config.cosmetic_gem_price = 10;
+    config.extension_gems_per_minute = 1;
+    config.mentor_min_level = 1;
+    config.mentorship_days = 7;
+    config.mentorship_bonus_bps = 500;
+    config.lootboxes_per_quest = 1;
+    config.lootboxes_per_boss = 1;
+    config.time_attack_seconds = 60;
+    config.time_attack_grace = 30;
+    config.endurance_optimal_seconds = 10 * 60;
+    config.gamble_window = 5 * 60;
+    config.bump = ctx.bumps.config;
+
+    let mut stats = ctx.accounts.global_stats.load_init()?;
+    stats.epoch_start = current_time;
+    stats.difficulty = GlobalStats::DIFFICULTY_ONE;
+    stats.bump = ctx.bumps.global_stats;
+
+    ctx.accounts.treasury.bump = ctx.bumps.treasury;
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.admin.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+            },
+        ),
+        LOCAL_TREASURY_LAMPORTS,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeForTest<'info> {
+    #[account(init, payer = admin, space = 8 + Config::MAXIMUM_SIZE, seeds = [Config::SEED], bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + GlobalStats::MAXIMUM_SIZE,
+        seeds = [GlobalStats::SEED],
+        bump
+    )]
+    pub global_stats: AccountLoader<'info, GlobalStats>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Treasury::MAXIMUM_SIZE,
+        seeds = [Treasury::SEED],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
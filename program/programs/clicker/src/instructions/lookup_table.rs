@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use solana_address_lookup_table_interface::instruction::{create_lookup_table, extend_lookup_table};
+use solana_address_lookup_table_interface::program::ID as LOOKUP_TABLE_PROGRAM_ID;
+
+use crate::errors::ClickerError;
+use crate::state::Config;
+
+/// Creates the program's address lookup table, so wallets can send the
+/// larger instructions with the common accounts compressed to one byte each.
+/// The config PDA is its authority, so the table only changes through
+/// extend_lookup_table. `recent_slot` picks the table's address.
+pub fn handle_create_lookup_table(ctx: Context<CreateLookupTable>, recent_slot: u64) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let (ix, table) = create_lookup_table(config.key(), ctx.accounts.admin.key(), recent_slot);
+    if table != ctx.accounts.lookup_table.key() {
+        msg!("table for slot {} is {}, got {}", recent_slot, table, ctx.accounts.lookup_table.key());
+        return Err(error!(ClickerError::InvalidLookupTable));
+    }
+
+    invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.lookup_table.to_account_info(),
+            config.to_account_info(),
+            ctx.accounts.admin.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.address_lookup_table_program.to_account_info(),
+        ],
+        &[&[Config::SEED, &[config.bump]]],
+    )?;
+
+    ctx.accounts.config.lookup_table = table;
+    Ok(())
+}
+
+pub fn handle_extend_lookup_table(ctx: Context<ExtendLookupTable>, addresses: Vec<Pubkey>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let ix = extend_lookup_table(
+        config.lookup_table,
+        config.key(),
+        Some(ctx.accounts.admin.key()),
+        addresses,
+    );
+
+    invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.lookup_table.to_account_info(),
+            config.to_account_info(),
+            ctx.accounts.admin.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.address_lookup_table_program.to_account_info(),
+        ],
+        &[&[Config::SEED, &[config.bump]]],
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateLookupTable<'info> {
+    #[account(mut, seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: created by the lookup table program, address checked against the derivation
+    #[account(mut)]
+    pub lookup_table: UncheckedAccount<'info>,
+    /// CHECK: checked by address
+    #[account(address = LOOKUP_TABLE_PROGRAM_ID)]
+    pub address_lookup_table_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExtendLookupTable<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: the table created by create_lookup_table, owned by the lookup table program
+    #[account(mut, address = config.lookup_table @ ClickerError::InvalidLookupTable)]
+    pub lookup_table: UncheckedAccount<'info>,
+    /// CHECK: checked by address
+    #[account(address = LOOKUP_TABLE_PROGRAM_ID)]
+    pub address_lookup_table_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+
+use crate::errors::ClickerError;
+use crate::events::{EVENT_SCHEMA_VERSION, LootboxOpened, LootboxRequested};
+use crate::state::{Config, FeatureGate, FreezeGuard, Game, Inventory, LiveState};
+use crate::oracle::oracle_randomness;
+
+/// Takes one lootbox out of the game's inventory and records a pending
+/// draw against the slot this lands in. Nobody knows that slot while the
+/// transaction is being built, and the trusted oracle only signs for
+/// requests it has seen on chain, so neither the player nor whoever the box
+/// came from can preview the draw. The box is gone either way; settle_lootbox pays
+/// out.
+pub fn handle_open_lootbox(ctx: Context<OpenLootbox>) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+
+    ctx.accounts.config.require_feature(Config::LOOTBOXES_ENABLED | Config::VRF_ENABLED)?;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+    game.require_unfrozen()?;
+    if game.lootbox_request_slot != 0 {
+        msg!("game {} has an open from slot {} to settle first", game.key(), game.lootbox_request_slot);
+        return Err(error!(ClickerError::LootboxPending));
+    }
+
+    let clock = Clock::get()?;
+    if !ctx.accounts.inventory.load_mut()?.consume(Inventory::LOOTBOX, 1, clock.unix_timestamp) {
+        msg!("game {} has no lootboxes", game.key());
+        return Err(error!(ClickerError::NoLootboxes));
+    }
+
+    game.lootbox_request_slot = clock.slot;
+
+    emit!(LootboxRequested {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+        slot: clock.slot,
+    });
+
+    Ok(())
+}
+
+/// Draws the pending open's reward from the config's table. The trusted
+/// oracle signs (game || lootboxes_opened || request slot), see oracle.rs;
+/// anyone may submit its signature. After
+/// lootbox_pity_threshold - 1 opens without a rare reward, the next one
+/// draws from the rare entries only. Pause exempt, so a box already taken
+/// is never stuck.
+pub fn handle_settle_lootbox(ctx: Context<SettleLootbox>) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+    let config: &Account<Config> = &ctx.accounts.config;
+
+    if game.lootbox_request_slot == 0 {
+        msg!("game {} has no lootbox open to settle", game.key());
+        return Err(error!(ClickerError::NoPendingLootbox));
+    }
+
+    let mut seed = [0u8; 48];
+    seed[..32].copy_from_slice(game.key().as_ref());
+    seed[32..40].copy_from_slice(&game.lootboxes_opened.to_le_bytes());
+    seed[40..].copy_from_slice(&game.lootbox_request_slot.to_le_bytes());
+    let randomness = oracle_randomness(&ctx.accounts.instructions, &config.vrf_oracle, &seed)?;
+
+    let pity = config.lootbox_pity_threshold > 0 && game.lootbox_pity + 1 >= config.lootbox_pity_threshold;
+    let index = match config.draw_lootbox(randomness, pity) {
+        Some(index) => index,
+        None => {
+            msg!("lootbox table has no {}entries", if pity { "rare " } else { "" });
+            return Err(error!(ClickerError::LootboxTableEmpty));
+        }
+    };
+    let reward = config.lootbox_table[index];
+
+    game.lootboxes_opened += 1;
+    game.lootbox_request_slot = 0;
+    game.lootbox_pity = if reward.rare { 0 } else { game.lootbox_pity + 1 };
+    game.gems += reward.reward_gems;
+    game.credit_clicks(reward.reward_clicks);
+
+    ctx.accounts.live_state.sync(game, Clock::get()?.unix_timestamp);
+
+    emit!(LootboxOpened {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+        index: index as u8,
+        reward_gems: reward.reward_gems,
+        reward_clicks: reward.reward_clicks,
+        rare: reward.rare,
+        pity,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct OpenLootbox<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(mut, has_one = game)]
+    pub inventory: AccountLoader<'info, Inventory>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct SettleLootbox<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    /// CHECK: the instructions sysvar, checked by address
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    #[account(mut, seeds = [LiveState::SEED, game.key().as_ref()], bump = live_state.bump)]
+    pub live_state: Account<'info, LiveState>,
+}
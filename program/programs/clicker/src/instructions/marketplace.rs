@@ -0,0 +1,207 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::constants::BPS_ONE;
+use crate::errors::ClickerError;
+use crate::events::{EVENT_SCHEMA_VERSION, ItemListed, ListingCancelled, ListingSold};
+use crate::state::{Config, FeatureGate, FreezeGuard, Game, Inventory, Listing, Treasury};
+
+/// Moves part of the stack in `slot` into a new listing, asking `price`
+/// lamports for all of it.
+pub fn handle_list_item(ctx: Context<ListItem>, slot: u8, quantity: u32, price: u64) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+    let mut inventory = ctx.accounts.inventory.load_mut()?;
+
+    ctx.accounts.config.require_feature(Config::MARKETPLACE_ENABLED)?;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+    game.require_unfrozen()?;
+
+    if price == 0 {
+        msg!("listings can't be free");
+        return Err(error!(ClickerError::InvalidListingPrice));
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let stack = match inventory.stacks.get_mut(slot as usize) {
+        Some(stack) if stack.is_live(current_time) && quantity > 0 && stack.quantity >= quantity => stack,
+        _ => {
+            msg!("slot {} doesn't hold {} live items", slot, quantity);
+            return Err(error!(ClickerError::InsufficientItems));
+        }
+    };
+    stack.quantity -= quantity;
+
+    let listing: &mut Account<Listing> = &mut ctx.accounts.listing;
+    listing.game = game.key();
+    listing.seller = *ctx.accounts.player.key;
+    listing.item_id = stack.item_id;
+    listing.quantity = quantity;
+    listing.expires_at = stack.expires_at;
+    listing.price = price;
+    listing.listed_at = current_time;
+    listing.bump = ctx.bumps.listing;
+
+    game.listing_count += 1;
+
+    emit!(ItemListed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+        listing: listing.key(),
+        item_id: listing.item_id,
+        quantity,
+        price,
+    });
+
+    Ok(())
+}
+
+/// Pays the seller the price less Config.marketplace_fee_bps, which goes
+/// to the treasury, and moves the items into the buyer's inventory.
+pub fn handle_buy_listing(ctx: Context<BuyListing>) -> Result<()> {
+    let game = &ctx.accounts.game;
+    let listing = &ctx.accounts.listing;
+    let mut inventory = ctx.accounts.inventory.load_mut()?;
+
+    ctx.accounts.config.require_feature(Config::MARKETPLACE_ENABLED)?;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+    game.require_unfrozen()?;
+
+    if listing.game == game.key() {
+        msg!("game {} listed this itself, cancel it instead", game.key());
+        return Err(error!(ClickerError::OwnListing));
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    if listing.expires_at != 0 && current_time >= listing.expires_at {
+        msg!("listed items expired at {}, now is {}", listing.expires_at, current_time);
+        return Err(error!(ClickerError::ListingExpired));
+    }
+
+    let fee = (listing.price as u128 * ctx.accounts.config.marketplace_fee_bps as u128 / BPS_ONE as u128) as u64;
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.player.to_account_info(),
+                to: ctx.accounts.seller.to_account_info(),
+            },
+        ),
+        listing.price - fee,
+    )?;
+    if fee > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.player.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            fee,
+        )?;
+        ctx.accounts.treasury.total_received += fee;
+    }
+
+    inventory.grant(listing.item_id, listing.quantity, listing.expires_at, current_time)?;
+
+    emit!(ListingSold {
+        schema_version: EVENT_SCHEMA_VERSION,
+        listing: listing.key(),
+        seller_game: listing.game,
+        buyer_game: game.key(),
+        item_id: listing.item_id,
+        quantity: listing.quantity,
+        price: listing.price,
+        fee,
+    });
+
+    Ok(())
+}
+
+/// Returns the items to the seller's inventory, unless they expired while
+/// listed. Pause exempt, so switching the marketplace off strands nothing.
+pub fn handle_cancel_listing(ctx: Context<CancelListing>) -> Result<()> {
+    let game = &ctx.accounts.game;
+    let listing = &ctx.accounts.listing;
+    let mut inventory = ctx.accounts.inventory.load_mut()?;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let live = listing.expires_at == 0 || current_time < listing.expires_at;
+    if live {
+        inventory.grant(listing.item_id, listing.quantity, listing.expires_at, current_time)?;
+    }
+
+    emit!(ListingCancelled {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+        listing: listing.key(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ListItem<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, has_one = game)]
+    pub inventory: AccountLoader<'info, Inventory>,
+    #[account(
+        init,
+        payer = player,
+        space = 8 + Listing::MAXIMUM_SIZE,
+        seeds = [Listing::SEED, game.key().as_ref(), &game.listing_count.to_le_bytes()],
+        bump
+    )]
+    pub listing: Account<'info, Listing>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BuyListing<'info> {
+    pub game: Account<'info, Game>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, has_one = game)]
+    pub inventory: AccountLoader<'info, Inventory>,
+    #[account(mut, close = seller, has_one = seller)]
+    pub listing: Account<'info, Listing>,
+    /// CHECK: receives the proceeds, checked against the listing
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+    #[account(mut, seeds = [Treasury::SEED], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelListing<'info> {
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(mut, has_one = game)]
+    pub inventory: AccountLoader<'info, Inventory>,
+    #[account(mut, close = seller, has_one = game, has_one = seller)]
+    pub listing: Account<'info, Listing>,
+    /// CHECK: gets the listing's rent back, checked against the listing
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+}
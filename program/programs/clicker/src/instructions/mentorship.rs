@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::SECONDS_PER_DAY;
+use crate::errors::ClickerError;
+use crate::events::{EVENT_SCHEMA_VERSION, LevelUp, MentorshipStarted};
+use crate::gateway::require_pass;
+use crate::state::{Config, FeatureGate, FreezeGuard, Game, LiveState, Mentorship};
+
+pub fn handle_become_mentor(ctx: Context<BecomeMentor>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    config.require_feature(Config::MENTORSHIP_ENABLED)?;
+
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    if game.level < config.mentor_min_level {
+        msg!("level {} is below the mentor minimum of {}", game.level, config.mentor_min_level);
+        return Err(error!(ClickerError::NotEligibleMentor));
+    }
+
+    game.is_mentor = true;
+    Ok(())
+}
+
+/// Pairs a mentor with a mentee. Both players sign, so neither can be
+/// signed up without agreeing.
+pub fn handle_accept_mentee(ctx: Context<AcceptMentee>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    config.require_feature(Config::MENTORSHIP_ENABLED)?;
+
+    let mentor_game = &ctx.accounts.mentor_game;
+    let mentee_game = &ctx.accounts.mentee_game;
+
+    if &mentor_game.player != ctx.accounts.mentor.key {
+        msg!("signer {} is not player {}", ctx.accounts.mentor.key, mentor_game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+    if &mentee_game.player != ctx.accounts.mentee.key {
+        msg!("signer {} is not player {}", ctx.accounts.mentee.key, mentee_game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    if !mentor_game.is_mentor || mentor_game.level < config.mentor_min_level {
+        msg!(
+            "game {} (mentor: {}, level {}) cannot mentor, minimum level is {}",
+            mentor_game.key(),
+            mentor_game.is_mentor,
+            mentor_game.level,
+            config.mentor_min_level
+        );
+        return Err(error!(ClickerError::NotEligibleMentor));
+    }
+
+    if mentee_game.is_hardcore() {
+        msg!("hardcore game {} takes no mentorship bonus", mentee_game.key());
+        return Err(error!(ClickerError::HardcoreNoBoosts));
+    }
+
+    // Only new players, and no pairing a wallet with itself to farm the bonus
+    if mentee_game.level >= config.mentor_min_level || mentee_game.player == mentor_game.player {
+        msg!(
+            "game {} of {} at level {} cannot be mentored by {}",
+            mentee_game.key(),
+            mentee_game.player,
+            mentee_game.level,
+            mentor_game.player
+        );
+        return Err(error!(ClickerError::NotEligibleMentee));
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let mentorship: &mut Account<Mentorship> = &mut ctx.accounts.mentorship;
+    mentorship.mentor_game = mentor_game.key();
+    mentorship.mentee_game = mentee_game.key();
+    mentorship.started_at = now;
+    mentorship.expires_at = now + config.mentorship_days as i64 * SECONDS_PER_DAY;
+    mentorship.bonus_bps = config.mentorship_bonus_bps;
+    mentorship.bump = ctx.bumps.mentorship;
+
+    emit!(MentorshipStarted {
+        schema_version: EVENT_SCHEMA_VERSION,
+        mentorship: mentorship.key(),
+        mentor_game: mentorship.mentor_game,
+        mentee_game: mentorship.mentee_game,
+        expires_at: mentorship.expires_at,
+    });
+
+    Ok(())
+}
+
+/// Credits the mentor's accrued share of the mentee's bonus. Pause exempt.
+pub fn handle_claim_mentor_bonus(ctx: Context<ClaimMentorBonus>) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.mentor_game;
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+    game.require_unfrozen()?;
+    require_pass(
+        &ctx.accounts.config,
+        &game.player,
+        ctx.accounts.gateway_token.as_deref(),
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    let mentorship: &mut Account<Mentorship> = &mut ctx.accounts.mentorship;
+    let bonus = mentorship.mentor_bonus_clicks;
+    mentorship.mentor_bonus_clicks = 0;
+
+    game.credit_clicks(bonus);
+    if let Some(level) = game.award_xp(bonus) {
+        emit!(LevelUp {
+            schema_version: EVENT_SCHEMA_VERSION,
+            game: game.key(),
+            player: game.player,
+            level,
+            xp: game.xp,
+        });
+    }
+    ctx.accounts.live_state.sync(game, Clock::get()?.unix_timestamp);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BecomeMentor<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptMentee<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    pub mentor_game: Account<'info, Game>,
+    pub mentor: Signer<'info>,
+    pub mentee_game: Account<'info, Game>,
+    pub mentee: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    // One mentorship per mentee, ever
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Mentorship::MAXIMUM_SIZE,
+        seeds = [Mentorship::SEED, mentee_game.key().as_ref()],
+        bump
+    )]
+    pub mentorship: Account<'info, Mentorship>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimMentorBonus<'info> {
+    #[account(mut)]
+    pub mentor_game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(mut, has_one = mentor_game @ ClickerError::InvalidMentorship)]
+    pub mentorship: Account<'info, Mentorship>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    /// CHECK: parsed and checked by gateway::require_pass. Required while
+    /// Config.gatekeeper_network is set.
+    pub gateway_token: Option<UncheckedAccount<'info>>,
+    #[account(mut, seeds = [LiveState::SEED, mentor_game.key().as_ref()], bump = live_state.bump)]
+    pub live_state: Account<'info, LiveState>,
+}
@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+
+use crate::errors::ClickerError;
+use crate::state::Game;
+
+/// Rewrites a game created before the fixed layout, when the active session
+/// and pending recovery owner were `Option<Pubkey>`. Older layouts only
+/// appended fields, so bytes missing from the end of a small account decode
+/// as zero, which is every field's starting value. A game too short to hold
+/// flags is one of the first `Game{player, clicks}` accounts. Anyone can run
+/// it; the payer tops up rent if the account has to grow.
+pub fn handle_migrate_game(ctx: Context<MigrateGame>) -> Result<()> {
+    let game = &ctx.accounts.game;
+
+    if !game.try_borrow_data()?.starts_with(Game::DISCRIMINATOR) {
+        msg!("account {} is not a game", game.key());
+        return Err(error!(ErrorCode::AccountDiscriminatorMismatch));
+    }
+
+    let space = 8 + Game::MAXIMUM_SIZE;
+    if game.data_len() < space {
+        let rent_exempt = Rent::get()?.minimum_balance(space);
+        let lamports = game.lamports();
+        if rent_exempt > lamports {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: game.to_account_info(),
+                    },
+                ),
+                rent_exempt - lamports,
+            )?;
+        }
+        game.to_account_info().realloc(space, true)?;
+    }
+
+    let legacy = {
+        let data = game.try_borrow_data()?;
+        if data[Game::FLAGS_OFFSET] & Game::FLAG_FIXED_LAYOUT != 0 {
+            msg!("game {} already has the fixed layout", game.key());
+            return Err(error!(ClickerError::GameAlreadyMigrated));
+        }
+
+        let mut body = data[Game::DISCRIMINATOR.len()..].to_vec();
+        if body.len() < LegacyGame::MAXIMUM_SIZE {
+            body.resize(LegacyGame::MAXIMUM_SIZE, 0);
+        }
+        LegacyGame::deserialize(&mut body.as_slice()).map_err(|e| {
+            msg!("game {} does not decode: {}", game.key(), e);
+            error!(ErrorCode::AccountDidNotDeserialize)
+        })?
+    };
+
+    let migrated = legacy.into_game();
+    let mut data = game.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    migrated.try_serialize(&mut writer)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateGame<'info> {
+    /// CHECK: decoded by hand, since the old layout doesn't load as Game
+    #[account(mut, owner = crate::ID)]
+    pub game: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Game as it was laid out before the fixed layout.
+#[derive(AnchorDeserialize)]
+struct LegacyGame {
+    player: Pubkey,
+    clicks: u32,
+    total_clicks: u64,
+    last_session_end: i64,
+    active_session: Option<Pubkey>,
+    devices: [Pubkey; Game::MAX_DEVICES],
+    guardians: [Pubkey; 3],
+    guardian_threshold: u8,
+    recovery_owner: Option<Pubkey>,
+    recovery_unlock_time: i64,
+    frozen: bool,
+    unfreeze_requires_guardian: bool,
+    partner_mint: Pubkey,
+    partner_multiplier_bps: u16,
+    partner_multiplier_expires_at: i64,
+    xp: u64,
+    level: u32,
+    unlocked_skills: u64,
+    skill_points_spent: u32,
+    skill_cps_bonus_bps: u16,
+    skill_idle_bonus_bps: u16,
+    skill_crit_chance_bps: u16,
+    last_respec_at: i64,
+    gems: u64,
+    cosmetics: u64,
+    purchase_count: u64,
+    gem_purchase_day: i64,
+    gems_bought_today: u64,
+    gems_bought_lifetime: u64,
+    best_cps: u16,
+    best_session_clicks: u32,
+    best_day_clicks: u64,
+    click_day: i64,
+    clicks_today: u64,
+    former_record_holder: bool,
+    is_mentor: bool,
+    reputation: u32,
+}
+
+impl LegacyGame {
+    // both Options at their largest
+    const MAXIMUM_SIZE: usize = Game::MAXIMUM_SIZE + 1;
+
+    fn into_game(self) -> Game {
+        let mut game = Game {
+            player: self.player,
+            clicks: self.clicks,
+            spendable_clicks: self.total_clicks,
+            lifetime_clicks: self.total_clicks,
+            last_session_end: self.last_session_end,
+            flags: Game::FLAG_FIXED_LAYOUT,
+            active_session: Pubkey::default(),
+            devices: self.devices,
+            guardians: self.guardians,
+            guardian_threshold: self.guardian_threshold,
+            recovery_owner: Pubkey::default(),
+            recovery_unlock_time: self.recovery_unlock_time,
+            frozen: self.frozen,
+            unfreeze_requires_guardian: self.unfreeze_requires_guardian,
+            partner_mint: self.partner_mint,
+            partner_multiplier_bps: self.partner_multiplier_bps,
+            partner_multiplier_expires_at: self.partner_multiplier_expires_at,
+            xp: self.xp,
+            level: self.level,
+            unlocked_skills: self.unlocked_skills,
+            skill_points_spent: self.skill_points_spent,
+            skill_cps_bonus_bps: self.skill_cps_bonus_bps,
+            skill_idle_bonus_bps: self.skill_idle_bonus_bps,
+            skill_crit_chance_bps: self.skill_crit_chance_bps,
+            last_respec_at: self.last_respec_at,
+            gems: self.gems,
+            cosmetics: self.cosmetics,
+            purchase_count: self.purchase_count,
+            gem_purchase_day: self.gem_purchase_day,
+            gems_bought_today: self.gems_bought_today,
+            gems_bought_lifetime: self.gems_bought_lifetime,
+            best_cps: self.best_cps,
+            best_session_clicks: self.best_session_clicks,
+            best_day_clicks: self.best_day_clicks,
+            click_day: self.click_day,
+            clicks_today: self.clicks_today,
+            former_record_holder: self.former_record_holder,
+            is_mentor: self.is_mentor,
+            reputation: self.reputation,
+            ..Game::default()
+        };
+        game.set_active_session(self.active_session);
+        game.set_recovery_owner(self.recovery_owner);
+        game
+    }
+}
@@ -0,0 +1,97 @@
+mod admin_record;
+mod archive;
+mod attestation;
+mod cancel_session;
+mod click;
+mod close_session;
+mod config;
+mod coop;
+mod crafting;
+mod devices;
+mod difficulty;
+mod end_session;
+mod extend_session;
+mod freeze;
+mod gamble;
+mod gems;
+mod gifts;
+mod golden_window;
+mod identity;
+mod initialize;
+mod inventory;
+mod live_state;
+#[cfg(feature = "local-testing")]
+mod local_testing;
+mod lootbox;
+mod lookup_table;
+mod marketplace;
+mod mentorship;
+mod migrate_game;
+mod offers;
+mod partners;
+mod pause;
+mod quests;
+mod recovery;
+mod raffle;
+mod repair;
+mod season_pass;
+mod session_index;
+mod shoutbox;
+mod skills;
+mod snapshot;
+mod soft_reset;
+mod start_session;
+mod trophy_cabinet;
+mod void_session;
+mod weekly_challenge;
+mod world_boss;
+mod world_record;
+
+pub use admin_record::*;
+pub use archive::*;
+pub use attestation::*;
+pub use cancel_session::*;
+pub use click::*;
+pub use close_session::*;
+pub use config::*;
+pub use coop::*;
+pub use crafting::*;
+pub use devices::*;
+pub use difficulty::*;
+pub use end_session::*;
+pub use extend_session::*;
+pub use freeze::*;
+pub use gamble::*;
+pub use gems::*;
+pub use gifts::*;
+pub use golden_window::*;
+pub use identity::*;
+pub use initialize::*;
+pub use inventory::*;
+pub use live_state::*;
+#[cfg(feature = "local-testing")]
+pub use local_testing::*;
+pub use lootbox::*;
+pub use lookup_table::*;
+pub use marketplace::*;
+pub use mentorship::*;
+pub use migrate_game::*;
+pub use offers::*;
+pub use partners::*;
+pub use pause::*;
+pub use quests::*;
+pub use recovery::*;
+pub use raffle::*;
+pub use repair::*;
+pub use season_pass::*;
+pub use session_index::*;
+pub use shoutbox::*;
+pub use skills::*;
+pub use snapshot::*;
+pub use soft_reset::*;
+pub use start_session::*;
+pub use trophy_cabinet::*;
+pub use void_session::*;
+pub use weekly_challenge::*;
+pub use world_boss::*;
+pub use world_record::*;
@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+
+use crate::constants::{BPS_ONE, SECONDS_PER_DAY};
+use crate::ed25519::verify_preceding_signature;
+use crate::errors::ClickerError;
+use crate::events::{EVENT_SCHEMA_VERSION, OfferRedeemed};
+use crate::state::{Config, FeatureGate, FreezeGuard, Game, Offer, OfferRedemption, OfferReward, PartnerCollection};
+
+pub fn handle_add_offer(
+    ctx: Context<AddOffer>,
+    offer_id: u32,
+    reward: OfferReward,
+    max_redemptions: u32
+) -> Result<()> {
+    let offer: &mut Account<Offer> = &mut ctx.accounts.offer;
+
+    match reward {
+        OfferReward::Gems(0) => {
+            msg!("offer {} grants no gems", offer_id);
+            return Err(error!(ClickerError::InvalidOfferReward));
+        }
+        OfferReward::Boost { multiplier_bps, duration }
+            if !(BPS_ONE..=PartnerCollection::MAX_MULTIPLIER_BPS).contains(&multiplier_bps) || duration <= 0 =>
+        {
+            msg!(
+                "boost of {} bps for {}s, expected {}..={} bps for a positive duration",
+                multiplier_bps,
+                duration,
+                BPS_ONE,
+                PartnerCollection::MAX_MULTIPLIER_BPS
+            );
+            return Err(error!(ClickerError::InvalidOfferReward));
+        }
+        _ => {}
+    }
+
+    offer.offer_id = offer_id;
+    offer.reward = reward;
+    offer.max_redemptions = max_redemptions;
+    offer.active = true;
+    offer.bump = ctx.bumps.offer;
+
+    Ok(())
+}
+
+pub fn handle_set_offer_active(ctx: Context<SetOfferActive>, active: bool) -> Result<()> {
+    ctx.accounts.offer.active = active;
+    Ok(())
+}
+
+/// The offerwall oracle's Ed25519 signature over
+/// `Offer::attestation_message` must be verified by the instruction right
+/// before this one; `signature` names that signature and is kept on the
+/// redemption. Each game redeems an offer once.
+pub fn handle_redeem_offer(ctx: Context<RedeemOffer>, offer_id: u32, signature: [u8; 64]) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+    let offer: &mut Account<Offer> = &mut ctx.accounts.offer;
+    let config: &Account<Config> = &ctx.accounts.config;
+
+    config.require_feature(Config::OFFERS_ENABLED)?;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+    game.require_unfrozen()?;
+
+    if !offer.active {
+        msg!("offer {} is inactive", offer_id);
+        return Err(error!(ClickerError::OfferInactive));
+    }
+
+    if offer.max_redemptions > 0 && offer.redemptions >= offer.max_redemptions {
+        msg!("offer {} was redeemed {} of {} times", offer_id, offer.redemptions, offer.max_redemptions);
+        return Err(error!(ClickerError::OfferSoldOut));
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let day = current_time.div_euclid(SECONDS_PER_DAY);
+    let redeemed_today = game.offers_redeemed_on(day) + 1;
+    if config.max_offers_per_day > 0 && redeemed_today > config.max_offers_per_day {
+        msg!("game {} already redeemed {} offers today", game.key(), config.max_offers_per_day);
+        return Err(error!(ClickerError::OfferLimitReached));
+    }
+
+    let message = Offer::attestation_message(offer_id, &game.key());
+    let verified = verify_preceding_signature(&ctx.accounts.instructions, &config.offerwall_oracle, &message)?;
+    if verified != signature {
+        msg!("Ed25519 instruction verified a different signature than the one passed");
+        return Err(error!(ClickerError::InvalidSignatureInstruction));
+    }
+
+    match offer.reward {
+        OfferReward::Gems(gems) => game.gems += gems,
+        OfferReward::Boost { multiplier_bps, duration } => {
+            game.offer_boost_bps = multiplier_bps;
+            game.offer_boost_expires_at = current_time + duration;
+        }
+    }
+    game.offer_day = day;
+    game.offers_today = redeemed_today;
+    offer.redemptions += 1;
+
+    let redemption: &mut Account<OfferRedemption> = &mut ctx.accounts.redemption;
+    redemption.offer = offer.key();
+    redemption.game = game.key();
+    redemption.signature = signature;
+    redemption.redeemed_at = current_time;
+
+    emit!(OfferRedeemed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+        offer_id,
+        reward: offer.reward,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(offer_id: u32)]
+pub struct AddOffer<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Offer::MAXIMUM_SIZE,
+        seeds = [Offer::SEED, &offer_id.to_le_bytes()],
+        bump
+    )]
+    pub offer: Account<'info, Offer>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetOfferActive<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [Offer::SEED, &offer.offer_id.to_le_bytes()], bump = offer.bump)]
+    pub offer: Account<'info, Offer>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(offer_id: u32)]
+pub struct RedeemOffer<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [Offer::SEED, &offer_id.to_le_bytes()], bump = offer.bump)]
+    pub offer: Account<'info, Offer>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + OfferRedemption::MAXIMUM_SIZE,
+        seeds = [OfferRedemption::SEED, offer.key().as_ref(), game.key().as_ref()],
+        bump
+    )]
+    pub redemption: Account<'info, OfferRedemption>,
+    /// CHECK: the instructions sysvar, checked by address
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
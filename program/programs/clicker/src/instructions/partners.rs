@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::{Metadata, MetadataAccount};
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::constants::BPS_ONE;
+use crate::errors::ClickerError;
+use crate::state::{Config, FeatureGate, Game, LiveState, PartnerCollection, PartnerNft};
+
+pub fn handle_add_partner_collection(
+    ctx: Context<AddPartnerCollection>,
+    collection: Pubkey,
+    multiplier_bps: u16
+) -> Result<()> {
+    let partner: &mut Account<PartnerCollection> = &mut ctx.accounts.partner_collection;
+
+    if !(BPS_ONE..=PartnerCollection::MAX_MULTIPLIER_BPS).contains(&multiplier_bps) {
+        msg!(
+            "multiplier {} bps is outside {}..={}",
+            multiplier_bps,
+            BPS_ONE,
+            PartnerCollection::MAX_MULTIPLIER_BPS
+        );
+        return Err(error!(ClickerError::InvalidMultiplier));
+    }
+
+    partner.collection = collection;
+    partner.multiplier_bps = multiplier_bps;
+    partner.active = true;
+    partner.bump = ctx.bumps.partner_collection;
+
+    Ok(())
+}
+
+pub fn handle_update_partner_collection(
+    ctx: Context<UpdatePartnerCollection>,
+    multiplier_bps: u16,
+    active: bool
+) -> Result<()> {
+    let partner: &mut Account<PartnerCollection> = &mut ctx.accounts.partner_collection;
+
+    if !(BPS_ONE..=PartnerCollection::MAX_MULTIPLIER_BPS).contains(&multiplier_bps) {
+        msg!(
+            "multiplier {} bps is outside {}..={}",
+            multiplier_bps,
+            BPS_ONE,
+            PartnerCollection::MAX_MULTIPLIER_BPS
+        );
+        return Err(error!(ClickerError::InvalidMultiplier));
+    }
+
+    partner.multiplier_bps = multiplier_bps;
+    partner.active = active;
+
+    Ok(())
+}
+
+pub fn handle_verify_partner_nft(ctx: Context<VerifyPartnerNft>, mint: Pubkey) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+    let partner: &Account<PartnerCollection> = &ctx.accounts.partner_collection;
+    let partner_nft: &mut Account<PartnerNft> = &mut ctx.accounts.partner_nft;
+    let token_account = &ctx.accounts.token_account;
+
+    ctx.accounts.config.require_feature(Config::PARTNERS_ENABLED)?;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    if !partner.active {
+        msg!("partner collection {} is inactive", partner.collection);
+        return Err(error!(ClickerError::PartnerCollectionInactive));
+    }
+
+    // The player must actually hold the NFT right now
+    if token_account.mint != mint || token_account.owner != game.player || token_account.amount == 0 {
+        msg!(
+            "token account holds {} of mint {} for {}, expected mint {} for {}",
+            token_account.amount,
+            token_account.mint,
+            token_account.owner,
+            mint,
+            game.player
+        );
+        return Err(error!(ClickerError::NftNotHeld));
+    }
+
+    // ...and it must be a verified member of the partner's collection
+    match &ctx.accounts.metadata.collection {
+        Some(collection) if collection.verified && collection.key == partner.collection => {}
+        _ => {
+            msg!("mint {} is not verified in collection {}", mint, partner.collection);
+            return Err(error!(ClickerError::CollectionNotVerified));
+        }
+    }
+
+    // Selling or lending the NFT on doesn't let a second game verify it
+    // while this verification lasts
+    let current_time = Clock::get()?.unix_timestamp;
+    if partner_nft.game != Pubkey::default()
+        && partner_nft.game != game.key()
+        && current_time < partner_nft.expires_at
+    {
+        msg!(
+            "mint {} boosts game {} until {}",
+            mint,
+            partner_nft.game,
+            partner_nft.expires_at
+        );
+        return Err(error!(ClickerError::NftAlreadyBound));
+    }
+
+    game.partner_mint = mint;
+    game.partner_collection = partner.collection;
+    game.partner_multiplier_bps = partner.multiplier_bps;
+    game.partner_multiplier_expires_at = current_time + PartnerCollection::VERIFICATION_TTL;
+
+    partner_nft.mint = mint;
+    partner_nft.game = game.key();
+    partner_nft.expires_at = game.partner_multiplier_expires_at;
+    partner_nft.bump = ctx.bumps.partner_nft;
+
+    ctx.accounts.live_state.sync(game, current_time);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(collection: Pubkey)]
+pub struct AddPartnerCollection<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + PartnerCollection::MAXIMUM_SIZE,
+        seeds = [PartnerCollection::SEED, collection.as_ref()],
+        bump
+    )]
+    pub partner_collection: Account<'info, PartnerCollection>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePartnerCollection<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [PartnerCollection::SEED, partner_collection.collection.as_ref()],
+        bump = partner_collection.bump
+    )]
+    pub partner_collection: Account<'info, PartnerCollection>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(mint: Pubkey)]
+pub struct VerifyPartnerNft<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        seeds = [PartnerCollection::SEED, partner_collection.collection.as_ref()],
+        bump = partner_collection.bump
+    )]
+    pub partner_collection: Account<'info, PartnerCollection>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PartnerNft::MAXIMUM_SIZE,
+        seeds = [PartnerNft::SEED, mint.as_ref()],
+        bump
+    )]
+    pub partner_nft: Account<'info, PartnerNft>,
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        seeds = [b"metadata", Metadata::id().as_ref(), mint.as_ref()],
+        seeds::program = Metadata::id(),
+        bump
+    )]
+    pub metadata: Account<'info, MetadataAccount>,
+    #[account(mut, seeds = [LiveState::SEED, game.key().as_ref()], bump = live_state.bump)]
+    pub live_state: Account<'info, LiveState>,
+    pub system_program: Program<'info, System>,
+}
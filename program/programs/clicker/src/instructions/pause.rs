@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ClickerError;
+use crate::state::Session;
+
+/// Stops the clock the rate checks use until `resume_session`. Paused time
+/// doesn't move the session's deadline.
+pub fn handle_pause_session(ctx: Context<PauseSession>) -> Result<()> {
+    let session: &mut Account<Session> = &mut ctx.accounts.session;
+
+    // Verify player ownership
+    if &session.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, session.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    // Every way a session stops being active closes it, recovery included,
+    // so an open session is its game's active one
+    if session.revealed {
+        msg!("session {} is already closed", session.key());
+        return Err(error!(ClickerError::SessionAlreadyRevealed));
+    }
+
+    if session.is_paused() {
+        msg!("session {} is already paused since {}", session.key(), session.paused_at);
+        return Err(error!(ClickerError::SessionPaused));
+    }
+
+    if session.is_time_attack() {
+        msg!("session {} is a time attack", session.key());
+        return Err(error!(ClickerError::TimeAttackFixed));
+    }
+
+    if session.paused_total >= Session::MAX_PAUSED_SECONDS {
+        msg!(
+            "session has been paused for {}s, the limit is {}s",
+            session.paused_total,
+            Session::MAX_PAUSED_SECONDS
+        );
+        return Err(error!(ClickerError::PauseLimitReached));
+    }
+
+    session.paused_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+pub fn handle_resume_session(ctx: Context<PauseSession>) -> Result<()> {
+    let session: &mut Account<Session> = &mut ctx.accounts.session;
+
+    // Verify player ownership
+    if &session.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, session.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    // Every way a session stops being active closes it, recovery included,
+    // so an open session is its game's active one
+    if session.revealed {
+        msg!("session {} is already closed", session.key());
+        return Err(error!(ClickerError::SessionAlreadyRevealed));
+    }
+
+    if !session.is_paused() {
+        msg!("session {} is not paused", session.key());
+        return Err(error!(ClickerError::SessionNotPaused));
+    }
+
+    // anything past the limit counts as active time
+    session.paused_total = session.paused_seconds(Clock::get()?.unix_timestamp);
+    session.paused_at = 0;
+
+    Ok(())
+}
+
+// The session records its player and whether it is still open, so the game
+// isn't needed
+#[derive(Accounts)]
+pub struct PauseSession<'info> {
+    #[account(mut)]
+    pub session: Account<'info, Session>,
+    pub player: Signer<'info>,
+}
@@ -0,0 +1,328 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::errors::ClickerError;
+use crate::events::{EVENT_SCHEMA_VERSION, PartnerQuestCompleted, QuestChainCompleted};
+use crate::instructions::grant_items;
+use crate::state::{
+    Config, FeatureGate, FreezeGuard, Game, Inventory, LiveState, PartnerQuest, QuestChain, QuestChainProgress,
+    QuestCompletion, QuestCondition,
+};
+
+pub fn handle_add_partner_quest(
+    ctx: Context<AddPartnerQuest>,
+    quest_id: u32,
+    condition: QuestCondition,
+    reward_clicks: u64,
+    min_level: u32
+) -> Result<()> {
+    let quest: &mut Account<PartnerQuest> = &mut ctx.accounts.quest;
+
+    quest.quest_id = quest_id;
+    quest.condition = condition;
+    quest.reward_clicks = reward_clicks;
+    quest.min_level = min_level;
+    quest.active = true;
+    quest.bump = ctx.bumps.quest;
+
+    Ok(())
+}
+
+pub fn handle_set_partner_quest_active(ctx: Context<SetPartnerQuestActive>, active: bool) -> Result<()> {
+    ctx.accounts.quest.active = active;
+    Ok(())
+}
+
+pub fn handle_create_quest_chain(
+    ctx: Context<CreateQuestChain>,
+    chain_id: u32,
+    reward_item_id: u32,
+    reward_quantity: u32
+) -> Result<()> {
+    let chain: &mut Account<QuestChain> = &mut ctx.accounts.chain;
+
+    chain.chain_id = chain_id;
+    chain.reward_item_id = reward_item_id;
+    chain.reward_quantity = reward_quantity;
+    chain.bump = ctx.bumps.chain;
+
+    Ok(())
+}
+
+/// Makes a standalone quest the chain's next step. From then on it can only
+/// be completed in order.
+pub fn handle_append_chain_quest(ctx: Context<AppendChainQuest>, _chain_id: u32, _quest_id: u32) -> Result<()> {
+    let chain: &mut Account<QuestChain> = &mut ctx.accounts.chain;
+    let quest: &mut Account<PartnerQuest> = &mut ctx.accounts.quest;
+
+    if quest.is_chained() {
+        msg!("quest {} is already a step of chain {}", quest.quest_id, quest.chain);
+        return Err(error!(ClickerError::QuestAlreadyChained));
+    }
+    if chain.length as usize >= QuestChain::MAX_STEPS {
+        msg!("chain {} already has {} steps", chain.chain_id, QuestChain::MAX_STEPS);
+        return Err(error!(ClickerError::QuestChainFull));
+    }
+
+    let step = chain.length;
+    quest.chain = chain.key();
+    quest.chain_step = step;
+    chain.quests[step as usize] = quest.quest_id;
+    chain.length = step + 1;
+
+    Ok(())
+}
+
+/// Unlocks a chain's first step for the game.
+pub fn handle_start_quest_chain(ctx: Context<StartQuestChain>, _chain_id: u32) -> Result<()> {
+    let game = &ctx.accounts.game;
+
+    ctx.accounts.config.require_feature(Config::QUESTS_ENABLED)?;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    let progress: &mut Account<QuestChainProgress> = &mut ctx.accounts.progress;
+    progress.chain = ctx.accounts.chain.key();
+    progress.game = game.key();
+    progress.bump = ctx.bumps.progress;
+
+    Ok(())
+}
+
+/// The account proving the condition is passed as `evidence`; what it has to
+/// look like depends on the quest's condition. A chained quest also needs
+/// the chain and the game's progress through it, and only completes once
+/// the step before it has.
+pub fn handle_complete_partner_quest(ctx: Context<CompletePartnerQuest>, _quest_id: u32) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+    let quest: &Account<PartnerQuest> = &ctx.accounts.quest;
+    let evidence: &AccountInfo = &ctx.accounts.evidence;
+
+    ctx.accounts.config.require_feature(Config::QUESTS_ENABLED)?;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+    game.require_unfrozen()?;
+
+    if !quest.active {
+        msg!("quest {} is inactive", quest.quest_id);
+        return Err(error!(ClickerError::QuestInactive));
+    }
+
+    if game.level < quest.min_level {
+        msg!("quest needs level {}, player is level {}", quest.min_level, game.level);
+        return Err(error!(ClickerError::LevelTooLow));
+    }
+
+    let condition_met = match &quest.condition {
+        QuestCondition::TokenBalance { mint, min_amount } => {
+            let is_token_program = *evidence.owner == Token::id() || *evidence.owner == Token2022::id();
+            let data = evidence.try_borrow_data()?;
+            match TokenAccount::try_deserialize(&mut &data[..]) {
+                Ok(token_account) if is_token_program => {
+                    token_account.mint == *mint
+                        && token_account.owner == game.player
+                        && token_account.amount >= *min_amount
+                }
+                _ => false,
+            }
+        }
+        QuestCondition::ProgramAccount { program, player_offset } => {
+            let start = *player_offset as usize;
+            let data = evidence.try_borrow_data()?;
+            evidence.owner == program
+                && data.get(start..start + 32) == Some(game.player.as_ref())
+        }
+    };
+
+    if !condition_met {
+        msg!("evidence {} does not meet quest {}", evidence.key, quest.quest_id);
+        return Err(error!(ClickerError::QuestConditionNotMet));
+    }
+
+    let completion: &mut Account<QuestCompletion> = &mut ctx.accounts.completion;
+    completion.quest = quest.key();
+    completion.game = game.key();
+    completion.completed_at = Clock::get()?.unix_timestamp;
+
+    if quest.is_chained() {
+        let (Some(chain), Some(progress)) =
+            (ctx.accounts.quest_chain.as_ref(), ctx.accounts.chain_progress.as_mut())
+        else {
+            msg!("quest {} is a step of chain {}, which must be passed", quest.quest_id, quest.chain);
+            return Err(error!(ClickerError::QuestChainRequired));
+        };
+        if chain.key() != quest.chain || progress.chain != quest.chain {
+            msg!("quest {} is a step of chain {}, not {}", quest.quest_id, quest.chain, progress.chain);
+            return Err(error!(ClickerError::QuestChainRequired));
+        }
+        if progress.completed != quest.chain_step {
+            msg!(
+                "quest {} is step {} of chain {}, game has step {} unlocked",
+                quest.quest_id,
+                quest.chain_step,
+                chain.chain_id,
+                progress.completed
+            );
+            return Err(error!(ClickerError::QuestLocked));
+        }
+
+        progress.completed += 1;
+        if progress.completed == chain.length {
+            progress.completed_at = completion.completed_at;
+            grant_items(
+                ctx.accounts.inventory.as_ref(),
+                chain.reward_item_id,
+                chain.reward_quantity,
+                completion.completed_at,
+            )?;
+
+            emit!(QuestChainCompleted {
+                schema_version: EVENT_SCHEMA_VERSION,
+                game: game.key(),
+                chain_id: chain.chain_id,
+                reward_item_id: chain.reward_item_id,
+                reward_quantity: chain.reward_quantity,
+            });
+        }
+    }
+
+    game.credit_clicks(quest.reward_clicks);
+    grant_items(
+        ctx.accounts.inventory.as_ref(),
+        Inventory::LOOTBOX,
+        ctx.accounts.config.lootboxes_per_quest,
+        completion.completed_at,
+    )?;
+
+    ctx.accounts.live_state.sync(game, completion.completed_at);
+
+    emit!(PartnerQuestCompleted {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+        quest_id: quest.quest_id,
+        reward_clicks: quest.reward_clicks,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(quest_id: u32)]
+pub struct AddPartnerQuest<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + PartnerQuest::MAXIMUM_SIZE,
+        seeds = [PartnerQuest::SEED, &quest_id.to_le_bytes()],
+        bump
+    )]
+    pub quest: Account<'info, PartnerQuest>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetPartnerQuestActive<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [PartnerQuest::SEED, &quest.quest_id.to_le_bytes()], bump = quest.bump)]
+    pub quest: Account<'info, PartnerQuest>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(quest_id: u32)]
+pub struct CompletePartnerQuest<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(seeds = [PartnerQuest::SEED, &quest_id.to_le_bytes()], bump = quest.bump)]
+    pub quest: Account<'info, PartnerQuest>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + QuestCompletion::MAXIMUM_SIZE,
+        seeds = [QuestCompletion::SEED, quest.key().as_ref(), game.key().as_ref()],
+        bump
+    )]
+    pub completion: Account<'info, QuestCompletion>,
+    /// CHECK: interpreted according to the quest's condition
+    pub evidence: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    #[account(mut, seeds = [LiveState::SEED, game.key().as_ref()], bump = live_state.bump)]
+    pub live_state: Account<'info, LiveState>,
+    // Receives Config::lootboxes_per_quest and a finished chain's reward
+    #[account(mut, has_one = game)]
+    pub inventory: Option<AccountLoader<'info, Inventory>>,
+    // Both required when the quest is a chain step
+    pub quest_chain: Option<Account<'info, QuestChain>>,
+    #[account(mut, has_one = game)]
+    pub chain_progress: Option<Account<'info, QuestChainProgress>>,
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u32)]
+pub struct CreateQuestChain<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + QuestChain::MAXIMUM_SIZE,
+        seeds = [QuestChain::SEED, &chain_id.to_le_bytes()],
+        bump
+    )]
+    pub chain: Account<'info, QuestChain>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u32, quest_id: u32)]
+pub struct AppendChainQuest<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [QuestChain::SEED, &chain_id.to_le_bytes()], bump = chain.bump)]
+    pub chain: Account<'info, QuestChain>,
+    #[account(mut, seeds = [PartnerQuest::SEED, &quest_id.to_le_bytes()], bump = quest.bump)]
+    pub quest: Account<'info, PartnerQuest>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u32)]
+pub struct StartQuestChain<'info> {
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(seeds = [QuestChain::SEED, &chain_id.to_le_bytes()], bump = chain.bump)]
+    pub chain: Account<'info, QuestChain>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + QuestChainProgress::MAXIMUM_SIZE,
+        seeds = [QuestChainProgress::SEED, chain.key().as_ref(), game.key().as_ref()],
+        bump
+    )]
+    pub progress: Account<'info, QuestChainProgress>,
+    pub system_program: Program<'info, System>,
+}
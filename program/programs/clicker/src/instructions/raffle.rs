@@ -0,0 +1,213 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::errors::ClickerError;
+use crate::events::{EVENT_SCHEMA_VERSION, RaffleClosed, RaffleDrawn};
+use crate::state::{Config, FeatureGate, Raffle, Session};
+use crate::oracle::oracle_randomness;
+
+pub fn handle_open_raffle(ctx: Context<OpenRaffle>, period: u64) -> Result<()> {
+    let raffle: &mut Account<Raffle> = &mut ctx.accounts.raffle;
+
+    ctx.accounts.config.require_feature(Config::RAFFLES_ENABLED)?;
+
+    // raffles can be opened ahead of time, but never for a period that has begun
+    let current_time = Clock::get()?.unix_timestamp;
+    if period < Raffle::period_at(current_time) {
+        msg!(
+            "period {} has begun, the current period is {}",
+            period,
+            Raffle::period_at(current_time)
+        );
+        return Err(error!(ClickerError::InvalidRafflePeriod));
+    }
+
+    raffle.period = period;
+    raffle.start_time = period as i64 * Raffle::PERIOD_SECONDS;
+    raffle.end_time = raffle.start_time + Raffle::PERIOD_SECONDS;
+    raffle.bump = ctx.bumps.raffle;
+
+    Ok(())
+}
+
+pub fn handle_fund_raffle(ctx: Context<FundRaffle>, amount: u64) -> Result<()> {
+    ctx.accounts.config.require_feature(Config::RAFFLES_ENABLED)?;
+
+    if ctx.accounts.raffle.drawn {
+        msg!("raffle {} was already drawn", ctx.accounts.raffle.period);
+        return Err(error!(ClickerError::RaffleAlreadyDrawn));
+    }
+
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.funder.to_account_info(),
+                to: ctx.accounts.raffle.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
+/// Stops ticket sales once the period is over and commits the draw to the
+/// slot this lands in. Nobody knows that slot while tickets are selling, so
+/// a player can't line a ticket up with the draw. The oracle picks the draw
+/// and is trusted, see oracle.rs. Anyone can crank it.
+pub fn handle_close_raffle(ctx: Context<CloseRaffle>) -> Result<()> {
+    let raffle: &mut Account<Raffle> = &mut ctx.accounts.raffle;
+
+    ctx.accounts.config.require_feature(Config::RAFFLES_ENABLED)?;
+
+    if raffle.draw_slot != 0 {
+        msg!("raffle {} closed at slot {}", raffle.period, raffle.draw_slot);
+        return Err(error!(ClickerError::RaffleAlreadyClosed));
+    }
+
+    let clock = Clock::get()?;
+    if clock.unix_timestamp < raffle.end_time {
+        msg!("raffle closes at {}, now is {}", raffle.end_time, clock.unix_timestamp);
+        return Err(error!(ClickerError::RaffleStillOpen));
+    }
+
+    raffle.draw_slot = clock.slot;
+
+    emit!(RaffleClosed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        raffle: raffle.key(),
+        period: raffle.period,
+        total_tickets: raffle.total_tickets,
+        draw_slot: clock.slot,
+    });
+
+    Ok(())
+}
+
+/// Pays the prize to the holder of the winning ticket. The trusted oracle
+/// signs (raffle || draw slot), which nobody could know before
+/// close_raffle.
+pub fn handle_draw_raffle(ctx: Context<DrawRaffle>) -> Result<()> {
+    let raffle: &mut Account<Raffle> = &mut ctx.accounts.raffle;
+    let session: &Account<Session> = &ctx.accounts.winning_session;
+
+    ctx.accounts
+        .config
+        .require_feature(Config::RAFFLES_ENABLED | Config::VRF_ENABLED)?;
+
+    if raffle.drawn {
+        msg!("raffle {} was already drawn", raffle.period);
+        return Err(error!(ClickerError::RaffleAlreadyDrawn));
+    }
+
+    if raffle.draw_slot == 0 {
+        msg!("raffle {} has to be closed before the draw", raffle.period);
+        return Err(error!(ClickerError::RaffleStillOpen));
+    }
+
+    if raffle.total_tickets == 0 {
+        msg!("raffle {} has no tickets", raffle.period);
+        return Err(error!(ClickerError::RaffleHasNoTickets));
+    }
+
+    // The caller can only pass in the session holding the drawn ticket
+    let mut seed = [0u8; 40];
+    seed[..32].copy_from_slice(raffle.key().as_ref());
+    seed[32..].copy_from_slice(&raffle.draw_slot.to_le_bytes());
+    let randomness = oracle_randomness(
+        &ctx.accounts.instructions,
+        &ctx.accounts.config.vrf_oracle,
+        &seed,
+    )?;
+    let winning_ticket = randomness % raffle.total_tickets;
+
+    if session.raffle != raffle.key()
+        || winning_ticket < session.raffle_ticket_start
+        || winning_ticket >= session.raffle_ticket_start + session.raffle_tickets
+    {
+        msg!(
+            "ticket {} of raffle {} is not in session {}",
+            winning_ticket,
+            raffle.key(),
+            session.key()
+        );
+        return Err(error!(ClickerError::WinningTicketMismatch));
+    }
+
+    // Everything above the rent-exempt minimum is the prize
+    let raffle_info = raffle.to_account_info();
+    let rent_exempt = Rent::get()?.minimum_balance(raffle_info.data_len());
+    let prize = raffle_info.lamports().saturating_sub(rent_exempt);
+
+    **raffle_info.try_borrow_mut_lamports()? -= prize;
+    **ctx.accounts.winner.try_borrow_mut_lamports()? += prize;
+
+    raffle.drawn = true;
+    raffle.winning_ticket = winning_ticket;
+    raffle.winner = session.player;
+    raffle.prize = prize;
+
+    emit_cpi!(RaffleDrawn {
+        schema_version: EVENT_SCHEMA_VERSION,
+        raffle: raffle.key(),
+        period: raffle.period,
+        winning_ticket,
+        winner: session.player,
+        prize,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(period: u64)]
+pub struct OpenRaffle<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Raffle::MAXIMUM_SIZE,
+        seeds = [Raffle::SEED, &period.to_le_bytes()],
+        bump
+    )]
+    pub raffle: Account<'info, Raffle>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FundRaffle<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [Raffle::SEED, &raffle.period.to_le_bytes()], bump = raffle.bump)]
+    pub raffle: Account<'info, Raffle>,
+    #[account(mut)]
+    pub funder: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseRaffle<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [Raffle::SEED, &raffle.period.to_le_bytes()], bump = raffle.bump)]
+    pub raffle: Account<'info, Raffle>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DrawRaffle<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [Raffle::SEED, &raffle.period.to_le_bytes()], bump = raffle.bump)]
+    pub raffle: Account<'info, Raffle>,
+    pub winning_session: Account<'info, Session>,
+    /// CHECK: receives the prize, must own the winning session
+    #[account(mut, address = winning_session.player @ ClickerError::WinningTicketMismatch)]
+    pub winner: UncheckedAccount<'info>,
+    /// CHECK: the instructions sysvar, checked by address
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
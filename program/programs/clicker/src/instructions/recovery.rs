@@ -0,0 +1,206 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ClickerError;
+use crate::events::{EVENT_SCHEMA_VERSION, GameRecovered, RecoveryCancelled, RecoveryStarted};
+use crate::state::{Config, FeatureGate, FreezeGuard, Game, LiveState, Session};
+
+pub fn handle_set_guardians(
+    ctx: Context<SetGuardians>,
+    guardians: [Pubkey; 3],
+    threshold: u8
+) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+
+    ctx.accounts.config.require_feature(Config::RECOVERY_ENABLED)?;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    game.require_unfrozen()?;
+
+    // Changing guardians mid-recovery would let either side race the other
+    if let Some(recovery_owner) = game.recovery_owner() {
+        msg!("recovery to {} is pending", recovery_owner);
+        return Err(error!(ClickerError::RecoveryAlreadyPending));
+    }
+
+    // Pubkey::default() marks an empty slot; anything else must be distinct,
+    // since recover_game counts each guardian's approval once
+    for (i, guardian) in guardians.iter().enumerate() {
+        if *guardian != Pubkey::default() && guardians[..i].contains(guardian) {
+            msg!("guardian {} is listed more than once", guardian);
+            return Err(error!(ClickerError::DuplicateGuardian));
+        }
+    }
+
+    let guardian_count = guardians.iter().filter(|g| **g != Pubkey::default()).count();
+    if threshold as usize > guardian_count {
+        msg!("threshold {} exceeds {} guardians", threshold, guardian_count);
+        return Err(error!(ClickerError::InvalidGuardianThreshold));
+    }
+
+    game.guardians = guardians;
+    game.guardian_threshold = threshold;
+
+    Ok(())
+}
+
+/// Starts handing the game to `new_owner`. Guardians sign the transaction and
+/// are passed as remaining accounts; the handover completes after a delay
+/// during which the current owner can still cancel it.
+pub fn handle_recover_game(ctx: Context<RecoverGame>, new_owner: Pubkey) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+
+    ctx.accounts.config.require_feature(Config::RECOVERY_ENABLED)?;
+
+    if game.guardian_threshold == 0 {
+        msg!("game {} has no guardians", game.key());
+        return Err(error!(ClickerError::InvalidGuardianThreshold));
+    }
+
+    if let Some(recovery_owner) = game.recovery_owner() {
+        msg!("recovery to {} is pending", recovery_owner);
+        return Err(error!(ClickerError::RecoveryAlreadyPending));
+    }
+
+    let mut approvals: Vec<Pubkey> = Vec::new();
+    for account in ctx.remaining_accounts.iter() {
+        if account.is_signer && game.is_guardian(account.key) && !approvals.contains(account.key) {
+            approvals.push(*account.key);
+        }
+    }
+
+    if approvals.len() < game.guardian_threshold as usize {
+        msg!(
+            "{} guardians signed, threshold is {}",
+            approvals.len(),
+            game.guardian_threshold
+        );
+        return Err(error!(ClickerError::InsufficientGuardianSignatures));
+    }
+
+    let unlock_time = Clock::get()?.unix_timestamp + Game::RECOVERY_DELAY;
+    game.set_recovery_owner(Some(new_owner));
+    game.recovery_unlock_time = unlock_time;
+
+    emit!(RecoveryStarted {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+        new_owner,
+        unlock_time,
+    });
+
+    Ok(())
+}
+
+pub fn handle_cancel_recovery(ctx: Context<CancelRecovery>) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    if game.recovery_owner().is_none() {
+        msg!("game {} has no pending recovery", game.key());
+        return Err(error!(ClickerError::NoRecoveryPending));
+    }
+
+    game.set_recovery_owner(None);
+    game.recovery_unlock_time = 0;
+
+    emit!(RecoveryCancelled {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+    });
+
+    Ok(())
+}
+
+pub fn handle_finalize_recovery(ctx: Context<FinalizeRecovery>) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+
+    let new_owner = match game.recovery_owner() {
+        Some(new_owner) => new_owner,
+        None => {
+            msg!("game {} has no pending recovery", game.key());
+            return Err(error!(ClickerError::NoRecoveryPending));
+        }
+    };
+
+    let current_time = Clock::get()?.unix_timestamp;
+    if current_time < game.recovery_unlock_time {
+        msg!("recovery unlocks at {}, now is {}", game.recovery_unlock_time, current_time);
+        return Err(error!(ClickerError::RecoveryTimelocked));
+    }
+
+    let previous_owner = game.player;
+    game.player = new_owner;
+    game.set_recovery_owner(None);
+    game.recovery_unlock_time = 0;
+    // Anything bound to the lost key is dropped: an open session can only be
+    // revealed by the old signer, and its devices may be compromised too.
+    // The session is closed like a cancel, so an open session is always its
+    // game's active one and pause_session can go by the session alone.
+    if let Some(active_session) = game.active_session() {
+        let Some(session) = ctx.accounts.session.as_mut().filter(|session| session.key() == active_session) else {
+            msg!("game {} has active session {}, which wasn't passed", game.key(), active_session);
+            return Err(error!(ClickerError::InvalidSession));
+        };
+        session.revealed = true;
+        session.actual_clicks = 0;
+        session.end_time = current_time;
+        game.set_active_session(None);
+    }
+    game.devices = [Pubkey::default(); Game::MAX_DEVICES];
+    game.frozen = false;
+    game.unfreeze_requires_guardian = false;
+    ctx.accounts.live_state.sync(game, current_time);
+
+    emit_cpi!(GameRecovered {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+        previous_owner,
+        new_owner,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGuardians<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct RecoverGame<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct CancelRecovery<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FinalizeRecovery<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    /// The game's active session, required while it has one.
+    #[account(mut, has_one = game)]
+    pub session: Option<Account<'info, Session>>,
+    #[account(mut, seeds = [LiveState::SEED, game.key().as_ref()], bump = live_state.bump)]
+    pub live_state: Account<'info, LiveState>,
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ClickerError;
+use crate::events::{EVENT_SCHEMA_VERSION, GameRepaired};
+use crate::state::{Config, Game, LiveState, Session};
+
+/// Support tool for a game bricked by a dangling active session: one that
+/// was closed, already revealed, or isn't a session of this game at all.
+/// Clears the pointer and nothing else. A session still open goes through
+/// admin_void_session instead, which keeps a record on it.
+pub fn handle_admin_repair_game(ctx: Context<AdminRepairGame>) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+    let session = &ctx.accounts.session;
+
+    let Some(active_session) = game.active_session() else {
+        msg!("game {} has no active session", game.key());
+        return Err(error!(ClickerError::NothingToRepair));
+    };
+    if session.key() != active_session {
+        msg!("session {} is not the active session {}", session.key(), active_session);
+        return Err(error!(ClickerError::InvalidSession));
+    }
+
+    let session_exists = !session.data_is_empty();
+    if session_exists && session.owner == &crate::ID {
+        let data = session.try_borrow_data()?;
+        if let Ok(state) = Session::try_deserialize(&mut &data[..]) {
+            if !state.revealed && state.game == game.key() {
+                msg!("session {} is still open, void it instead", session.key());
+                return Err(error!(ClickerError::NothingToRepair));
+            }
+        }
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    game.set_active_session(None);
+    msg!("cleared dangling session {} from game {}", session.key(), game.key());
+
+    ctx.accounts.live_state.sync(game, current_time);
+
+    emit_cpi!(GameRepaired {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+        admin: ctx.accounts.admin.key(),
+        session: session.key(),
+        session_exists,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AdminRepairGame<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    /// CHECK: the game's active session, which may be closed or not a
+    /// Session at all; decoded by hand
+    pub session: UncheckedAccount<'info>,
+    #[account(mut, seeds = [LiveState::SEED, game.key().as_ref()], bump = live_state.bump)]
+    pub live_state: Account<'info, LiveState>,
+}
@@ -0,0 +1,330 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::errors::ClickerError;
+use crate::events::{EVENT_SCHEMA_VERSION, PassRewardClaimed, PremiumPassPurchased};
+use crate::gateway::require_pass;
+use crate::state::{
+    Config, FeatureGate, FreezeGuard, Game, LiveState, PassPayment, PassSeason, PassTier, Receipt, SeasonModifiers,
+    SeasonPass, Treasury,
+};
+
+pub fn handle_create_pass_season(
+    ctx: Context<CreatePassSeason>,
+    season_id: u32,
+    starts_at: i64,
+    ends_at: i64,
+    premium_gems: u64,
+    premium_lamports: u64,
+    tiers: [PassTier; PassSeason::MAX_TIERS]
+) -> Result<()> {
+    let season: &mut Account<PassSeason> = &mut ctx.accounts.season;
+
+    if starts_at >= ends_at {
+        msg!("season runs {}..{}", starts_at, ends_at);
+        return Err(error!(ClickerError::InvalidPassSeason));
+    }
+
+    season.season_id = season_id;
+    season.starts_at = starts_at;
+    season.ends_at = ends_at;
+    season.premium_gems = premium_gems;
+    season.premium_lamports = premium_lamports;
+    season.tiers = tiers;
+    season.bump = ctx.bumps.season;
+
+    Ok(())
+}
+
+/// Sets the rule changes a season makes and makes it the season reveals
+/// are modified by, replacing any other. Empty modifiers on that season
+/// stop modifying reveals.
+pub fn handle_set_season_modifiers(
+    ctx: Context<SetSeasonModifiers>,
+    _season_id: u32,
+    modifiers: SeasonModifiers
+) -> Result<()> {
+    let config: &mut Account<Config> = &mut ctx.accounts.config;
+    let season: &mut Account<PassSeason> = &mut ctx.accounts.season;
+
+    season.modifiers = modifiers;
+    if !modifiers.is_empty() {
+        config.modifier_season = season.key();
+    } else if config.modifier_season == season.key() {
+        config.modifier_season = Pubkey::default();
+    }
+
+    Ok(())
+}
+
+/// Progress counts from the game's xp at joining, so xp from before the
+/// season doesn't unlock tiers.
+pub fn handle_join_season_pass(ctx: Context<JoinSeasonPass>, _season_id: u32) -> Result<()> {
+    let game: &Account<Game> = &ctx.accounts.game;
+    let season: &Account<PassSeason> = &ctx.accounts.season;
+
+    ctx.accounts.config.require_feature(Config::SEASON_PASS_ENABLED)?;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    if !season.is_running(current_time) {
+        msg!("season {} runs {}..{}, now is {}", season.season_id, season.starts_at, season.ends_at, current_time);
+        return Err(error!(ClickerError::SeasonNotActive));
+    }
+
+    let pass: &mut Account<SeasonPass> = &mut ctx.accounts.pass;
+    pass.season = season.key();
+    pass.game = game.key();
+    pass.start_xp = game.xp;
+    pass.bump = ctx.bumps.pass;
+
+    Ok(())
+}
+
+pub fn handle_buy_premium_pass(ctx: Context<BuyPremiumPass>, _season_id: u32, payment: PassPayment) -> Result<()> {
+    let season: &Account<PassSeason> = &ctx.accounts.season;
+
+    ctx.accounts.config.require_feature(Config::SEASON_PASS_ENABLED)?;
+
+    if &ctx.accounts.game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, ctx.accounts.game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    if !season.is_running(current_time) {
+        msg!("season {} runs {}..{}, now is {}", season.season_id, season.starts_at, season.ends_at, current_time);
+        return Err(error!(ClickerError::SeasonNotActive));
+    }
+
+    if ctx.accounts.pass.premium {
+        msg!("pass for season {} is already premium", season.season_id);
+        return Err(error!(ClickerError::PremiumPassOwned));
+    }
+
+    let (gems, lamports) = match payment {
+        PassPayment::Gems => (season.premium_gems, 0),
+        PassPayment::Lamports => (0, season.premium_lamports),
+    };
+    if gems == 0 && lamports == 0 {
+        msg!("season {} doesn't sell premium for {:?}", season.season_id, payment);
+        return Err(error!(ClickerError::PremiumNotForSale));
+    }
+
+    if gems > 0 {
+        let game: &mut Account<Game> = &mut ctx.accounts.game;
+        game.require_unfrozen()?;
+        if game.gems < gems {
+            msg!("costs {} gems, player has {}", gems, game.gems);
+            return Err(error!(ClickerError::InsufficientGems));
+        }
+        game.gems -= gems;
+    } else {
+        let Some(receipt) = ctx.accounts.receipt.as_mut() else {
+            msg!("premium for {} lamports needs a receipt", lamports);
+            return Err(error!(ClickerError::ReceiptRequired));
+        };
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.player.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            lamports,
+        )?;
+        ctx.accounts.treasury.total_received += lamports;
+
+        // Kept like a gem purchase so the SOL can be refunded in the window
+        receipt.game = ctx.accounts.game.key();
+        receipt.buyer = *ctx.accounts.player.key;
+        receipt.gems = 0;
+        receipt.lamports = lamports;
+        receipt.purchased_at = current_time;
+        receipt.bump = ctx.bumps.receipt.unwrap_or_default();
+        receipt.pass = ctx.accounts.pass.key();
+        ctx.accounts.game.purchase_count += 1;
+    }
+
+    ctx.accounts.pass.premium = true;
+
+    emit!(PremiumPassPurchased {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: ctx.accounts.game.key(),
+        season_id: season.season_id,
+        gems,
+        lamports,
+    });
+
+    Ok(())
+}
+
+/// Pays out a tier the pass has earned. Pause exempt: a premium pass was
+/// paid for, so switching season passes off doesn't hold its tiers back.
+pub fn handle_claim_pass_reward(ctx: Context<ClaimPassReward>, _season_id: u32, tier: u8) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+    let season: &Account<PassSeason> = &ctx.accounts.season;
+    let pass: &mut Account<SeasonPass> = &mut ctx.accounts.pass;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+    game.require_unfrozen()?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    if !season.is_running(current_time) {
+        msg!("season {} runs {}..{}, now is {}", season.season_id, season.starts_at, season.ends_at, current_time);
+        return Err(error!(ClickerError::SeasonNotActive));
+    }
+    require_pass(&ctx.accounts.config, &game.player, ctx.accounts.gateway_token.as_deref(), current_time)?;
+
+    let reward = match season.tiers.get(tier as usize) {
+        Some(reward) if reward.is_used() => *reward,
+        _ => {
+            msg!("season {} has no tier {}", season.season_id, tier);
+            return Err(error!(ClickerError::InvalidPassTier));
+        }
+    };
+
+    if pass.is_claimed(tier) {
+        msg!("tier {} was already claimed", tier);
+        return Err(error!(ClickerError::TierAlreadyClaimed));
+    }
+
+    if reward.premium_only && !pass.premium {
+        msg!("tier {} is on the premium track", tier);
+        return Err(error!(ClickerError::PremiumPassRequired));
+    }
+
+    let earned_xp = pass.earned_xp(game.xp);
+    if earned_xp < reward.xp {
+        msg!("tier {} needs {} xp this season, game has {}", tier, reward.xp, earned_xp);
+        return Err(error!(ClickerError::PassTierLocked));
+    }
+
+    pass.claimed_tiers |= 1 << tier;
+    game.gems += reward.reward_gems;
+    game.credit_clicks(reward.reward_clicks);
+    ctx.accounts.live_state.sync(game, Clock::get()?.unix_timestamp);
+
+    emit!(PassRewardClaimed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+        season_id: season.season_id,
+        tier,
+        reward_gems: reward.reward_gems,
+        reward_clicks: reward.reward_clicks,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(season_id: u32)]
+pub struct CreatePassSeason<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + PassSeason::MAXIMUM_SIZE,
+        seeds = [PassSeason::SEED, &season_id.to_le_bytes()],
+        bump
+    )]
+    pub season: Account<'info, PassSeason>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(season_id: u32)]
+pub struct SetSeasonModifiers<'info> {
+    #[account(mut, seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [PassSeason::SEED, &season_id.to_le_bytes()], bump = season.bump)]
+    pub season: Account<'info, PassSeason>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(season_id: u32)]
+pub struct JoinSeasonPass<'info> {
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(seeds = [PassSeason::SEED, &season_id.to_le_bytes()], bump = season.bump)]
+    pub season: Account<'info, PassSeason>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + SeasonPass::MAXIMUM_SIZE,
+        seeds = [SeasonPass::SEED, season.key().as_ref(), game.key().as_ref()],
+        bump
+    )]
+    pub pass: Account<'info, SeasonPass>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(season_id: u32)]
+pub struct BuyPremiumPass<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(seeds = [PassSeason::SEED, &season_id.to_le_bytes()], bump = season.bump)]
+    pub season: Account<'info, PassSeason>,
+    #[account(
+        mut,
+        seeds = [SeasonPass::SEED, season.key().as_ref(), game.key().as_ref()],
+        bump = pass.bump
+    )]
+    pub pass: Account<'info, SeasonPass>,
+    #[account(mut, seeds = [Treasury::SEED], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    /// Required when paying with lamports, so the purchase can be refunded.
+    #[account(
+        init,
+        payer = player,
+        space = 8 + Receipt::MAXIMUM_SIZE,
+        seeds = [Receipt::SEED, game.key().as_ref(), &game.purchase_count.to_le_bytes()],
+        bump
+    )]
+    pub receipt: Option<Account<'info, Receipt>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(season_id: u32)]
+pub struct ClaimPassReward<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(seeds = [PassSeason::SEED, &season_id.to_le_bytes()], bump = season.bump)]
+    pub season: Account<'info, PassSeason>,
+    #[account(
+        mut,
+        seeds = [SeasonPass::SEED, season.key().as_ref(), game.key().as_ref()],
+        bump = pass.bump
+    )]
+    pub pass: Account<'info, SeasonPass>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    /// CHECK: parsed and checked by gateway::require_pass. Required while
+    /// Config.gatekeeper_network is set.
+    pub gateway_token: Option<UncheckedAccount<'info>>,
+    #[account(mut, seeds = [LiveState::SEED, game.key().as_ref()], bump = live_state.bump)]
+    pub live_state: Account<'info, LiveState>,
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::errors::ClickerError;
+use crate::state::{Game, SessionIndex};
+
+/// Creates the game's SessionIndex. From then on every session the game
+/// starts has to pass it, and the starter's payer funds its growth.
+pub fn handle_initialize_session_index(ctx: Context<InitializeSessionIndex>) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+    let session_index: &mut Account<SessionIndex> = &mut ctx.accounts.session_index;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    session_index.game = game.key();
+    session_index.count = 0;
+    session_index.bump = ctx.bumps.session_index;
+    game.set_session_index();
+
+    Ok(())
+}
+
+/// Appends `session` to the game's index, if it keeps one, growing the
+/// account by an entry paid for by `payer`.
+pub(crate) fn index_session<'info>(
+    game: &Account<'info, Game>,
+    session_index: Option<&mut Account<'info, SessionIndex>>,
+    session: Pubkey,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>
+) -> Result<()> {
+    let session_index = match session_index {
+        Some(session_index) => session_index,
+        None if game.has_session_index() => {
+            msg!("game {} keeps a session index, none was passed", game.key());
+            return Err(error!(ClickerError::SessionIndexRequired));
+        }
+        None => return Ok(()),
+    };
+
+    let number = session_index.count;
+    let space = SessionIndex::entry_offset(number + 1);
+    let info = session_index.to_account_info();
+    let rent_exempt = Rent::get()?.minimum_balance(space);
+    if rent_exempt > info.lamports() {
+        transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: info.clone(),
+                },
+            ),
+            rent_exempt - info.lamports(),
+        )?;
+    }
+    info.realloc(space, false)?;
+    info.try_borrow_mut_data()?[SessionIndex::entry_offset(number)..space].copy_from_slice(session.as_ref());
+    session_index.count = number + 1;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeSessionIndex<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + SessionIndex::MAXIMUM_SIZE,
+        seeds = [SessionIndex::SEED, game.key().as_ref()],
+        bump
+    )]
+    pub session_index: Account<'info, SessionIndex>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
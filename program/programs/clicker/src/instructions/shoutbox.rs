@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ClickerError;
+use crate::events::{EVENT_SCHEMA_VERSION, MessagePosted};
+use crate::state::{Config, FeatureGate, FreezeGuard, Game, PassSeason, Shoutbox, ShoutboxMessage};
+
+/// How a post is paid for.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PostPayment {
+    Clicks,
+    Gems,
+}
+
+pub fn handle_create_shoutbox(
+    ctx: Context<CreateShoutbox>,
+    _season_id: u32,
+    fee_clicks: u64,
+    fee_gems: u64
+) -> Result<()> {
+    if fee_clicks == 0 && fee_gems == 0 {
+        msg!("shoutbox needs a fee in clicks or gems");
+        return Err(error!(ClickerError::PaymentNotAccepted));
+    }
+
+    let mut shoutbox = ctx.accounts.shoutbox.load_init()?;
+    shoutbox.season = ctx.accounts.season.key();
+    shoutbox.fee_clicks = fee_clicks;
+    shoutbox.fee_gems = fee_gems;
+    shoutbox.bump = ctx.bumps.shoutbox;
+
+    Ok(())
+}
+
+/// Posts to the season's shoutbox while the season runs, once per
+/// Shoutbox::POST_COOLDOWN per game. The fee is burned.
+pub fn handle_post_message(
+    ctx: Context<PostMessage>,
+    _season_id: u32,
+    text: String,
+    payment: PostPayment
+) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+    let season = &ctx.accounts.season;
+
+    ctx.accounts.config.require_feature(Config::SHOUTBOX_ENABLED)?;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+    game.require_unfrozen()?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    if !season.is_running(current_time) {
+        msg!("season {} runs {}..{}, now is {}", season.season_id, season.starts_at, season.ends_at, current_time);
+        return Err(error!(ClickerError::SeasonNotActive));
+    }
+    if game.last_post_at != 0 && current_time < game.last_post_at + Shoutbox::POST_COOLDOWN {
+        msg!("game can post again at {}, now is {}", game.last_post_at + Shoutbox::POST_COOLDOWN, current_time);
+        return Err(error!(ClickerError::PostCooldown));
+    }
+    if text.is_empty() || text.len() > ShoutboxMessage::MAX_LEN {
+        msg!("message is {} bytes, limit is {}", text.len(), ShoutboxMessage::MAX_LEN);
+        return Err(error!(ClickerError::InvalidMessage));
+    }
+
+    let mut shoutbox = ctx.accounts.shoutbox.load_mut()?;
+    match payment {
+        PostPayment::Clicks => {
+            if shoutbox.fee_clicks == 0 {
+                msg!("season {} doesn't take clicks for posts", season.season_id);
+                return Err(error!(ClickerError::PaymentNotAccepted));
+            }
+            if !game.spend_clicks(shoutbox.fee_clicks) {
+                msg!("costs {} clicks, player has {}", shoutbox.fee_clicks, game.spendable_clicks);
+                return Err(error!(ClickerError::InsufficientClicks));
+            }
+        }
+        PostPayment::Gems => {
+            if shoutbox.fee_gems == 0 {
+                msg!("season {} doesn't take gems for posts", season.season_id);
+                return Err(error!(ClickerError::PaymentNotAccepted));
+            }
+            if game.gems < shoutbox.fee_gems {
+                msg!("costs {} gems, player has {}", shoutbox.fee_gems, game.gems);
+                return Err(error!(ClickerError::InsufficientGems));
+            }
+            game.gems -= shoutbox.fee_gems;
+        }
+    }
+
+    game.last_post_at = current_time;
+    let index = shoutbox.push(game.key(), text.as_bytes(), current_time);
+
+    emit!(MessagePosted {
+        schema_version: EVENT_SCHEMA_VERSION,
+        season: season.key(),
+        game: game.key(),
+        index,
+        text,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(season_id: u32)]
+pub struct CreateShoutbox<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(seeds = [PassSeason::SEED, &season_id.to_le_bytes()], bump = season.bump)]
+    pub season: Account<'info, PassSeason>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Shoutbox::MAXIMUM_SIZE,
+        seeds = [Shoutbox::SEED, season.key().as_ref()],
+        bump
+    )]
+    pub shoutbox: AccountLoader<'info, Shoutbox>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(season_id: u32)]
+pub struct PostMessage<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(seeds = [PassSeason::SEED, &season_id.to_le_bytes()], bump = season.bump)]
+    pub season: Account<'info, PassSeason>,
+    #[account(mut, seeds = [Shoutbox::SEED, season.key().as_ref()], bump = shoutbox.load()?.bump)]
+    pub shoutbox: AccountLoader<'info, Shoutbox>,
+}
@@ -0,0 +1,205 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ClickerError;
+use crate::state::{Config, FeatureGate, Game, LiveState, SkillEffect, SkillTree};
+
+pub fn handle_initialize_skill_tree(ctx: Context<InitializeSkillTree>) -> Result<()> {
+    let mut tree = ctx.accounts.skill_tree.load_init()?;
+    tree.bump = ctx.bumps.skill_tree;
+    Ok(())
+}
+
+pub fn handle_set_skill_node(
+    ctx: Context<SetSkillNode>,
+    node_id: u8,
+    prerequisites: u64,
+    cost: u8,
+    effect: SkillEffect,
+    magnitude_bps: u16
+) -> Result<()> {
+    let mut tree = ctx.accounts.skill_tree.load_mut()?;
+
+    if node_id as usize >= SkillTree::MAX_NODES {
+        msg!("node {} is out of range, the tree has {}", node_id, SkillTree::MAX_NODES);
+        return Err(error!(ClickerError::InvalidSkillNode));
+    }
+
+    // only lower-numbered prerequisites, so the graph stays a DAG
+    let lower_nodes = (1u64 << node_id) - 1;
+    if prerequisites & !lower_nodes != 0 {
+        msg!("prerequisites {:#x} of node {} are not all lower nodes", prerequisites, node_id);
+        return Err(error!(ClickerError::InvalidSkillNode));
+    }
+
+    if magnitude_bps > SkillTree::MAX_MAGNITUDE_BPS {
+        msg!("magnitude {} bps exceeds {}", magnitude_bps, SkillTree::MAX_MAGNITUDE_BPS);
+        return Err(error!(ClickerError::InvalidSkillNode));
+    }
+
+    // Games cache the effects of nodes they unlocked, so those can't change
+    let node = &mut tree.nodes[node_id as usize];
+    if node.unlocked == 1 {
+        msg!("node {} has been unlocked, it can't be redefined", node_id);
+        return Err(error!(ClickerError::SkillNodeUnlocked));
+    }
+
+    node.prerequisites = prerequisites;
+    node.cost = cost;
+    node.effect = effect as u8;
+    node.magnitude_bps = magnitude_bps;
+    node.defined = 1;
+
+    Ok(())
+}
+
+pub fn handle_unlock_skill(ctx: Context<UnlockSkill>, node_id: u8) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+    let mut tree = ctx.accounts.skill_tree.load_mut()?;
+
+    ctx.accounts.config.require_feature(Config::SKILLS_ENABLED)?;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    let node = match tree.nodes.get_mut(node_id as usize) {
+        Some(node) if node.defined == 1 => node,
+        _ => {
+            msg!("node {} is not defined", node_id);
+            return Err(error!(ClickerError::InvalidSkillNode));
+        }
+    };
+
+    let bit = 1u64 << node_id;
+    if game.unlocked_skills & bit != 0 {
+        msg!("node {} is already unlocked", node_id);
+        return Err(error!(ClickerError::SkillAlreadyUnlocked));
+    }
+
+    if game.unlocked_skills & node.prerequisites != node.prerequisites {
+        msg!(
+            "node {} needs {:#x}, unlocked {:#x}",
+            node_id,
+            node.prerequisites,
+            game.unlocked_skills
+        );
+        return Err(error!(ClickerError::SkillPrerequisitesMissing));
+    }
+
+    if game.available_skill_points() < node.cost as u32 {
+        msg!(
+            "node {} costs {} points, player has {}",
+            node_id,
+            node.cost,
+            game.available_skill_points()
+        );
+        return Err(error!(ClickerError::NotEnoughSkillPoints));
+    }
+
+    game.unlocked_skills |= bit;
+    game.skill_points_spent += node.cost as u32;
+    node.unlocked = 1;
+
+    // effects are cached on the game so the hot paths don't load the tree
+    let bonus = match SkillEffect::from_u8(node.effect) {
+        Some(SkillEffect::CpsCap) => &mut game.skill_cps_bonus_bps,
+        Some(SkillEffect::IdleRate) => &mut game.skill_idle_bonus_bps,
+        Some(SkillEffect::CritChance) => &mut game.skill_crit_chance_bps,
+        None => {
+            msg!("node {} has unknown effect {}", node_id, node.effect);
+            return Err(error!(ClickerError::InvalidSkillNode));
+        }
+    };
+    *bonus = bonus.saturating_add(node.magnitude_bps).min(SkillTree::MAX_BONUS_BPS);
+
+    Ok(())
+}
+
+/// Refunds every spent skill point for a click cost proportional to the
+/// points refunded. Limited to once per RESPEC_COOLDOWN.
+pub fn handle_respec_skills(ctx: Context<RespecSkills>) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+
+    ctx.accounts.config.require_feature(Config::SKILLS_ENABLED)?;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    if game.last_respec_at != 0 && current_time < game.last_respec_at + SkillTree::RESPEC_COOLDOWN {
+        msg!(
+            "respec available at {}, now is {}",
+            game.last_respec_at + SkillTree::RESPEC_COOLDOWN,
+            current_time
+        );
+        return Err(error!(ClickerError::RespecOnCooldown));
+    }
+
+    let cost = game.skill_points_spent as u64 * SkillTree::RESPEC_COST_PER_POINT;
+    if !game.spend_clicks(cost) {
+        msg!("respec costs {} clicks, player has {}", cost, game.spendable_clicks);
+        return Err(error!(ClickerError::InsufficientClicks));
+    }
+
+    game.unlocked_skills = 0;
+    game.skill_points_spent = 0;
+    game.skill_cps_bonus_bps = 0;
+    game.skill_idle_bonus_bps = 0;
+    game.skill_crit_chance_bps = 0;
+    game.last_respec_at = current_time;
+
+    ctx.accounts.live_state.sync(game, current_time);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeSkillTree<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + SkillTree::MAXIMUM_SIZE,
+        seeds = [SkillTree::SEED],
+        bump
+    )]
+    pub skill_tree: AccountLoader<'info, SkillTree>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetSkillNode<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [SkillTree::SEED], bump = skill_tree.load()?.bump)]
+    pub skill_tree: AccountLoader<'info, SkillTree>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UnlockSkill<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [SkillTree::SEED], bump = skill_tree.load()?.bump)]
+    pub skill_tree: AccountLoader<'info, SkillTree>,
+}
+
+#[derive(Accounts)]
+pub struct RespecSkills<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [LiveState::SEED, game.key().as_ref()], bump = live_state.bump)]
+    pub live_state: Account<'info, LiveState>,
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::errors::ClickerError;
+use crate::state::{Config, FeatureGate, Game, Snapshot};
+
+pub fn handle_export_snapshot(ctx: Context<ExportSnapshot>) -> Result<()> {
+    let game: &Account<Game> = &ctx.accounts.game;
+    let snapshot: &mut Account<Snapshot> = &mut ctx.accounts.snapshot;
+
+    ctx.accounts.config.require_feature(Config::SNAPSHOTS_ENABLED)?;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    // Only settled totals are exported; an open session would be lost
+    if let Some(active_session) = game.active_session() {
+        msg!("session {} is still active", active_session);
+        return Err(error!(ClickerError::SessionAlreadyActive));
+    }
+
+    let clock = Clock::get()?;
+
+    snapshot.version = Snapshot::VERSION;
+    snapshot.game = game.key();
+    snapshot.player = game.player;
+    snapshot.clicks = game.clicks;
+    snapshot.total_clicks = game.lifetime_clicks;
+    snapshot.spendable_clicks = game.spendable_clicks;
+    snapshot.last_session_end = game.last_session_end;
+    snapshot.game_hash = hash(&game.to_account_info().try_borrow_data()?).to_bytes();
+    snapshot.exported_at = clock.unix_timestamp;
+    snapshot.exported_slot = clock.slot;
+    snapshot.bump = ctx.bumps.snapshot;
+
+    Ok(())
+}
+
+/// Returns the snapshot as return data so a successor program can read it
+/// through CPI and rely on this program having validated it.
+pub fn handle_read_snapshot(ctx: Context<ReadSnapshot>) -> Result<Snapshot> {
+    Ok(ctx.accounts.snapshot.clone().into_inner())
+}
+
+#[derive(Accounts)]
+pub struct ExportSnapshot<'info> {
+    pub game: Account<'info, Game>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Snapshot::MAXIMUM_SIZE,
+        seeds = [Snapshot::SEED, game.key().as_ref()],
+        bump
+    )]
+    pub snapshot: Account<'info, Snapshot>,
+    pub player: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReadSnapshot<'info> {
+    #[account(seeds = [Snapshot::SEED, snapshot.game.as_ref()], bump = snapshot.bump)]
+    pub snapshot: Account<'info, Snapshot>,
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ClickerError;
+use crate::events::{EVENT_SCHEMA_VERSION, GameSoftReset};
+use crate::state::{FreezeGuard, Game, LiveState};
+
+/// Starts the player over without touching what they paid for or their
+/// account security, see `Game::soft_reset`. `confirmation` must be
+/// `Game::SOFT_RESET_CONFIRMATION` so a misclick or a bad client can't do it.
+pub fn handle_soft_reset_game(ctx: Context<SoftResetGame>, confirmation: String) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    if confirmation != Game::SOFT_RESET_CONFIRMATION {
+        msg!(
+            "confirmation {:?} does not match {:?}",
+            confirmation,
+            Game::SOFT_RESET_CONFIRMATION
+        );
+        return Err(error!(ClickerError::ConfirmationMismatch));
+    }
+
+    game.require_unfrozen()?;
+
+    // An open session would credit pre-reset clicks afterwards
+    if let Some(active_session) = game.active_session() {
+        msg!("session {} is still active", active_session);
+        return Err(error!(ClickerError::SessionAlreadyActive));
+    }
+
+    let previous_total_clicks = game.lifetime_clicks;
+    let previous_level = game.level;
+    game.soft_reset();
+
+    ctx.accounts.live_state.sync(game, Clock::get()?.unix_timestamp);
+
+    emit_cpi!(GameSoftReset {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+        player: game.player,
+        previous_total_clicks,
+        previous_level,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SoftResetGame<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(mut, seeds = [LiveState::SEED, game.key().as_ref()], bump = live_state.bump)]
+    pub live_state: Account<'info, LiveState>,
+}
@@ -0,0 +1,209 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+
+use crate::ed25519::verify_preceding_signature;
+use crate::errors::ClickerError;
+use crate::instructions::index_session;
+use crate::state::{
+    ChallengeEntry, Config, FeatureGate, FreezeGuard, Game, LiveState, Session, SessionIndex,
+    WeeklyChallenge,
+};
+
+pub fn handle_start_session(
+    ctx: Context<StartSession>,
+    commitment: [u8; 32],
+    challenge: Option<[u8; 32]>
+) -> Result<()> {
+    open_session(ctx.accounts, commitment, challenge, 0)
+}
+
+/// Starts a session lasting exactly Config.time_attack_seconds. It reveals
+/// between the end of the window and Config.time_attack_grace after, and
+/// can't be paused or extended.
+pub fn handle_start_time_attack(
+    ctx: Context<StartSession>,
+    commitment: [u8; 32],
+    challenge: Option<[u8; 32]>
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    config.require_feature(Config::TIME_ATTACK_ENABLED)?;
+    if config.time_attack_seconds <= 0 {
+        msg!("time attack window is {}s", config.time_attack_seconds);
+        return Err(error!(ClickerError::TimeAttackDisabled));
+    }
+    let window = config.time_attack_seconds;
+    open_session(ctx.accounts, commitment, challenge, window)
+}
+
+/// Starts a session whose credit decays once it runs past
+/// Config.endurance_optimal_seconds, see Config::endurance_multiplier.
+pub fn handle_start_endurance(
+    ctx: Context<StartSession>,
+    commitment: [u8; 32],
+    challenge: Option<[u8; 32]>
+) -> Result<()> {
+    ctx.accounts.config.require_feature(Config::ENDURANCE_ENABLED)?;
+    open_session(ctx.accounts, commitment, challenge, 0)?;
+    ctx.accounts.session.endurance = true;
+    Ok(())
+}
+
+fn open_session(
+    accounts: &mut StartSession,
+    commitment: [u8; 32],
+    challenge: Option<[u8; 32]>,
+    time_attack_seconds: i64
+) -> Result<()> {
+    let game: &mut Account<Game> = &mut accounts.game;
+    let session: &mut Account<Session> = &mut accounts.session;
+    let config: &Account<Config> = &accounts.config;
+
+    // The attestor signs (session address || challenge). Session accounts can
+    // only be created once, so each signed challenge is good for one session.
+    if let Some(challenge) = challenge {
+        let mut message = [0u8; 64];
+        message[..32].copy_from_slice(session.key().as_ref());
+        message[32..].copy_from_slice(&challenge);
+        verify_preceding_signature(&accounts.instructions, &config.attestor, &message)?;
+    }
+
+    let current_time = begin_session(
+        game,
+        session,
+        accounts.player.key,
+        accounts.payer.key,
+        config,
+        commitment,
+        challenge,
+    )?;
+    if time_attack_seconds > 0 {
+        session.deadline = current_time + time_attack_seconds + config.time_attack_grace;
+        session.time_attack_seconds = time_attack_seconds;
+    }
+    // Passing a weekly challenge and the game's entry enters the session in it
+    match (accounts.weekly_challenge.as_ref(), accounts.challenge_entry.as_mut()) {
+        (Some(weekly_challenge), Some(entry)) => {
+            config.require_feature(Config::WEEKLY_CHALLENGES_ENABLED)?;
+            if entry.challenge != weekly_challenge.key() {
+                msg!("entry is for challenge {}, not {}", entry.challenge, weekly_challenge.key());
+                return Err(error!(ClickerError::ChallengeEntryRequired));
+            }
+            if !weekly_challenge.is_running(current_time) {
+                msg!(
+                    "challenge {} runs {}..{}, now is {}",
+                    weekly_challenge.challenge_id,
+                    weekly_challenge.starts_at,
+                    weekly_challenge.ends_at,
+                    current_time
+                );
+                return Err(error!(ClickerError::ChallengeNotRunning));
+            }
+            let max_sessions = weekly_challenge.rules.max_sessions;
+            if max_sessions > 0 && entry.sessions >= max_sessions {
+                msg!("game entered {} of {} challenge sessions", entry.sessions, max_sessions);
+                return Err(error!(ClickerError::ChallengeSessionLimit));
+            }
+            entry.sessions += 1;
+            session.weekly_challenge = weekly_challenge.key();
+        }
+        (None, None) => {}
+        _ => {
+            msg!("weekly challenge and entry must be passed together");
+            return Err(error!(ClickerError::ChallengeEntryRequired));
+        }
+    }
+    index_session(
+        game,
+        accounts.session_index.as_mut(),
+        session.key(),
+        &accounts.payer,
+        &accounts.system_program,
+    )?;
+
+    accounts.live_state.sync(game, current_time);
+
+    Ok(())
+}
+
+/// Checks the game can start a session and fills in `session`, returning the
+/// start time. The caller verifies a passed challenge.
+pub(crate) fn begin_session(
+    game: &mut Account<Game>,
+    session: &mut Account<Session>,
+    player: &Pubkey,
+    payer: &Pubkey,
+    config: &Config,
+    commitment: [u8; 32],
+    challenge: Option<[u8; 32]>
+) -> Result<i64> {
+    // Verify player ownership
+    if &game.player != player {
+        msg!("signer {} is not player {}", player, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    game.require_unfrozen()?;
+
+    if game.has_fallen() {
+        msg!("hardcore game {} has fallen", game.key());
+        return Err(error!(ClickerError::GameFallen));
+    }
+
+    // Check if there's already an active session
+    if let Some(active_session) = game.active_session() {
+        msg!("session {} is still active", active_session);
+        return Err(error!(ClickerError::SessionAlreadyActive));
+    }
+
+    if challenge.is_none() && config.require_challenge {
+        msg!("config requires an attestor challenge, none was passed");
+        return Err(error!(ClickerError::ChallengeRequired));
+    }
+
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    if game.first_session_slot == 0 {
+        game.first_session_slot = clock.slot;
+    }
+
+    session.player = *player;
+    session.game = game.key();
+    session.commitment = commitment;
+    session.start_time = current_time;
+    session.revealed = false;
+    session.challenge = challenge;
+    session.rent_payer = *payer;
+    // Fixed now so later config changes don't move an open session's deadline
+    session.deadline = config.session_deadline(current_time);
+
+    game.set_active_session(Some(session.key()));
+
+    Ok(current_time)
+}
+
+#[derive(Accounts)]
+pub struct StartSession<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    #[account(init, payer = payer, space = 8 + Session::MAXIMUM_SIZE)]
+    pub session: Account<'info, Session>,
+    pub player: Signer<'info>,
+    // A sponsor such as a guild treasury can fund the rent; close_session
+    // refunds it to them
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    /// CHECK: the instructions sysvar, checked by address
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    #[account(mut, seeds = [LiveState::SEED, game.key().as_ref()], bump = live_state.bump)]
+    pub live_state: Account<'info, LiveState>,
+    // Required once the game keeps one
+    #[account(mut, has_one = game)]
+    pub session_index: Option<Account<'info, SessionIndex>>,
+    pub weekly_challenge: Option<Account<'info, WeeklyChallenge>>,
+    #[account(mut, has_one = game)]
+    pub challenge_entry: Option<Account<'info, ChallengeEntry>>,
+}
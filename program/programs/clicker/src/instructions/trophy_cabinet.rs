@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::{Metadata, MetadataAccount};
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::errors::ClickerError;
+use crate::state::{Config, FeatureGate, Game, Trophy, TrophyCabinet};
+
+pub fn handle_initialize_trophy_cabinet(ctx: Context<InitializeTrophyCabinet>) -> Result<()> {
+    let game = &ctx.accounts.game;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    let cabinet: &mut Account<TrophyCabinet> = &mut ctx.accounts.trophy_cabinet;
+    cabinet.game = game.key();
+    cabinet.bump = ctx.bumps.trophy_cabinet;
+
+    Ok(())
+}
+
+/// Pins an NFT the player holds from the config's trophy collection to
+/// `slot`, replacing whatever was there.
+pub fn handle_pin_trophy(ctx: Context<PinTrophy>, slot: u8, mint: Pubkey) -> Result<()> {
+    let game = &ctx.accounts.game;
+    let config: &Account<Config> = &ctx.accounts.config;
+    let cabinet: &mut Account<TrophyCabinet> = &mut ctx.accounts.trophy_cabinet;
+
+    config.require_feature(Config::TROPHY_CABINET_ENABLED)?;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+    if slot as usize >= TrophyCabinet::MAX_TROPHIES {
+        msg!("slot {} is past the cabinet's {}", slot, TrophyCabinet::MAX_TROPHIES);
+        return Err(error!(ClickerError::InvalidTrophySlot));
+    }
+    if let Some(pinned) = cabinet.slot_of(&mint) {
+        msg!("mint {} is already pinned to slot {}", mint, pinned);
+        return Err(error!(ClickerError::TrophyAlreadyPinned));
+    }
+
+    verify_trophy(config, game, &mint, &ctx.accounts.token_account, &ctx.accounts.metadata)?;
+
+    cabinet.trophies[slot as usize] = Trophy {
+        mint,
+        verified_at: Clock::get()?.unix_timestamp,
+    };
+
+    Ok(())
+}
+
+pub fn handle_unpin_trophy(ctx: Context<UnpinTrophy>, slot: u8) -> Result<()> {
+    let game = &ctx.accounts.game;
+    let cabinet: &mut Account<TrophyCabinet> = &mut ctx.accounts.trophy_cabinet;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+    match cabinet.trophies.get(slot as usize) {
+        Some(trophy) if !trophy.is_empty() => {}
+        _ => {
+            msg!("slot {} holds no trophy", slot);
+            return Err(error!(ClickerError::TrophySlotEmpty));
+        }
+    }
+
+    cabinet.trophies[slot as usize] = Trophy::default();
+
+    Ok(())
+}
+
+/// Refreshes a pinned trophy's verified_at while the player still holds
+/// it. Anyone can call it; it only ever proves ownership.
+pub fn handle_reverify_trophy(ctx: Context<ReverifyTrophy>, mint: Pubkey) -> Result<()> {
+    let game = &ctx.accounts.game;
+    let cabinet: &mut Account<TrophyCabinet> = &mut ctx.accounts.trophy_cabinet;
+
+    let Some(slot) = cabinet.slot_of(&mint) else {
+        msg!("mint {} is not pinned in game {}'s cabinet", mint, game.key());
+        return Err(error!(ClickerError::TrophySlotEmpty));
+    };
+
+    verify_trophy(&ctx.accounts.config, game, &mint, &ctx.accounts.token_account, &ctx.accounts.metadata)?;
+
+    cabinet.trophies[slot].verified_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+/// The player holds `mint` right now, and it is a verified member of the
+/// trophy collection.
+fn verify_trophy(
+    config: &Config,
+    game: &Game,
+    mint: &Pubkey,
+    token_account: &TokenAccount,
+    metadata: &MetadataAccount
+) -> Result<()> {
+    if token_account.mint != *mint || token_account.owner != game.player || token_account.amount == 0 {
+        msg!(
+            "token account holds {} of mint {} for {}, expected mint {} for {}",
+            token_account.amount,
+            token_account.mint,
+            token_account.owner,
+            mint,
+            game.player
+        );
+        return Err(error!(ClickerError::NftNotHeld));
+    }
+
+    match &metadata.collection {
+        Some(collection)
+            if collection.verified
+                && config.trophy_collection != Pubkey::default()
+                && collection.key == config.trophy_collection => {}
+        _ => {
+            msg!("mint {} is not verified in collection {}", mint, config.trophy_collection);
+            return Err(error!(ClickerError::CollectionNotVerified));
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeTrophyCabinet<'info> {
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + TrophyCabinet::MAXIMUM_SIZE,
+        seeds = [TrophyCabinet::SEED, game.key().as_ref()],
+        bump
+    )]
+    pub trophy_cabinet: Account<'info, TrophyCabinet>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(slot: u8, mint: Pubkey)]
+pub struct PinTrophy<'info> {
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(mut, has_one = game)]
+    pub trophy_cabinet: Account<'info, TrophyCabinet>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        seeds = [b"metadata", Metadata::id().as_ref(), mint.as_ref()],
+        seeds::program = Metadata::id(),
+        bump
+    )]
+    pub metadata: Account<'info, MetadataAccount>,
+}
+
+#[derive(Accounts)]
+pub struct UnpinTrophy<'info> {
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(mut, has_one = game)]
+    pub trophy_cabinet: Account<'info, TrophyCabinet>,
+}
+
+#[derive(Accounts)]
+#[instruction(mint: Pubkey)]
+pub struct ReverifyTrophy<'info> {
+    pub game: Account<'info, Game>,
+    #[account(mut, has_one = game)]
+    pub trophy_cabinet: Account<'info, TrophyCabinet>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        seeds = [b"metadata", Metadata::id().as_ref(), mint.as_ref()],
+        seeds::program = Metadata::id(),
+        bump
+    )]
+    pub metadata: Account<'info, MetadataAccount>,
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ClickerError;
+use crate::events::{EVENT_SCHEMA_VERSION, SessionVoided};
+use crate::state::{Config, Game, LiveState, Session, VoidReason};
+
+/// Support tool for sessions a client bug left stuck. Unlike a cancel, the
+/// session keeps a record that an admin closed it and why.
+pub fn handle_admin_void_session(ctx: Context<AdminVoidSession>, reason: VoidReason) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+    let session: &mut Account<Session> = &mut ctx.accounts.session;
+
+    if session.revealed {
+        msg!("session {} is already closed", session.key());
+        return Err(error!(ClickerError::SessionAlreadyRevealed));
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    if game.active_session() == Some(session.key()) {
+        game.set_active_session(None);
+    }
+
+    // Closed like a cancel, so it can never be revealed afterwards
+    session.revealed = true;
+    session.actual_clicks = 0;
+    session.end_time = current_time;
+    session.void_reason = Some(reason);
+
+    if reason == VoidReason::SuspectedCheating {
+        game.lose_reputation(Game::CHEATING_PENALTY);
+    }
+
+    ctx.accounts.live_state.sync(game, current_time);
+
+    emit_cpi!(SessionVoided {
+        schema_version: EVENT_SCHEMA_VERSION,
+        game: game.key(),
+        session: session.key(),
+        player: session.player,
+        admin: ctx.accounts.admin.key(),
+        reason,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AdminVoidSession<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    #[account(mut, has_one = game)]
+    pub session: Account<'info, Session>,
+    #[account(mut, seeds = [LiveState::SEED, game.key().as_ref()], bump = live_state.bump)]
+    pub live_state: Account<'info, LiveState>,
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ClickerError;
+use crate::state::{ChallengeEntry, ChallengeRules, Config, FeatureGate, Game, WeeklyChallenge};
+
+/// Creates a challenge running for WeeklyChallenge::DURATION from
+/// `starts_at` under `rules`.
+pub fn handle_create_weekly_challenge(
+    ctx: Context<CreateWeeklyChallenge>,
+    challenge_id: u32,
+    starts_at: i64,
+    rules: ChallengeRules
+) -> Result<()> {
+    ctx.accounts.config.require_feature(Config::WEEKLY_CHALLENGES_ENABLED)?;
+
+    let challenge: &mut Account<WeeklyChallenge> = &mut ctx.accounts.challenge;
+    challenge.challenge_id = challenge_id;
+    challenge.starts_at = starts_at;
+    challenge.ends_at = starts_at + WeeklyChallenge::DURATION;
+    challenge.rules = rules;
+    challenge.bump = ctx.bumps.challenge;
+
+    Ok(())
+}
+
+/// Enters a game in a challenge that hasn't ended. Its sessions still opt
+/// in one by one at start.
+pub fn handle_join_weekly_challenge(ctx: Context<JoinWeeklyChallenge>, _challenge_id: u32) -> Result<()> {
+    let game = &ctx.accounts.game;
+    let challenge = &ctx.accounts.challenge;
+
+    ctx.accounts.config.require_feature(Config::WEEKLY_CHALLENGES_ENABLED)?;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    if current_time >= challenge.ends_at {
+        msg!("challenge {} ended at {}, now is {}", challenge.challenge_id, challenge.ends_at, current_time);
+        return Err(error!(ClickerError::ChallengeNotRunning));
+    }
+
+    let entry: &mut Account<ChallengeEntry> = &mut ctx.accounts.entry;
+    entry.challenge = challenge.key();
+    entry.game = game.key();
+    entry.bump = ctx.bumps.entry;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(challenge_id: u32)]
+pub struct CreateWeeklyChallenge<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + WeeklyChallenge::MAXIMUM_SIZE,
+        seeds = [WeeklyChallenge::SEED, &challenge_id.to_le_bytes()],
+        bump
+    )]
+    pub challenge: Account<'info, WeeklyChallenge>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(challenge_id: u32)]
+pub struct JoinWeeklyChallenge<'info> {
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(seeds = [WeeklyChallenge::SEED, &challenge_id.to_le_bytes()], bump = challenge.bump)]
+    pub challenge: Account<'info, WeeklyChallenge>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ChallengeEntry::MAXIMUM_SIZE,
+        seeds = [ChallengeEntry::SEED, challenge.key().as_ref(), game.key().as_ref()],
+        bump
+    )]
+    pub entry: Account<'info, ChallengeEntry>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,298 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::SECONDS_PER_DAY;
+use crate::errors::ClickerError;
+use crate::events::{EVENT_SCHEMA_VERSION, WorldBossSpawned};
+use crate::gateway::require_pass;
+use crate::instructions::grant_items;
+use crate::state::{
+    BossContribution, Config, FeatureGate, FreezeGuard, Game, Inventory, LiveState, RaidSchedule, WorldBoss,
+};
+
+pub fn handle_spawn_world_boss(
+    ctx: Context<SpawnWorldBoss>,
+    boss_id: u32,
+    hp: u64,
+    loot_clicks: u64
+) -> Result<()> {
+    if hp == 0 {
+        msg!("boss {} needs some hp", boss_id);
+        return Err(error!(ClickerError::InvalidWorldBoss));
+    }
+
+    let boss: &mut Account<WorldBoss> = &mut ctx.accounts.boss;
+    boss.spawn(boss_id, hp, loot_clicks, Clock::get()?.unix_timestamp, 0);
+    boss.bump = ctx.bumps.boss;
+
+    emit!(WorldBossSpawned {
+        schema_version: EVENT_SCHEMA_VERSION,
+        boss: boss.key(),
+        boss_id,
+        hp,
+        despawn_at: boss.despawn_at,
+    });
+
+    Ok(())
+}
+
+/// Creates the raid schedule, disabled. Scheduled bosses take ids from
+/// `first_boss_id` up, so pick a range spawn_world_boss won't use.
+pub fn handle_initialize_raid_schedule(ctx: Context<InitializeRaidSchedule>, first_boss_id: u32) -> Result<()> {
+    let schedule: &mut Account<RaidSchedule> = &mut ctx.accounts.schedule;
+    schedule.next_boss_id = first_boss_id;
+    schedule.bump = ctx.bumps.schedule;
+    Ok(())
+}
+
+pub fn handle_set_raid_schedule(
+    ctx: Context<SetRaidSchedule>,
+    spawn_hour: u8,
+    hp: u64,
+    loot_clicks: u64,
+    lifetime: i64,
+    enabled: bool
+) -> Result<()> {
+    if spawn_hour >= 24 || hp == 0 || lifetime < 0 {
+        msg!("raid at {}h with {} hp for {}s is invalid", spawn_hour, hp, lifetime);
+        return Err(error!(ClickerError::InvalidWorldBoss));
+    }
+
+    let schedule: &mut Account<RaidSchedule> = &mut ctx.accounts.schedule;
+    schedule.spawn_hour = spawn_hour;
+    schedule.hp = hp;
+    schedule.loot_clicks = loot_clicks;
+    schedule.lifetime = lifetime;
+    schedule.enabled = enabled;
+
+    Ok(())
+}
+
+/// Permissionless crank: spawns the day's scheduled boss once its hour has
+/// passed. Whoever cranks pays the boss account's rent.
+pub fn handle_spawn_scheduled_boss(ctx: Context<SpawnScheduledBoss>) -> Result<()> {
+    ctx.accounts.config.require_feature(Config::WORLD_BOSS_ENABLED)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let schedule: &mut Account<RaidSchedule> = &mut ctx.accounts.schedule;
+    if !schedule.is_due(now) {
+        msg!(
+            "raid (enabled: {}) spawns after {}h, last spawned on day {}, now is {}",
+            schedule.enabled,
+            schedule.spawn_hour,
+            schedule.last_spawn_day,
+            now
+        );
+        return Err(error!(ClickerError::RaidNotDue));
+    }
+
+    let despawn_at = if schedule.lifetime > 0 { now + schedule.lifetime } else { 0 };
+    let boss: &mut Account<WorldBoss> = &mut ctx.accounts.boss;
+    boss.spawn(schedule.next_boss_id, schedule.hp, schedule.loot_clicks, now, despawn_at);
+    boss.bump = ctx.bumps.boss;
+
+    schedule.last_spawn_day = now.div_euclid(SECONDS_PER_DAY);
+    schedule.next_boss_id += 1;
+
+    emit!(WorldBossSpawned {
+        schema_version: EVENT_SCHEMA_VERSION,
+        boss: boss.key(),
+        boss_id: boss.boss_id,
+        hp: boss.hp,
+        despawn_at,
+    });
+
+    Ok(())
+}
+
+/// Takes a boss out of play. Loot of a boss that was already defeated stays
+/// claimable.
+pub fn handle_despawn_world_boss(ctx: Context<DespawnWorldBoss>) -> Result<()> {
+    ctx.accounts.boss.active = false;
+    Ok(())
+}
+
+/// Signs a game up to fight a boss; from then on its reveals in end_session
+/// damage the boss when the contribution is passed.
+pub fn handle_join_world_boss(ctx: Context<JoinWorldBoss>, _boss_id: u32) -> Result<()> {
+    ctx.accounts.config.require_feature(Config::WORLD_BOSS_ENABLED)?;
+
+    let game = &ctx.accounts.game;
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+
+    let boss = &ctx.accounts.boss;
+    if !boss.is_alive(Clock::get()?.unix_timestamp) {
+        msg!("boss {} is not active (hp {})", boss.boss_id, boss.hp);
+        return Err(error!(ClickerError::BossNotActive));
+    }
+
+    let contribution: &mut Account<BossContribution> = &mut ctx.accounts.contribution;
+    contribution.boss = boss.key();
+    contribution.game = game.key();
+    contribution.bump = ctx.bumps.contribution;
+
+    Ok(())
+}
+
+/// Pays a defeated boss's loot in proportion to the damage the game dealt.
+/// Pause exempt.
+pub fn handle_claim_boss_loot(ctx: Context<ClaimBossLoot>, _boss_id: u32) -> Result<()> {
+    let game: &mut Account<Game> = &mut ctx.accounts.game;
+    let boss = &ctx.accounts.boss;
+    let contribution: &mut Account<BossContribution> = &mut ctx.accounts.contribution;
+
+    if &game.player != ctx.accounts.player.key {
+        msg!("signer {} is not player {}", ctx.accounts.player.key, game.player);
+        return Err(error!(ClickerError::InvalidPlayer));
+    }
+    game.require_unfrozen()?;
+
+    if !boss.is_defeated() {
+        msg!("boss {} still has {} of {} hp", boss.boss_id, boss.hp, boss.max_hp);
+        return Err(error!(ClickerError::BossNotDefeated));
+    }
+
+    if contribution.claimed {
+        msg!("game {} already claimed loot of boss {}", game.key(), boss.boss_id);
+        return Err(error!(ClickerError::LootAlreadyClaimed));
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    require_pass(&ctx.accounts.config, &game.player, ctx.accounts.gateway_token.as_deref(), current_time)?;
+
+    contribution.claimed = true;
+    game.credit_clicks(boss.loot_for(contribution.damage));
+
+    grant_items(
+        ctx.accounts.inventory.as_ref(),
+        Inventory::LOOTBOX,
+        ctx.accounts.config.lootboxes_per_boss,
+        current_time,
+    )?;
+
+    ctx.accounts.live_state.sync(game, current_time);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(boss_id: u32)]
+pub struct SpawnWorldBoss<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + WorldBoss::MAXIMUM_SIZE,
+        seeds = [WorldBoss::SEED, &boss_id.to_le_bytes()],
+        bump
+    )]
+    pub boss: Account<'info, WorldBoss>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DespawnWorldBoss<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [WorldBoss::SEED, &boss.boss_id.to_le_bytes()], bump = boss.bump)]
+    pub boss: Account<'info, WorldBoss>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeRaidSchedule<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + RaidSchedule::MAXIMUM_SIZE,
+        seeds = [RaidSchedule::SEED],
+        bump
+    )]
+    pub schedule: Account<'info, RaidSchedule>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetRaidSchedule<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [RaidSchedule::SEED], bump = schedule.bump)]
+    pub schedule: Account<'info, RaidSchedule>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SpawnScheduledBoss<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [RaidSchedule::SEED], bump = schedule.bump)]
+    pub schedule: Account<'info, RaidSchedule>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + WorldBoss::MAXIMUM_SIZE,
+        seeds = [WorldBoss::SEED, &schedule.next_boss_id.to_le_bytes()],
+        bump
+    )]
+    pub boss: Account<'info, WorldBoss>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(boss_id: u32)]
+pub struct JoinWorldBoss<'info> {
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(seeds = [WorldBoss::SEED, &boss_id.to_le_bytes()], bump = boss.bump)]
+    pub boss: Account<'info, WorldBoss>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + BossContribution::MAXIMUM_SIZE,
+        seeds = [BossContribution::SEED, boss.key().as_ref(), game.key().as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, BossContribution>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(boss_id: u32)]
+pub struct ClaimBossLoot<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(seeds = [WorldBoss::SEED, &boss_id.to_le_bytes()], bump = boss.bump)]
+    pub boss: Account<'info, WorldBoss>,
+    #[account(
+        mut,
+        seeds = [BossContribution::SEED, boss.key().as_ref(), game.key().as_ref()],
+        bump = contribution.bump
+    )]
+    pub contribution: Account<'info, BossContribution>,
+    #[account(mut, seeds = [LiveState::SEED, game.key().as_ref()], bump = live_state.bump)]
+    pub live_state: Account<'info, LiveState>,
+    // Receives Config::lootboxes_per_boss
+    #[account(mut, has_one = game)]
+    pub inventory: Option<AccountLoader<'info, Inventory>>,
+    /// CHECK: parsed and checked by gateway::require_pass. Required while
+    /// Config.gatekeeper_network is set.
+    pub gateway_token: Option<UncheckedAccount<'info>>,
+}
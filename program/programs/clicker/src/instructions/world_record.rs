@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ClickerError;
+use crate::state::{Config, WorldRecord};
+
+pub fn handle_initialize_world_record(ctx: Context<InitializeWorldRecord>) -> Result<()> {
+    ctx.accounts.world_record.bump = ctx.bumps.world_record;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeWorldRecord<'info> {
+    #[account(seeds = [Config::SEED], bump = config.bump, has_one = admin @ ClickerError::Unauthorized)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + WorldRecord::MAXIMUM_SIZE,
+        seeds = [WorldRecord::SEED],
+        bump
+    )]
+    pub world_record: Account<'info, WorldRecord>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,5 +1,25 @@
 use anchor_lang::prelude::*;
-use anchor_lang::error_code;
+
+pub mod commitment;
+pub mod constants;
+pub mod cps_cap;
+pub mod diagnostics;
+pub mod ed25519;
+pub mod errors;
+pub mod events;
+pub mod gateway;
+pub mod instructions;
+pub mod math;
+pub mod modifiers;
+pub mod oracle;
+#[cfg(feature = "no-entrypoint")]
+pub mod pda;
+pub mod reveal_guard;
+pub mod state;
+
+pub use errors::*;
+pub use instructions::*;
+pub use state::*;
 
 declare_id!("GwY9aAMD8nxhZxuTtPBbsFfgiqsVGkRTeA5fRyDjNkdM");
 
@@ -8,58 +28,574 @@ pub mod clicker {
     use super::*;
 
     pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
-        let game: &mut Account<Game> = &mut ctx.accounts.game;
-        let player: &Signer = &ctx.accounts.player;
+        instructions::handle_initialize(ctx)
+    }
 
-        game.player = *player.key;
-        game.clicks = 0;
+    pub fn initialize_hardcore(ctx: Context<Initialize>) -> Result<()> {
+        instructions::handle_initialize_hardcore(ctx)
+    }
 
-        Ok(())
+    pub fn migrate_game(ctx: Context<MigrateGame>) -> Result<()> {
+        instructions::handle_migrate_game(ctx)
     }
-    
+
     pub fn click(ctx: Context<Play>) -> Result<()> {
-        let game: &mut Account<Game> = &mut ctx.accounts.game;
+        instructions::handle_click(ctx)
+    }
 
-        // only allow player to increment their own account
-        if &game.player != ctx.accounts.player.key {
-            return Err(error!(ClickerError::InvalidPlayer));
-        }
+    pub fn start_session(
+        ctx: Context<StartSession>,
+        commitment: [u8; 32],
+        challenge: Option<[u8; 32]>
+    ) -> Result<()> {
+        instructions::handle_start_session(ctx, commitment, challenge)
+    }
 
-        game.clicks += 1;
+    pub fn start_coop_session(
+        ctx: Context<StartCoopSession>,
+        host_commitment: [u8; 32],
+        guest_commitment: [u8; 32]
+    ) -> Result<()> {
+        instructions::handle_start_coop_session(ctx, host_commitment, guest_commitment)
+    }
 
-        Ok(())
+    pub fn start_time_attack(
+        ctx: Context<StartSession>,
+        commitment: [u8; 32],
+        challenge: Option<[u8; 32]>
+    ) -> Result<()> {
+        instructions::handle_start_time_attack(ctx, commitment, challenge)
     }
-}
 
+    pub fn start_endurance(
+        ctx: Context<StartSession>,
+        commitment: [u8; 32],
+        challenge: Option<[u8; 32]>
+    ) -> Result<()> {
+        instructions::handle_start_endurance(ctx, commitment, challenge)
+    }
 
-#[account]
-#[derive(Default)]
-pub struct Game {
-    player: Pubkey,     // 32 bytes
-    clicks: u32,        // 4 bytes
-}
+    pub fn end_session(
+        ctx: Context<EndSession>,
+        result: SessionResult,
+        nonce: u64,
+        max_session_duration: i64
+    ) -> Result<u64> {
+        instructions::handle_end_session(ctx, result, nonce, max_session_duration)
+    }
 
-impl Game {
-    pub const MAXIMUM_SIZE: usize = 32 + 4;
-}
+    pub fn cancel_session(ctx: Context<CancelSession>) -> Result<()> {
+        instructions::handle_cancel_session(ctx)
+    }
 
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(init, payer = player, space = 8 + Game::MAXIMUM_SIZE)]
-    pub game: Account<'info, Game>,
-    #[account(mut)]
-    pub player: Signer<'info>,
-    pub system_program: Program<'info, System>,
-}
+    pub fn extend_session(ctx: Context<ExtendSession>, extra_seconds: i64) -> Result<()> {
+        instructions::handle_extend_session(ctx, extra_seconds)
+    }
 
-#[derive(Accounts)]
-pub struct Play<'info> {
-    #[account(mut)]
-    pub game: Account<'info, Game>,
-    pub player: Signer<'info>,
-}
+    pub fn close_session(ctx: Context<CloseSession>) -> Result<()> {
+        instructions::handle_close_session(ctx)
+    }
+
+    pub fn admin_void_session(ctx: Context<AdminVoidSession>, reason: VoidReason) -> Result<()> {
+        instructions::handle_admin_void_session(ctx, reason)
+    }
+
+    pub fn admin_repair_game(ctx: Context<AdminRepairGame>) -> Result<()> {
+        instructions::handle_admin_repair_game(ctx)
+    }
+
+    pub fn pause_session(ctx: Context<PauseSession>) -> Result<()> {
+        instructions::handle_pause_session(ctx)
+    }
+
+    pub fn resume_session(ctx: Context<PauseSession>) -> Result<()> {
+        instructions::handle_resume_session(ctx)
+    }
+
+    pub fn register_device(ctx: Context<ManageDevice>, device: Pubkey) -> Result<()> {
+        instructions::handle_register_device(ctx, device)
+    }
+
+    pub fn revoke_device(ctx: Context<ManageDevice>, device: Pubkey) -> Result<()> {
+        instructions::handle_revoke_device(ctx, device)
+    }
+
+    pub fn set_guardians(
+        ctx: Context<SetGuardians>,
+        guardians: [Pubkey; 3],
+        threshold: u8
+    ) -> Result<()> {
+        instructions::handle_set_guardians(ctx, guardians, threshold)
+    }
+
+    pub fn recover_game(ctx: Context<RecoverGame>, new_owner: Pubkey) -> Result<()> {
+        instructions::handle_recover_game(ctx, new_owner)
+    }
+
+    pub fn cancel_recovery(ctx: Context<CancelRecovery>) -> Result<()> {
+        instructions::handle_cancel_recovery(ctx)
+    }
+
+    pub fn finalize_recovery(ctx: Context<FinalizeRecovery>) -> Result<()> {
+        instructions::handle_finalize_recovery(ctx)
+    }
+
+    pub fn bind_identity(ctx: Context<ManageIdentity>, hash: [u8; 32]) -> Result<()> {
+        instructions::handle_bind_identity(ctx, hash)
+    }
+
+    pub fn unbind_identity(ctx: Context<ManageIdentity>) -> Result<()> {
+        instructions::handle_unbind_identity(ctx)
+    }
+
+    pub fn soft_reset_game(ctx: Context<SoftResetGame>, confirmation: String) -> Result<()> {
+        instructions::handle_soft_reset_game(ctx, confirmation)
+    }
+
+    pub fn become_mentor(ctx: Context<BecomeMentor>) -> Result<()> {
+        instructions::handle_become_mentor(ctx)
+    }
+
+    pub fn accept_mentee(ctx: Context<AcceptMentee>) -> Result<()> {
+        instructions::handle_accept_mentee(ctx)
+    }
+
+    pub fn claim_mentor_bonus(ctx: Context<ClaimMentorBonus>) -> Result<()> {
+        instructions::handle_claim_mentor_bonus(ctx)
+    }
+
+    pub fn freeze_game(ctx: Context<FreezeGame>, unfreeze_requires_guardian: bool) -> Result<()> {
+        instructions::handle_freeze_game(ctx, unfreeze_requires_guardian)
+    }
+
+    pub fn unfreeze_game(ctx: Context<FreezeGame>) -> Result<()> {
+        instructions::handle_unfreeze_game(ctx)
+    }
+
+    pub fn export_snapshot(ctx: Context<ExportSnapshot>) -> Result<()> {
+        instructions::handle_export_snapshot(ctx)
+    }
+
+    pub fn read_snapshot(ctx: Context<ReadSnapshot>) -> Result<Snapshot> {
+        instructions::handle_read_snapshot(ctx)
+    }
+
+    pub fn add_partner_collection(
+        ctx: Context<AddPartnerCollection>,
+        collection: Pubkey,
+        multiplier_bps: u16
+    ) -> Result<()> {
+        instructions::handle_add_partner_collection(ctx, collection, multiplier_bps)
+    }
+
+    pub fn update_partner_collection(
+        ctx: Context<UpdatePartnerCollection>,
+        multiplier_bps: u16,
+        active: bool
+    ) -> Result<()> {
+        instructions::handle_update_partner_collection(ctx, multiplier_bps, active)
+    }
+
+    pub fn verify_partner_nft(ctx: Context<VerifyPartnerNft>, mint: Pubkey) -> Result<()> {
+        instructions::handle_verify_partner_nft(ctx, mint)
+    }
+
+    pub fn initialize_trophy_cabinet(ctx: Context<InitializeTrophyCabinet>) -> Result<()> {
+        instructions::handle_initialize_trophy_cabinet(ctx)
+    }
+
+    pub fn pin_trophy(ctx: Context<PinTrophy>, slot: u8, mint: Pubkey) -> Result<()> {
+        instructions::handle_pin_trophy(ctx, slot, mint)
+    }
+
+    pub fn unpin_trophy(ctx: Context<UnpinTrophy>, slot: u8) -> Result<()> {
+        instructions::handle_unpin_trophy(ctx, slot)
+    }
+
+    pub fn reverify_trophy(ctx: Context<ReverifyTrophy>, mint: Pubkey) -> Result<()> {
+        instructions::handle_reverify_trophy(ctx, mint)
+    }
+
+    pub fn add_partner_quest(
+        ctx: Context<AddPartnerQuest>,
+        quest_id: u32,
+        condition: QuestCondition,
+        reward_clicks: u64,
+        min_level: u32
+    ) -> Result<()> {
+        instructions::handle_add_partner_quest(ctx, quest_id, condition, reward_clicks, min_level)
+    }
+
+    pub fn set_partner_quest_active(ctx: Context<SetPartnerQuestActive>, active: bool) -> Result<()> {
+        instructions::handle_set_partner_quest_active(ctx, active)
+    }
+
+    pub fn complete_partner_quest(ctx: Context<CompletePartnerQuest>, quest_id: u32) -> Result<()> {
+        instructions::handle_complete_partner_quest(ctx, quest_id)
+    }
+
+    pub fn create_quest_chain(
+        ctx: Context<CreateQuestChain>,
+        chain_id: u32,
+        reward_item_id: u32,
+        reward_quantity: u32
+    ) -> Result<()> {
+        instructions::handle_create_quest_chain(ctx, chain_id, reward_item_id, reward_quantity)
+    }
+
+    pub fn append_chain_quest(ctx: Context<AppendChainQuest>, chain_id: u32, quest_id: u32) -> Result<()> {
+        instructions::handle_append_chain_quest(ctx, chain_id, quest_id)
+    }
+
+    pub fn start_quest_chain(ctx: Context<StartQuestChain>, chain_id: u32) -> Result<()> {
+        instructions::handle_start_quest_chain(ctx, chain_id)
+    }
+
+    pub fn add_offer(
+        ctx: Context<AddOffer>,
+        offer_id: u32,
+        reward: OfferReward,
+        max_redemptions: u32
+    ) -> Result<()> {
+        instructions::handle_add_offer(ctx, offer_id, reward, max_redemptions)
+    }
+
+    pub fn set_offer_active(ctx: Context<SetOfferActive>, active: bool) -> Result<()> {
+        instructions::handle_set_offer_active(ctx, active)
+    }
+
+    pub fn redeem_offer(ctx: Context<RedeemOffer>, offer_id: u32, signature: [u8; 64]) -> Result<()> {
+        instructions::handle_redeem_offer(ctx, offer_id, signature)
+    }
+
+    pub fn initialize_inventory(ctx: Context<InitializeInventory>) -> Result<()> {
+        instructions::handle_initialize_inventory(ctx)
+    }
+
+    pub fn consume_item(ctx: Context<ConsumeItem>, item_id: u32, quantity: u32) -> Result<()> {
+        instructions::handle_consume_item(ctx, item_id, quantity)
+    }
+
+    pub fn set_accepts_transfers(ctx: Context<SetAcceptsTransfers>, accepts: bool) -> Result<()> {
+        instructions::handle_set_accepts_transfers(ctx, accepts)
+    }
+
+    pub fn transfer_item(ctx: Context<TransferItem>, slot: u8, quantity: u32) -> Result<()> {
+        instructions::handle_transfer_item(ctx, slot, quantity)
+    }
+
+    pub fn add_recipe(
+        ctx: Context<AddRecipe>,
+        recipe_id: u32,
+        inputs: [RecipeInput; Recipe::MAX_INPUTS],
+        output_item_id: u32,
+        output_quantity: u32
+    ) -> Result<()> {
+        instructions::handle_add_recipe(ctx, recipe_id, inputs, output_item_id, output_quantity)
+    }
+
+    pub fn set_recipe_active(ctx: Context<SetRecipeActive>, active: bool) -> Result<()> {
+        instructions::handle_set_recipe_active(ctx, active)
+    }
+
+    pub fn craft(ctx: Context<Craft>, recipe_id: u32) -> Result<()> {
+        instructions::handle_craft(ctx, recipe_id)
+    }
+
+    pub fn list_item(ctx: Context<ListItem>, slot: u8, quantity: u32, price: u64) -> Result<()> {
+        instructions::handle_list_item(ctx, slot, quantity, price)
+    }
+
+    pub fn buy_listing(ctx: Context<BuyListing>) -> Result<()> {
+        instructions::handle_buy_listing(ctx)
+    }
+
+    pub fn cancel_listing(ctx: Context<CancelListing>) -> Result<()> {
+        instructions::handle_cancel_listing(ctx)
+    }
+
+    pub fn open_lootbox(ctx: Context<OpenLootbox>) -> Result<()> {
+        instructions::handle_open_lootbox(ctx)
+    }
+
+    pub fn settle_lootbox(ctx: Context<SettleLootbox>) -> Result<()> {
+        instructions::handle_settle_lootbox(ctx)
+    }
+
+    pub fn gamble_session(ctx: Context<GambleSession>) -> Result<()> {
+        instructions::handle_gamble_session(ctx)
+    }
+
+    pub fn settle_gamble(ctx: Context<SettleGamble>) -> Result<()> {
+        instructions::handle_settle_gamble(ctx)
+    }
+
+    pub fn offer_gift(ctx: Context<OfferGift>, amount: u64, expires_at: i64) -> Result<()> {
+        instructions::handle_offer_gift(ctx, amount, expires_at)
+    }
+
+    pub fn accept_gift(ctx: Context<AcceptGift>) -> Result<()> {
+        instructions::handle_accept_gift(ctx)
+    }
+
+    pub fn reclaim_expired_gift(ctx: Context<ReclaimExpiredGift>) -> Result<()> {
+        instructions::handle_reclaim_expired_gift(ctx)
+    }
+
+    pub fn initialize_skill_tree(ctx: Context<InitializeSkillTree>) -> Result<()> {
+        instructions::handle_initialize_skill_tree(ctx)
+    }
+
+    pub fn set_skill_node(
+        ctx: Context<SetSkillNode>,
+        node_id: u8,
+        prerequisites: u64,
+        cost: u8,
+        effect: SkillEffect,
+        magnitude_bps: u16
+    ) -> Result<()> {
+        instructions::handle_set_skill_node(ctx, node_id, prerequisites, cost, effect, magnitude_bps)
+    }
+
+    pub fn unlock_skill(ctx: Context<UnlockSkill>, node_id: u8) -> Result<()> {
+        instructions::handle_unlock_skill(ctx, node_id)
+    }
+
+    pub fn respec_skills(ctx: Context<RespecSkills>) -> Result<()> {
+        instructions::handle_respec_skills(ctx)
+    }
+
+    pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+        instructions::handle_initialize_treasury(ctx)
+    }
+
+    pub fn buy_gems(ctx: Context<BuyGems>, gems: u64) -> Result<()> {
+        instructions::handle_buy_gems(ctx, gems)
+    }
+
+    pub fn spend_gems(ctx: Context<SpendGems>, purchase: GemPurchase) -> Result<()> {
+        instructions::handle_spend_gems(ctx, purchase)
+    }
+
+    pub fn refund_purchase(ctx: Context<RefundPurchase>) -> Result<()> {
+        instructions::handle_refund_purchase(ctx)
+    }
+
+    pub fn create_pass_season(
+        ctx: Context<CreatePassSeason>,
+        season_id: u32,
+        starts_at: i64,
+        ends_at: i64,
+        premium_gems: u64,
+        premium_lamports: u64,
+        tiers: [PassTier; PassSeason::MAX_TIERS]
+    ) -> Result<()> {
+        instructions::handle_create_pass_season(
+            ctx,
+            season_id,
+            starts_at,
+            ends_at,
+            premium_gems,
+            premium_lamports,
+            tiers,
+        )
+    }
+
+    pub fn set_season_modifiers(
+        ctx: Context<SetSeasonModifiers>,
+        season_id: u32,
+        modifiers: SeasonModifiers
+    ) -> Result<()> {
+        instructions::handle_set_season_modifiers(ctx, season_id, modifiers)
+    }
+
+    pub fn join_season_pass(ctx: Context<JoinSeasonPass>, season_id: u32) -> Result<()> {
+        instructions::handle_join_season_pass(ctx, season_id)
+    }
+
+    pub fn buy_premium_pass(ctx: Context<BuyPremiumPass>, season_id: u32, payment: PassPayment) -> Result<()> {
+        instructions::handle_buy_premium_pass(ctx, season_id, payment)
+    }
+
+    pub fn claim_pass_reward(ctx: Context<ClaimPassReward>, season_id: u32, tier: u8) -> Result<()> {
+        instructions::handle_claim_pass_reward(ctx, season_id, tier)
+    }
+
+    pub fn create_shoutbox(
+        ctx: Context<CreateShoutbox>,
+        season_id: u32,
+        fee_clicks: u64,
+        fee_gems: u64
+    ) -> Result<()> {
+        instructions::handle_create_shoutbox(ctx, season_id, fee_clicks, fee_gems)
+    }
+
+    pub fn post_message(ctx: Context<PostMessage>, season_id: u32, text: String, payment: PostPayment) -> Result<()> {
+        instructions::handle_post_message(ctx, season_id, text, payment)
+    }
+
+    pub fn initialize_session_archive(ctx: Context<InitializeSessionArchive>) -> Result<()> {
+        instructions::handle_initialize_session_archive(ctx)
+    }
+
+    pub fn verify_archived_session(
+        ctx: Context<VerifyArchivedSession>,
+        record: ArchivedSession,
+        index: u64,
+        proof: Vec<[u8; 32]>
+    ) -> Result<()> {
+        instructions::handle_verify_archived_session(ctx, record, index, proof)
+    }
+
+    pub fn spawn_world_boss(
+        ctx: Context<SpawnWorldBoss>,
+        boss_id: u32,
+        hp: u64,
+        loot_clicks: u64
+    ) -> Result<()> {
+        instructions::handle_spawn_world_boss(ctx, boss_id, hp, loot_clicks)
+    }
 
-#[error_code]
-pub enum ClickerError {
-    InvalidPlayer,
-}
\ No newline at end of file
+    pub fn despawn_world_boss(ctx: Context<DespawnWorldBoss>) -> Result<()> {
+        instructions::handle_despawn_world_boss(ctx)
+    }
+
+    pub fn initialize_raid_schedule(ctx: Context<InitializeRaidSchedule>, first_boss_id: u32) -> Result<()> {
+        instructions::handle_initialize_raid_schedule(ctx, first_boss_id)
+    }
+
+    pub fn set_raid_schedule(
+        ctx: Context<SetRaidSchedule>,
+        spawn_hour: u8,
+        hp: u64,
+        loot_clicks: u64,
+        lifetime: i64,
+        enabled: bool
+    ) -> Result<()> {
+        instructions::handle_set_raid_schedule(ctx, spawn_hour, hp, loot_clicks, lifetime, enabled)
+    }
+
+    pub fn spawn_scheduled_boss(ctx: Context<SpawnScheduledBoss>) -> Result<()> {
+        instructions::handle_spawn_scheduled_boss(ctx)
+    }
+
+    pub fn join_world_boss(ctx: Context<JoinWorldBoss>, boss_id: u32) -> Result<()> {
+        instructions::handle_join_world_boss(ctx, boss_id)
+    }
+
+    pub fn claim_boss_loot(ctx: Context<ClaimBossLoot>, boss_id: u32) -> Result<()> {
+        instructions::handle_claim_boss_loot(ctx, boss_id)
+    }
+
+    pub fn schedule_golden_window(
+        ctx: Context<ScheduleGoldenWindow>,
+        window_id: u32,
+        start_time: i64,
+        end_time: i64,
+        multiplier_bps: u16
+    ) -> Result<()> {
+        instructions::handle_schedule_golden_window(ctx, window_id, start_time, end_time, multiplier_bps)
+    }
+
+    pub fn close_golden_window(ctx: Context<CloseGoldenWindow>, window_id: u32) -> Result<()> {
+        instructions::handle_close_golden_window(ctx, window_id)
+    }
+
+    pub fn create_weekly_challenge(
+        ctx: Context<CreateWeeklyChallenge>,
+        challenge_id: u32,
+        starts_at: i64,
+        rules: ChallengeRules
+    ) -> Result<()> {
+        instructions::handle_create_weekly_challenge(ctx, challenge_id, starts_at, rules)
+    }
+
+    pub fn join_weekly_challenge(ctx: Context<JoinWeeklyChallenge>, challenge_id: u32) -> Result<()> {
+        instructions::handle_join_weekly_challenge(ctx, challenge_id)
+    }
+
+    pub fn initialize_world_record(ctx: Context<InitializeWorldRecord>) -> Result<()> {
+        instructions::handle_initialize_world_record(ctx)
+    }
+
+    pub fn initialize_live_state(ctx: Context<InitializeLiveState>) -> Result<()> {
+        instructions::handle_initialize_live_state(ctx)
+    }
+
+    pub fn initialize_session_index(ctx: Context<InitializeSessionIndex>) -> Result<()> {
+        instructions::handle_initialize_session_index(ctx)
+    }
+
+    pub fn initialize_attestation(ctx: Context<InitializeAttestation>) -> Result<()> {
+        instructions::handle_initialize_attestation(ctx)
+    }
+
+    pub fn attest_total_clicks(ctx: Context<AttestTotalClicks>) -> Result<ClicksAttestation> {
+        instructions::handle_attest_total_clicks(ctx)
+    }
+
+    pub fn create_lookup_table(ctx: Context<CreateLookupTable>, recent_slot: u64) -> Result<()> {
+        instructions::handle_create_lookup_table(ctx, recent_slot)
+    }
+
+    pub fn extend_lookup_table(ctx: Context<ExtendLookupTable>, addresses: Vec<Pubkey>) -> Result<()> {
+        instructions::handle_extend_lookup_table(ctx, addresses)
+    }
+
+    pub fn initialize_config(ctx: Context<InitializeConfig>, vrf_oracle: Pubkey, cluster: u8) -> Result<()> {
+        instructions::handle_initialize_config(ctx, vrf_oracle, cluster)
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, update: ConfigUpdate) -> Result<()> {
+        instructions::handle_update_config(ctx, update)
+    }
+
+    #[cfg(feature = "local-testing")]
+    pub fn initialize_for_test(ctx: Context<InitializeForTest>, features: u64) -> Result<()> {
+        instructions::handle_initialize_for_test(ctx, features)
+    }
+
+    pub fn initialize_admin_record(ctx: Context<InitializeAdminRecord>) -> Result<()> {
+        instructions::handle_initialize_admin_record(ctx)
+    }
+
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        instructions::handle_propose_admin(ctx, new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::handle_accept_admin(ctx)
+    }
+
+    pub fn propose_upgrade_authority(ctx: Context<ProposeUpgradeAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::handle_propose_upgrade_authority(ctx, new_authority)
+    }
+
+    pub fn accept_upgrade_authority(ctx: Context<AcceptUpgradeAuthority>) -> Result<()> {
+        instructions::handle_accept_upgrade_authority(ctx)
+    }
+
+    pub fn initialize_global_stats(ctx: Context<InitializeGlobalStats>) -> Result<()> {
+        instructions::handle_initialize_global_stats(ctx)
+    }
+
+    pub fn adjust_difficulty(ctx: Context<AdjustDifficulty>) -> Result<()> {
+        instructions::handle_adjust_difficulty(ctx)
+    }
+
+    pub fn open_raffle(ctx: Context<OpenRaffle>, period: u64) -> Result<()> {
+        instructions::handle_open_raffle(ctx, period)
+    }
+
+    pub fn fund_raffle(ctx: Context<FundRaffle>, amount: u64) -> Result<()> {
+        instructions::handle_fund_raffle(ctx, amount)
+    }
+
+    pub fn close_raffle(ctx: Context<CloseRaffle>) -> Result<()> {
+        instructions::handle_close_raffle(ctx)
+    }
+
+    pub fn draw_raffle(ctx: Context<DrawRaffle>) -> Result<()> {
+        instructions::handle_draw_raffle(ctx)
+    }
+}
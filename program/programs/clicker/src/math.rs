@@ -0,0 +1 @@
+pub use clicker_types::math::*;
@@ -0,0 +1,37 @@
+//! Seasonal rule changes.
+//!
+//! The season Config.modifier_season points at changes some of the game's
+//! numbers while it runs. Handlers never read its SeasonModifiers
+//! themselves: they ask apply_modifiers for the factor on the value they are
+//! about to use, so a new seasonal twist is a field and a match arm here
+//! rather than an edit to every handler. Hardcore games play by fixed rules
+//! and are never modified.
+
+use crate::math::Fixed;
+use crate::state::{Game, PassSeason};
+
+/// A value a season can change.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SeasonStat {
+    Clicks,
+    CpsCap,
+    Xp,
+}
+
+/// Factor the running season applies to `stat` for `game`, 1 when no
+/// season is passed, it isn't running at `now`, or it leaves `stat` alone.
+pub fn apply_modifiers(season: Option<&PassSeason>, game: &Game, now: i64, stat: SeasonStat) -> Fixed {
+    let Some(season) = season.filter(|season| season.is_running(now) && !game.is_hardcore()) else {
+        return Fixed::ONE;
+    };
+
+    let bps = match stat {
+        SeasonStat::Clicks => season.modifiers.clicks_bps,
+        SeasonStat::CpsCap => season.modifiers.cps_cap_bps,
+        SeasonStat::Xp => season.modifiers.xp_bps,
+    };
+    match bps {
+        0 => Fixed::ONE,
+        bps => Fixed::from_bps(bps),
+    }
+}
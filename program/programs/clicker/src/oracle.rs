@@ -0,0 +1,31 @@
+//! Randomness from the configured oracle, which is trusted.
+//!
+//! The oracle signs a seed chosen by the program (e.g. the raffle address)
+//! with its Ed25519 key, and the hash of that signature is the random value.
+//! This is not a VRF: Ed25519 verification accepts any nonce, so the oracle
+//! can produce many valid signatures for one seed and submit the draw it
+//! likes. Anyone can check a draw came from the oracle, not that it was the
+//! only one it could have made. Draws are only as fair as the oracle key's
+//! holder; the seeds merely keep players from previewing them.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::ed25519::verify_preceding_signature;
+
+// Unchanged from when this was billed as a VRF, so existing oracles keep working
+const DOMAIN: &[u8] = b"clicker-vrf";
+
+/// Verifies the oracle's signature over `seed` and returns the random value it yields.
+pub fn oracle_randomness(
+    instructions_sysvar: &AccountInfo,
+    oracle: &Pubkey,
+    seed: &[u8],
+) -> Result<u64> {
+    let signature = verify_preceding_signature(instructions_sysvar, oracle, seed)?;
+    let digest = hashv(&[DOMAIN, &signature]).to_bytes();
+
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&digest[..8]);
+    Ok(u64::from_le_bytes(bytes))
+}
@@ -0,0 +1,198 @@
+//! Addresses of the program's PDAs, for bots and SDKs building on the
+//! `no-entrypoint` crate rather than copying seeds by hand. Seed prefixes
+//! themselves are in [`seeds`].
+//!
+//! Games and sessions are keypair accounts, so they have no address to
+//! derive; a game's SessionIndex lists its sessions by number.
+
+use anchor_lang::prelude::{ProgramData, Pubkey};
+use anchor_lang::{Id, Owner};
+use anchor_spl::metadata::Metadata;
+use solana_address_lookup_table_interface::instruction::derive_lookup_table_address;
+
+use crate::state::{
+    Attestation, BossContribution, ChallengeEntry, Config, CoopSession, Gift, GlobalStats,
+    GoldenWindow, Inventory, Listing, LiveState, Mentorship, Offer, OfferRedemption,
+    PartnerCollection, PartnerNft, PartnerQuest, PassSeason, ProgramAdminRecord, QuestChain,
+    QuestChainProgress, QuestCompletion, Raffle, RaidSchedule, Receipt, Recipe, SeasonPass,
+    SessionArchive, SessionIndex, Shoutbox, SkillTree, Snapshot, Treasury, TrophyCabinet,
+    WeeklyChallenge, WorldBoss, WorldRecord,
+};
+
+pub use clicker_types::seeds;
+
+fn find(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &crate::ID).0
+}
+
+pub fn config() -> Pubkey {
+    find(&[Config::SEED])
+}
+
+pub fn global_stats() -> Pubkey {
+    find(&[GlobalStats::SEED])
+}
+
+pub fn raffle(period: u64) -> Pubkey {
+    find(&[Raffle::SEED, &period.to_le_bytes()])
+}
+
+pub fn snapshot(game: &Pubkey) -> Pubkey {
+    find(&[Snapshot::SEED, game.as_ref()])
+}
+
+pub fn partner_collection(collection: &Pubkey) -> Pubkey {
+    find(&[PartnerCollection::SEED, collection.as_ref()])
+}
+
+pub fn partner_nft(mint: &Pubkey) -> Pubkey {
+    find(&[PartnerNft::SEED, mint.as_ref()])
+}
+
+pub fn partner_quest(quest_id: u32) -> Pubkey {
+    find(&[PartnerQuest::SEED, &quest_id.to_le_bytes()])
+}
+
+pub fn quest_completion(quest: &Pubkey, game: &Pubkey) -> Pubkey {
+    find(&[QuestCompletion::SEED, quest.as_ref(), game.as_ref()])
+}
+
+pub fn quest_chain(chain_id: u32) -> Pubkey {
+    find(&[QuestChain::SEED, &chain_id.to_le_bytes()])
+}
+
+pub fn quest_chain_progress(chain: &Pubkey, game: &Pubkey) -> Pubkey {
+    find(&[QuestChainProgress::SEED, chain.as_ref(), game.as_ref()])
+}
+
+pub fn offer(offer_id: u32) -> Pubkey {
+    find(&[Offer::SEED, &offer_id.to_le_bytes()])
+}
+
+pub fn offer_redemption(offer: &Pubkey, game: &Pubkey) -> Pubkey {
+    find(&[OfferRedemption::SEED, offer.as_ref(), game.as_ref()])
+}
+
+pub fn skill_tree() -> Pubkey {
+    find(&[SkillTree::SEED])
+}
+
+pub fn treasury() -> Pubkey {
+    find(&[Treasury::SEED])
+}
+
+/// `index` is the game's `purchase_count` at the time of the purchase.
+pub fn receipt(game: &Pubkey, index: u64) -> Pubkey {
+    find(&[Receipt::SEED, game.as_ref(), &index.to_le_bytes()])
+}
+
+pub fn pass_season(season_id: u32) -> Pubkey {
+    find(&[PassSeason::SEED, &season_id.to_le_bytes()])
+}
+
+pub fn season_pass(season: &Pubkey, game: &Pubkey) -> Pubkey {
+    find(&[SeasonPass::SEED, season.as_ref(), game.as_ref()])
+}
+
+pub fn session_archive() -> Pubkey {
+    find(&[SessionArchive::SEED])
+}
+
+pub fn world_record() -> Pubkey {
+    find(&[WorldRecord::SEED])
+}
+
+pub fn mentorship(mentee_game: &Pubkey) -> Pubkey {
+    find(&[Mentorship::SEED, mentee_game.as_ref()])
+}
+
+pub fn world_boss(boss_id: u32) -> Pubkey {
+    find(&[WorldBoss::SEED, &boss_id.to_le_bytes()])
+}
+
+pub fn boss_contribution(boss: &Pubkey, game: &Pubkey) -> Pubkey {
+    find(&[BossContribution::SEED, boss.as_ref(), game.as_ref()])
+}
+
+pub fn raid_schedule() -> Pubkey {
+    find(&[RaidSchedule::SEED])
+}
+
+pub fn golden_window(window_id: u32) -> Pubkey {
+    find(&[GoldenWindow::SEED, &window_id.to_le_bytes()])
+}
+
+pub fn trophy_cabinet(game: &Pubkey) -> Pubkey {
+    find(&[TrophyCabinet::SEED, game.as_ref()])
+}
+
+pub fn weekly_challenge(challenge_id: u32) -> Pubkey {
+    find(&[WeeklyChallenge::SEED, &challenge_id.to_le_bytes()])
+}
+
+pub fn challenge_entry(challenge: &Pubkey, game: &Pubkey) -> Pubkey {
+    find(&[ChallengeEntry::SEED, challenge.as_ref(), game.as_ref()])
+}
+
+pub fn live_state(game: &Pubkey) -> Pubkey {
+    find(&[LiveState::SEED, game.as_ref()])
+}
+
+pub fn attestation(game: &Pubkey) -> Pubkey {
+    find(&[Attestation::SEED, game.as_ref()])
+}
+
+pub fn inventory(game: &Pubkey) -> Pubkey {
+    find(&[Inventory::SEED, game.as_ref()])
+}
+
+pub fn coop_session(host_session: &Pubkey) -> Pubkey {
+    find(&[CoopSession::SEED, host_session.as_ref()])
+}
+
+pub fn session_index(game: &Pubkey) -> Pubkey {
+    find(&[SessionIndex::SEED, game.as_ref()])
+}
+
+pub fn shoutbox(season: &Pubkey) -> Pubkey {
+    find(&[Shoutbox::SEED, season.as_ref()])
+}
+
+pub fn admin_record() -> Pubkey {
+    find(&[ProgramAdminRecord::SEED])
+}
+
+pub fn gift(sender: &Pubkey, recipient: &Pubkey) -> Pubkey {
+    find(&[Gift::SEED, sender.as_ref(), recipient.as_ref()])
+}
+
+pub fn listing(game: &Pubkey, index: u64) -> Pubkey {
+    find(&[Listing::SEED, game.as_ref(), &index.to_le_bytes()])
+}
+
+pub fn recipe(recipe_id: u32) -> Pubkey {
+    find(&[Recipe::SEED, &recipe_id.to_le_bytes()])
+}
+
+/// The lookup table create_lookup_table makes for `recent_slot`.
+pub fn lookup_table(recent_slot: u64) -> Pubkey {
+    derive_lookup_table_address(&config(), recent_slot).0
+}
+
+/// Signs the self-CPI that records emit_cpi! events.
+pub fn event_authority() -> Pubkey {
+    find(&[b"__event_authority"])
+}
+
+pub fn program_data() -> Pubkey {
+    Pubkey::find_program_address(&[crate::ID.as_ref()], &ProgramData::owner()).0
+}
+
+/// Metaplex metadata account for `mint`.
+pub fn metadata(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"metadata", Metadata::id().as_ref(), mint.as_ref()],
+        &Metadata::id(),
+    )
+    .0
+}
@@ -0,0 +1,51 @@
+//! Transaction introspection for reveals that carry weight.
+//!
+//! An attested session's reveal must be co-signed by the attestor: the
+//! Ed25519 instruction right before end_session verifies its signature over
+//! (session address || revealed hash). Attested and high-stakes reveals also
+//! have to travel in a transaction made only of this program's instructions
+//! and the signature and compute budget programs. That rules out wrapping
+//! end_session in another program's CPI, or surrounding it with programs
+//! that could act on the reveal mid-transaction.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_instruction_at_checked;
+use anchor_lang::solana_program::{ed25519_program, pubkey};
+
+use crate::ed25519::verify_preceding_signature;
+use crate::errors::ClickerError;
+
+const COMPUTE_BUDGET_PROGRAM_ID: Pubkey = pubkey!("ComputeBudget111111111111111111111111111111");
+
+/// Checks the attestor co-signed `revealed_hash` for `session`.
+pub fn verify_attested_reveal(
+    instructions_sysvar: &AccountInfo,
+    attestor: &Pubkey,
+    session: &Pubkey,
+    revealed_hash: &[u8; 32],
+) -> Result<()> {
+    let mut message = [0u8; 64];
+    message[..32].copy_from_slice(session.as_ref());
+    message[32..].copy_from_slice(revealed_hash);
+    verify_preceding_signature(instructions_sysvar, attestor, &message)?;
+    Ok(())
+}
+
+/// Checks every top-level instruction in the transaction is for this
+/// program, the Ed25519 program or the compute budget program. A CPI into
+/// end_session shows up as its caller's top-level instruction, so it fails
+/// here too.
+pub fn verify_composition(instructions_sysvar: &AccountInfo) -> Result<()> {
+    let mut index = 0;
+    while let Ok(ix) = load_instruction_at_checked(index, instructions_sysvar) {
+        if ix.program_id != crate::ID
+            && ix.program_id != ed25519_program::ID
+            && ix.program_id != COMPUTE_BUDGET_PROGRAM_ID
+        {
+            msg!("instruction {} is for program {}, not allowed alongside this reveal", index, ix.program_id);
+            return Err(error!(ClickerError::UnexpectedInstruction));
+        }
+        index += 1;
+    }
+    Ok(())
+}
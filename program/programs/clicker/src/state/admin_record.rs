@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use clicker_types::seeds;
+
+/// A role that changes hands through ProgramAdminRecord.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AdminRole {
+    /// Config.admin
+    ConfigAdmin,
+    /// The program's upgrade authority on the BPF loader
+    UpgradeAuthority,
+}
+
+/// Who controls the game, in one account anyone can read. Both roles change
+/// in two steps: the holder proposes a successor, who accepts by signing,
+/// and each step emits an event, so the history is on-chain too.
+#[account]
+#[derive(Default)]
+pub struct ProgramAdminRecord {
+    pub admin: Pubkey,                      // 32 bytes - mirrors Config.admin
+    pub pending_admin: Pubkey,              // 32 bytes - proposed successor, default when none
+    pub upgrade_authority: Pubkey,          // 32 bytes - last acknowledged, default if the program is immutable
+    pub pending_upgrade_authority: Pubkey,  // 32 bytes - proposed successor, default when none
+    pub handovers: u64,                     // 8 bytes - accepted handovers of either role
+    pub updated_at: i64,                    // 8 bytes
+    pub bump: u8,                           // 1 byte
+}
+
+impl ProgramAdminRecord {
+    pub const SEED: &'static [u8] = seeds::ADMIN_RECORD;
+    pub const MAXIMUM_SIZE: usize = 32 + 32 + 32 + 32 + 8 + 8 + 1;
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use clicker_types::seeds;
+
+/// A game's verified click total as of `slot`, returned by
+/// attest_total_clicks for protocols that CPI into it. The game's creation
+/// and first session slots come along as a signal of its age that the
+/// player can't backdate; 0 means the game predates them or hasn't played.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ClicksAttestation {
+    pub game: Pubkey,
+    pub player: Pubkey,
+    pub total_clicks: u64,
+    pub slot: u64,
+    pub created_at_slot: u64,
+    pub first_session_slot: u64,
+}
+
+/// The latest attestation of a game, for protocols that read accounts
+/// instead of CPIing. Only this program can write it.
+#[account]
+#[derive(Default)]
+pub struct Attestation {
+    pub game: Pubkey,           // 32 bytes
+    pub player: Pubkey,         // 32 bytes
+    pub total_clicks: u64,      // 8 bytes
+    pub slot: u64,              // 8 bytes
+    pub bump: u8,               // 1 byte
+}
+
+impl Attestation {
+    pub const SEED: &'static [u8] = seeds::ATTESTATION;
+    pub const MAXIMUM_SIZE: usize = 32 + 32 + 8 + 8 + 1;
+
+    pub fn record(&mut self, attestation: &ClicksAttestation) {
+        self.player = attestation.player;
+        self.total_clicks = attestation.total_clicks;
+        self.slot = attestation.slot;
+    }
+}
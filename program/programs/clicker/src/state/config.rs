@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+pub use clicker_types::{Config, HappyHour, LootboxReward, ReputationBand};
+
+use crate::errors::ClickerError;
+
+/// `Config::require_feature`, kept here because the layout lives in
+/// clicker-types, which knows nothing of the program's errors.
+///
+/// Clearing feature bits is how the admin pauses parts of the game, so a
+/// pause must not stop players taking back what they already own. Handlers
+/// doing that never call this and are marked "Pause exempt" in their docs:
+/// cancel_session, close_session, refund_purchase, cancel_listing,
+/// claim_pass_reward, claim_boss_loot, claim_mentor_bonus, settle_gamble,
+/// settle_lootbox and reclaim_expired_gift.
+pub trait FeatureGate {
+    fn require_feature(&self, feature: u64) -> Result<()>;
+}
+
+impl FeatureGate for Config {
+    fn require_feature(&self, feature: u64) -> Result<()> {
+        if !self.has_feature(feature) {
+            if self.features & feature == feature {
+                msg!("features {:#x} pay real tokens, off on cluster {}", feature & Self::REAL_VALUE_FEATURES, self.cluster);
+            } else {
+                msg!("features {:#x} are off, enabled: {:#x}", feature & !self.features, self.features);
+            }
+            return Err(error!(ClickerError::FeatureDisabled));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use clicker_types::seeds;
+
+/// Links the two sessions of a co-op run. Each player reveals their own
+/// session and is credited their own clicks; the pair shares one CPS cap.
+#[account]
+#[derive(Default)]
+pub struct CoopSession {
+    pub games: [Pubkey; 2],         // 2 * 32 bytes - host first
+    pub sessions: [Pubkey; 2],      // 2 * 32 bytes - in the order of games
+    pub clicks: [u32; 2],           // 2 * 4 bytes - revealed so far
+    pub bump: u8,                   // 1 byte
+}
+
+impl CoopSession {
+    pub const SEED: &'static [u8] = seeds::COOP_SESSION;
+    pub const MAXIMUM_SIZE: usize = 2 * 32 + 2 * 32 + 2 * 4 + 1;
+
+    /// Index of `session` in the pair.
+    pub fn position(&self, session: &Pubkey) -> Option<usize> {
+        self.sessions.iter().position(|s| s == session)
+    }
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+pub use clicker_types::{GambleState, Game, PersonalRecord, Session, SessionResult, VoidReason};
+
+use crate::errors::ClickerError;
+
+/// `Game::require_unfrozen`, kept here for the same reason as FeatureGate.
+///
+/// A frozen game's key is presumed stolen, so anything that moves value out
+/// of it (items, clicks, gems, listings, refunds) or claims rewards into it
+/// calls this before touching state.
+pub trait FreezeGuard {
+    fn require_unfrozen(&self) -> Result<()>;
+}
+
+impl FreezeGuard for Account<'_, Game> {
+    fn require_unfrozen(&self) -> Result<()> {
+        if self.frozen {
+            msg!("game {} is frozen", self.key());
+            return Err(error!(ClickerError::GameFrozen));
+        }
+        Ok(())
+    }
+}
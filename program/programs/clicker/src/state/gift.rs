@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+use clicker_types::seeds;
+
+/// Clicks on their way from one game to another, held here until the
+/// recipient accepts them or, once expired, they go back to the sender.
+/// Closed either way, refunding rent to whoever paid it.
+#[account]
+#[derive(Default)]
+pub struct Gift {
+    pub sender: Pubkey,         // 32 bytes - the giving game
+    pub recipient: Pubkey,      // 32 bytes - the game that may accept
+    pub amount: u64,            // 8 bytes - taken from the sender's spendable_clicks
+    pub expires_at: i64,        // 8 bytes - accepting stops here, reclaiming starts
+    pub rent_payer: Pubkey,     // 32 bytes
+    pub bump: u8,               // 1 byte
+}
+
+impl Gift {
+    pub const SEED: &'static [u8] = seeds::GIFT;
+    pub const MAX_EXPIRY: i64 = 7 * 24 * 60 * 60;
+    pub const MAXIMUM_SIZE: usize = 32 + 32 + 8 + 8 + 32 + 1;
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use clicker_types::seeds;
+
+/// Worldwide totals and the current difficulty, shared by every game.
+///
+/// Stat accounts are zero-copy: every reveal writes this one, and larger
+/// stats/history accounts should follow the same layout rules (repr(C),
+/// fixed arrays, explicit padding) so they are read in place instead of
+/// going through Borsh.
+#[account(zero_copy)]
+#[derive(Default)]
+pub struct GlobalStats {
+    pub total_clicks: u64,      // 8 bytes
+    pub epoch_start: i64,       // 8 bytes
+    pub epoch_clicks: u64,      // 8 bytes - clicks revealed since epoch_start
+    pub difficulty: u64,        // 8 bytes - DIFFICULTY_ONE is the base CPS cap
+    pub bump: u8,               // 1 byte
+    pub _padding: [u8; 7],      // 7 bytes
+}
+
+impl GlobalStats {
+    pub const SEED: &'static [u8] = seeds::GLOBAL_STATS;
+    pub const MAXIMUM_SIZE: usize = 8 + 8 + 8 + 8 + 1 + 7;
+
+    pub const EPOCH_SECONDS: i64 = 24 * 60 * 60;
+    // when Config.base_cps is unset
+    pub const BASE_CPS: u64 = 10;
+    pub const DIFFICULTY_ONE: u64 = 10_000;
+    // like Bitcoin, a single retarget can move difficulty by at most 4x
+    pub const MAX_ADJUSTMENT_FACTOR: u64 = 4;
+
+    /// Difficulty for the next epoch, scaled by how far the clicks since the
+    /// last retarget overshot `target_epoch_clicks` for each of the
+    /// `elapsed_epochs` they span. Never drops below the base difficulty.
+    pub fn retarget(&self, target_epoch_clicks: u64, elapsed_epochs: u64) -> u64 {
+        let target = target_epoch_clicks as u128 * elapsed_epochs.max(1) as u128;
+        if target == 0 {
+            return self.difficulty;
+        }
+
+        let proposed = (self.difficulty as u128 * self.epoch_clicks as u128 / target) as u64;
+        let lower = self.difficulty / Self::MAX_ADJUSTMENT_FACTOR;
+        let upper = self.difficulty.saturating_mul(Self::MAX_ADJUSTMENT_FACTOR);
+
+        proposed.clamp(lower, upper).max(Self::DIFFICULTY_ONE)
+    }
+}
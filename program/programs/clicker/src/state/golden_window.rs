@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use clicker_types::constants::BPS_ONE;
+use clicker_types::math::Fixed;
+use clicker_types::seeds;
+
+/// A one-off stretch of time in which revealed clicks earn a bonus, for
+/// surprise events. Unlike happy hours it isn't repeated daily.
+#[account]
+#[derive(Default)]
+pub struct GoldenWindow {
+    pub window_id: u32,         // 4 bytes
+    pub start_time: i64,        // 8 bytes
+    pub end_time: i64,          // 8 bytes
+    pub multiplier_bps: u16,    // 2 bytes - at least BPS_ONE
+    pub scheduled_by: Pubkey,   // 32 bytes - paid the rent, refunded by close_golden_window
+    pub bump: u8,               // 1 byte
+}
+
+impl GoldenWindow {
+    pub const SEED: &'static [u8] = seeds::GOLDEN_WINDOW;
+    pub const MAXIMUM_SIZE: usize = 4 + 8 + 8 + 2 + 32 + 1;
+
+    /// Seconds of `[start, end)` that fall inside the window.
+    pub fn overlap(&self, start: i64, end: i64) -> i64 {
+        (end.min(self.end_time) - start.max(self.start_time)).max(0)
+    }
+
+    /// Multiplier for a session spanning `[start, end)`: the bonus is
+    /// weighted by how much of the session the window covers.
+    pub fn multiplier(&self, start: i64, end: i64) -> Option<Fixed> {
+        let duration = end - start;
+        if duration <= 0 {
+            return Some(Fixed::ONE);
+        }
+
+        let bonus = self.overlap(start, end) as u64 * self.multiplier_bps.saturating_sub(BPS_ONE) as u64;
+        let scale = duration as u64 * BPS_ONE as u64;
+        Fixed::from_ratio(scale + bonus, scale)
+    }
+}
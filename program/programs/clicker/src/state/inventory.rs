@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use clicker_types::seeds;
+
+use crate::errors::ClickerError;
+
+#[zero_copy]
+#[derive(Default)]
+pub struct ItemStack {
+    pub item_id: u32,           // 4 bytes
+    pub quantity: u32,          // 4 bytes - 0 marks a free slot
+    pub expires_at: i64,        // 8 bytes - 0 never expires
+}
+
+impl ItemStack {
+    pub fn is_live(&self, now: i64) -> bool {
+        self.quantity > 0 && (self.expires_at == 0 || now < self.expires_at)
+    }
+}
+
+/// A game's items. Stacks of the same item only merge when they expire
+/// together; expired stacks count as free slots. Other games can only
+/// transfer items in once the player opts in with set_accepts_transfers.
+#[account(zero_copy)]
+pub struct Inventory {
+    pub game: Pubkey,                                   // 32 bytes
+    pub stacks: [ItemStack; Inventory::MAX_STACKS],     // 32 * 16 bytes
+    pub bump: u8,                                       // 1 byte
+    pub accepts_transfers: u8,                          // 1 byte - 1 lets transfer_item add to it
+    pub _padding: [u8; 6],                              // 6 bytes
+}
+
+impl Inventory {
+    pub const SEED: &'static [u8] = seeds::INVENTORY;
+    pub const MAX_STACKS: usize = 32;
+    pub const MAXIMUM_SIZE: usize = 32 + Inventory::MAX_STACKS * 16 + 1 + 1 + 6;
+
+    // Item ids
+    pub const LOOTBOX: u32 = 1;
+
+    pub fn count(&self, item_id: u32, now: i64) -> u64 {
+        self.stacks
+            .iter()
+            .filter(|stack| stack.item_id == item_id && stack.is_live(now))
+            .map(|stack| stack.quantity as u64)
+            .sum()
+    }
+
+    /// Adds items. Fails if every slot is taken or the stack they merge into
+    /// would overflow.
+    pub fn grant(&mut self, item_id: u32, quantity: u32, expires_at: i64, now: i64) -> Result<()> {
+        if quantity == 0 {
+            return Ok(());
+        }
+        let slot = self
+            .stacks
+            .iter()
+            .position(|s| s.item_id == item_id && s.expires_at == expires_at && s.is_live(now))
+            .or_else(|| self.stacks.iter().position(|s| !s.is_live(now)));
+        match slot {
+            Some(slot) => {
+                let stack = &mut self.stacks[slot];
+                if !stack.is_live(now) {
+                    *stack = ItemStack { item_id, quantity: 0, expires_at };
+                }
+                stack.quantity = stack.quantity.checked_add(quantity).ok_or_else(|| {
+                    msg!("stack of item {} holds {}, {} more overflows", item_id, stack.quantity, quantity);
+                    error!(ClickerError::ItemStackOverflow)
+                })?;
+                Ok(())
+            }
+            None => {
+                msg!("inventory of game {} is full", self.game);
+                Err(error!(ClickerError::InventoryFull))
+            }
+        }
+    }
+
+    /// Removes items, soonest to expire first; false and unchanged if there
+    /// aren't enough.
+    pub fn consume(&mut self, item_id: u32, quantity: u32, now: i64) -> bool {
+        if self.count(item_id, now) < quantity as u64 {
+            return false;
+        }
+        let mut remaining = quantity;
+        while remaining > 0 {
+            let stack = self
+                .stacks
+                .iter_mut()
+                .filter(|s| s.item_id == item_id && s.is_live(now))
+                .min_by_key(|s| if s.expires_at == 0 { i64::MAX } else { s.expires_at })
+                .expect("counted above");
+            let taken = remaining.min(stack.quantity);
+            stack.quantity -= taken;
+            remaining -= taken;
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grant_refuses_to_overflow_a_stack() {
+        let mut inventory: Inventory = bytemuck::Zeroable::zeroed();
+
+        inventory.grant(Inventory::LOOTBOX, u32::MAX - 1, 0, 0).unwrap();
+        assert_eq!(
+            inventory.grant(Inventory::LOOTBOX, 2, 0, 0).unwrap_err(),
+            error!(ClickerError::ItemStackOverflow)
+        );
+        assert_eq!(inventory.count(Inventory::LOOTBOX, 0), (u32::MAX - 1) as u64);
+
+        inventory.grant(Inventory::LOOTBOX, 1, 0, 0).unwrap();
+        assert_eq!(inventory.count(Inventory::LOOTBOX, 0), u32::MAX as u64);
+    }
+}
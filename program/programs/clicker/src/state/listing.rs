@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+use clicker_types::seeds;
+
+/// Items put up for sale, held here until they are bought or the listing
+/// is cancelled. Closed either way, refunding rent to the seller.
+#[account]
+#[derive(Default)]
+pub struct Listing {
+    pub game: Pubkey,           // 32 bytes - the seller's game
+    pub seller: Pubkey,         // 32 bytes - receives the proceeds and rent
+    pub item_id: u32,           // 4 bytes
+    pub quantity: u32,          // 4 bytes
+    pub expires_at: i64,        // 8 bytes - of the listed stack, 0 never expires
+    pub price: u64,             // 8 bytes - lamports for the whole listing
+    pub listed_at: i64,         // 8 bytes
+    pub bump: u8,               // 1 byte
+}
+
+impl Listing {
+    pub const SEED: &'static [u8] = seeds::LISTING;
+    pub const MAXIMUM_SIZE: usize = 32 + 32 + 4 + 4 + 8 + 8 + 8 + 1;
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use clicker_types::seeds;
+
+use crate::state::Game;
+
+/// Everything a frontend shows for a game, mirrored into one small account
+/// so clients can hold a single websocket subscription per player.
+#[account]
+#[derive(Default)]
+pub struct LiveState {
+    pub game: Pubkey,                       // 32 bytes
+    pub active_session: Option<Pubkey>,     // 1 + 32 bytes
+    pub clicks: u32,                        // 4 bytes
+    pub total_clicks: u64,                  // 8 bytes - the game's lifetime_clicks
+    pub level: u32,                         // 4 bytes
+    pub boost_multiplier_bps: u16,          // 2 bytes - partner multiplier, 0 when none
+    pub boost_expires_at: i64,              // 8 bytes
+    pub updated_at: i64,                    // 8 bytes
+    pub bump: u8,                           // 1 byte
+}
+
+impl LiveState {
+    pub const SEED: &'static [u8] = seeds::LIVE_STATE;
+    pub const MAXIMUM_SIZE: usize = 32 + 1 + 32 + 4 + 8 + 4 + 2 + 8 + 8 + 1;
+
+    pub fn sync(&mut self, game: &Game, now: i64) {
+        self.active_session = game.active_session();
+        self.clicks = game.clicks;
+        self.total_clicks = game.lifetime_clicks;
+        self.level = game.level;
+        self.boost_multiplier_bps = game.partner_multiplier_bps;
+        self.boost_expires_at = game.partner_multiplier_expires_at;
+        self.updated_at = now;
+    }
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use clicker_types::math::Fixed;
+use clicker_types::seeds;
+
+/// Pairs a veteran with a new player. While it runs, the mentee's reveals
+/// earn both of them `bonus_bps` of the revealed clicks; the mentor's share
+/// accrues here until they claim it.
+#[account]
+#[derive(Default)]
+pub struct Mentorship {
+    pub mentor_game: Pubkey,        // 32 bytes
+    pub mentee_game: Pubkey,        // 32 bytes
+    pub started_at: i64,            // 8 bytes
+    pub expires_at: i64,            // 8 bytes
+    pub bonus_bps: u16,             // 2 bytes - snapshot of the config at pairing
+    pub mentor_bonus_clicks: u64,   // 8 bytes - accrued, not yet claimed
+    pub bump: u8,                   // 1 byte
+}
+
+impl Mentorship {
+    pub const SEED: &'static [u8] = seeds::MENTORSHIP;
+    pub const MAXIMUM_SIZE: usize = 32 + 32 + 8 + 8 + 2 + 8 + 1;
+
+    /// Bonus on a reveal at `now`, 0 once the pairing has run out.
+    pub fn active_bonus_bps(&self, now: i64) -> u64 {
+        if now < self.expires_at {
+            self.bonus_bps as u64
+        } else {
+            0
+        }
+    }
+
+    /// Bonus clicks on `clicks` revealed at `now`, rounded down.
+    pub fn bonus_clicks(&self, clicks: u64, now: i64) -> Option<u64> {
+        Fixed::from_bps(self.active_bonus_bps(now) as u16).apply(clicks)
+    }
+}
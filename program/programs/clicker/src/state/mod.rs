@@ -0,0 +1,59 @@
+mod admin_record;
+mod attestation;
+mod config;
+mod coop;
+mod game;
+mod gift;
+mod global_stats;
+mod golden_window;
+mod inventory;
+mod listing;
+mod live_state;
+mod mentorship;
+mod offer;
+mod partner;
+mod quest;
+mod raffle;
+mod receipt;
+mod recipe;
+mod season_pass;
+mod session_archive;
+mod session_index;
+mod shoutbox;
+mod skill_tree;
+mod snapshot;
+mod treasury;
+mod trophy_cabinet;
+mod weekly_challenge;
+mod world_boss;
+mod world_record;
+
+pub use admin_record::*;
+pub use attestation::*;
+pub use config::*;
+pub use coop::*;
+pub use game::*;
+pub use gift::*;
+pub use global_stats::*;
+pub use golden_window::*;
+pub use inventory::*;
+pub use listing::*;
+pub use live_state::*;
+pub use mentorship::*;
+pub use offer::*;
+pub use partner::*;
+pub use quest::*;
+pub use raffle::*;
+pub use receipt::*;
+pub use recipe::*;
+pub use season_pass::*;
+pub use session_archive::*;
+pub use session_index::*;
+pub use shoutbox::*;
+pub use skill_tree::*;
+pub use snapshot::*;
+pub use treasury::*;
+pub use trophy_cabinet::*;
+pub use weekly_challenge::*;
+pub use world_boss::*;
+pub use world_record::*;
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use clicker_types::seeds;
+
+/// What redeeming an offer grants.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OfferReward {
+    Gems(u64),
+    /// Multiplies revealed clicks for `duration` seconds, replacing any
+    /// offer boost still running.
+    Boost { multiplier_bps: u16, duration: i64 },
+}
+
+impl Default for OfferReward {
+    fn default() -> Self {
+        OfferReward::Gems(0)
+    }
+}
+
+/// An offerwall offer. The offerwall oracle signs (offer_id || game) once a
+/// player completes it off-chain.
+#[account]
+#[derive(Default)]
+pub struct Offer {
+    pub offer_id: u32,              // 4 bytes
+    pub reward: OfferReward,        // 1 + 2 + 8 bytes (largest variant)
+    pub max_redemptions: u32,       // 4 bytes - across all games, 0 is uncapped
+    pub redemptions: u32,           // 4 bytes
+    pub active: bool,               // 1 byte
+    pub bump: u8,                   // 1 byte
+}
+
+impl Offer {
+    pub const SEED: &'static [u8] = seeds::OFFER;
+    pub const MAXIMUM_SIZE: usize = 4 + (1 + 2 + 8) + 4 + 4 + 1 + 1;
+
+    /// The message the offerwall oracle signs for `game`.
+    pub fn attestation_message(offer_id: u32, game: &Pubkey) -> [u8; 36] {
+        let mut message = [0u8; 36];
+        message[..4].copy_from_slice(&offer_id.to_le_bytes());
+        message[4..].copy_from_slice(game.as_ref());
+        message
+    }
+}
+
+/// Marks an offer as redeemed by a game, so each attestation pays once.
+#[account]
+pub struct OfferRedemption {
+    pub offer: Pubkey,          // 32 bytes
+    pub game: Pubkey,           // 32 bytes
+    pub signature: [u8; 64],    // 64 bytes - the oracle's attestation, kept for support
+    pub redeemed_at: i64,       // 8 bytes
+}
+
+impl OfferRedemption {
+    pub const SEED: &'static [u8] = seeds::OFFER_REDEMPTION;
+    pub const MAXIMUM_SIZE: usize = 32 + 32 + 64 + 8;
+}
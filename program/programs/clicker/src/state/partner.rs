@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use clicker_types::seeds;
+
+/// An NFT collection run by a partner community. Holders of a verified item
+/// from the collection get a click multiplier.
+#[account]
+#[derive(Default)]
+pub struct PartnerCollection {
+    pub collection: Pubkey,     // 32 bytes - Metaplex collection mint
+    pub multiplier_bps: u16,    // 2 bytes
+    pub active: bool,           // 1 byte
+    pub bump: u8,               // 1 byte
+}
+
+impl PartnerCollection {
+    pub const SEED: &'static [u8] = seeds::PARTNER_COLLECTION;
+    pub const MAXIMUM_SIZE: usize = 32 + 2 + 1 + 1;
+
+    pub const MAX_MULTIPLIER_BPS: u16 = 30_000;
+    // holders re-verify weekly so a sold NFT doesn't keep boosting forever
+    pub const VERIFICATION_TTL: i64 = 7 * 24 * 60 * 60;
+}
+
+/// Which game a partner NFT is boosting. One NFT boosts one game at a time;
+/// another game can take it over once the current verification lapses.
+#[account]
+#[derive(Default)]
+pub struct PartnerNft {
+    pub mint: Pubkey,           // 32 bytes
+    pub game: Pubkey,           // 32 bytes
+    pub expires_at: i64,        // 8 bytes - the game's partner_multiplier_expires_at
+    pub bump: u8,               // 1 byte
+}
+
+impl PartnerNft {
+    pub const SEED: &'static [u8] = seeds::PARTNER_NFT;
+    pub const MAXIMUM_SIZE: usize = 32 + 32 + 8 + 1;
+}
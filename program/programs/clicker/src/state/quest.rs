@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use clicker_types::seeds;
+
+/// What a player has to show to complete a partner quest.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum QuestCondition {
+    /// Hold at least `min_amount` of `mint` in a token account they own.
+    TokenBalance { mint: Pubkey, min_amount: u64 },
+    /// Have an account owned by `program` whose data holds the player's key at `player_offset`.
+    ProgramAccount { program: Pubkey, player_offset: u16 },
+}
+
+impl Default for QuestCondition {
+    fn default() -> Self {
+        QuestCondition::TokenBalance {
+            mint: Pubkey::default(),
+            min_amount: 0,
+        }
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct PartnerQuest {
+    pub quest_id: u32,              // 4 bytes
+    pub condition: QuestCondition,  // 1 + 32 + 8 bytes (largest variant)
+    pub reward_clicks: u64,         // 8 bytes
+    pub min_level: u32,             // 4 bytes
+    pub active: bool,               // 1 byte
+    pub chain: Pubkey,              // 32 bytes - QuestChain it is a step of, default if standalone
+    pub chain_step: u8,             // 1 byte - its index in the chain
+    pub bump: u8,                   // 1 byte
+}
+
+impl PartnerQuest {
+    pub const SEED: &'static [u8] = seeds::PARTNER_QUEST;
+    pub const MAXIMUM_SIZE: usize = 4 + (1 + 32 + 8) + 8 + 4 + 1 + 32 + 1 + 1;
+
+    pub fn is_chained(&self) -> bool {
+        self.chain != Pubkey::default()
+    }
+}
+
+/// Marks a quest as completed by a game, so the reward is paid once.
+#[account]
+#[derive(Default)]
+pub struct QuestCompletion {
+    pub quest: Pubkey,          // 32 bytes
+    pub game: Pubkey,           // 32 bytes
+    pub completed_at: i64,      // 8 bytes
+}
+
+impl QuestCompletion {
+    pub const SEED: &'static [u8] = seeds::QUEST_COMPLETION;
+    pub const MAXIMUM_SIZE: usize = 32 + 32 + 8;
+}
+
+/// Partner quests that unlock one after another. Each step pays its own
+/// reward; finishing the last one also pays the chain's item reward.
+#[account]
+#[derive(Default)]
+pub struct QuestChain {
+    pub chain_id: u32,          // 4 bytes
+    pub quests: [u32; QuestChain::MAX_STEPS],   // 8 * 4 bytes - quest ids in order
+    pub length: u8,             // 1 byte
+    pub reward_item_id: u32,    // 4 bytes
+    pub reward_quantity: u32,   // 4 bytes
+    pub bump: u8,               // 1 byte
+}
+
+impl QuestChain {
+    pub const SEED: &'static [u8] = seeds::QUEST_CHAIN;
+    pub const MAX_STEPS: usize = 8;
+    pub const MAXIMUM_SIZE: usize = 4 + QuestChain::MAX_STEPS * 4 + 1 + 4 + 4 + 1;
+}
+
+/// How far a game has come through a quest chain.
+#[account]
+#[derive(Default)]
+pub struct QuestChainProgress {
+    pub chain: Pubkey,          // 32 bytes
+    pub game: Pubkey,           // 32 bytes
+    pub completed: u8,          // 1 byte - steps done, so the index of the one unlocked
+    pub completed_at: i64,      // 8 bytes - when the last step was done, 0 until then
+    pub bump: u8,               // 1 byte
+}
+
+impl QuestChainProgress {
+    pub const SEED: &'static [u8] = seeds::QUEST_CHAIN_PROGRESS;
+    pub const MAXIMUM_SIZE: usize = 32 + 32 + 1 + 8 + 1;
+}
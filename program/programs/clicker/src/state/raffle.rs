@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use clicker_types::seeds;
+
+/// A weekly raffle. Verified clicks revealed during the period are converted
+/// into a contiguous range of tickets recorded on the session.
+#[account]
+#[derive(Default)]
+pub struct Raffle {
+    pub period: u64,            // 8 bytes
+    pub start_time: i64,        // 8 bytes
+    pub end_time: i64,          // 8 bytes
+    pub total_tickets: u64,     // 8 bytes
+    pub drawn: bool,            // 1 byte
+    pub winning_ticket: u64,    // 8 bytes
+    pub winner: Pubkey,         // 32 bytes
+    pub prize: u64,             // 8 bytes
+    pub bump: u8,               // 1 byte
+    pub draw_slot: u64,         // 8 bytes - slot close_raffle landed in, 0 while tickets sell
+}
+
+impl Raffle {
+    pub const SEED: &'static [u8] = seeds::RAFFLE;
+    pub const MAXIMUM_SIZE: usize = 8 + 8 + 8 + 8 + 1 + 8 + 32 + 8 + 1 + 8;
+
+    pub const PERIOD_SECONDS: i64 = 7 * 24 * 60 * 60;
+    pub const CLICKS_PER_TICKET: u64 = 100;
+
+    pub fn period_at(timestamp: i64) -> u64 {
+        (timestamp / Self::PERIOD_SECONDS) as u64
+    }
+
+    pub fn is_open(&self, now: i64) -> bool {
+        !self.drawn && self.draw_slot == 0 && now >= self.start_time && now < self.end_time
+    }
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+use clicker_types::seeds;
+
+/// One SOL purchase, kept so it can be refunded within Config.refund_window.
+/// Closed on refund. Written for gems and for premium season passes bought
+/// with SOL.
+#[account]
+#[derive(Default)]
+pub struct Receipt {
+    pub game: Pubkey,           // 32 bytes
+    pub buyer: Pubkey,          // 32 bytes
+    pub gems: u64,              // 8 bytes - granted by the purchase
+    pub lamports: u64,          // 8 bytes - paid into the treasury
+    pub purchased_at: i64,      // 8 bytes
+    pub bump: u8,               // 1 byte
+    pub pass: Pubkey,           // 32 bytes - SeasonPass made premium, default for gems
+}
+
+impl Receipt {
+    pub const SEED: &'static [u8] = seeds::RECEIPT;
+    pub const MAXIMUM_SIZE: usize = 32 + 32 + 8 + 8 + 8 + 1 + 32;
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use clicker_types::seeds;
+
+/// An item a recipe uses up. A zero quantity marks an unused slot.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RecipeInput {
+    pub item_id: u32,
+    pub quantity: u32,
+}
+
+impl RecipeInput {
+    pub const SIZE: usize = 4 + 4;
+}
+
+/// Turns a set of inventory items into another item.
+#[account]
+#[derive(Default)]
+pub struct Recipe {
+    pub recipe_id: u32,             // 4 bytes
+    pub inputs: [RecipeInput; Recipe::MAX_INPUTS],  // 4 * 8 bytes
+    pub output_item_id: u32,        // 4 bytes
+    pub output_quantity: u32,       // 4 bytes
+    pub active: bool,               // 1 byte
+    pub bump: u8,                   // 1 byte
+}
+
+impl Recipe {
+    pub const SEED: &'static [u8] = seeds::RECIPE;
+    pub const MAX_INPUTS: usize = 4;
+    pub const MAXIMUM_SIZE: usize = 4 + Recipe::MAX_INPUTS * RecipeInput::SIZE + 4 + 4 + 1 + 1;
+}
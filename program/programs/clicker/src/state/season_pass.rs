@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use clicker_types::seeds;
+
+/// One step of a season pass. A tier without rewards marks an unused slot.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PassTier {
+    pub xp: u64,                // xp earned since joining the pass
+    pub reward_gems: u64,
+    pub reward_clicks: u64,
+    pub premium_only: bool,
+}
+
+impl PassTier {
+    pub const SIZE: usize = 8 + 8 + 8 + 1;
+
+    pub fn is_used(&self) -> bool {
+        self.reward_gems > 0 || self.reward_clicks > 0
+    }
+}
+
+/// Rule changes a season makes while it runs, each in basis points of the
+/// usual value. 0 leaves a value as it is.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SeasonModifiers {
+    pub clicks_bps: u16,        // credited clicks
+    pub cps_cap_bps: u16,       // the CPS cap reveals are held to
+    pub xp_bps: u16,            // xp earned from reveals
+}
+
+impl SeasonModifiers {
+    pub const SIZE: usize = 2 + 2 + 2;
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// How a premium pass is paid for.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PassPayment {
+    Gems,
+    Lamports,
+}
+
+/// A season of the pass, with its tiers and premium prices.
+#[account]
+#[derive(Default)]
+pub struct PassSeason {
+    pub season_id: u32,             // 4 bytes
+    pub starts_at: i64,             // 8 bytes
+    pub ends_at: i64,               // 8 bytes - joining, upgrading and claiming stop here
+    pub premium_gems: u64,          // 8 bytes - 0 means premium can't be bought with gems
+    pub premium_lamports: u64,      // 8 bytes - 0 means premium can't be bought with SOL
+    pub tiers: [PassTier; PassSeason::MAX_TIERS],   // 10 * 25 bytes
+    pub modifiers: SeasonModifiers, // SeasonModifiers::SIZE bytes - applied while the season runs
+    pub bump: u8,                   // 1 byte
+}
+
+impl PassSeason {
+    pub const SEED: &'static [u8] = seeds::PASS_SEASON;
+    pub const MAX_TIERS: usize = 10;
+    pub const MAXIMUM_SIZE: usize =
+        4 + 8 + 8 + 8 + 8 + PassSeason::MAX_TIERS * PassTier::SIZE + SeasonModifiers::SIZE + 1;
+
+    pub fn is_running(&self, now: i64) -> bool {
+        now >= self.starts_at && now < self.ends_at
+    }
+}
+
+/// A game's pass for one season.
+#[account]
+#[derive(Default)]
+pub struct SeasonPass {
+    pub season: Pubkey,         // 32 bytes
+    pub game: Pubkey,           // 32 bytes
+    pub start_xp: u64,          // 8 bytes - game xp when the pass was joined
+    pub premium: bool,          // 1 byte
+    pub claimed_tiers: u16,     // 2 bytes - bitmask of tier indexes
+    pub bump: u8,               // 1 byte
+}
+
+impl SeasonPass {
+    pub const SEED: &'static [u8] = seeds::SEASON_PASS;
+    pub const MAXIMUM_SIZE: usize = 32 + 32 + 8 + 1 + 2 + 1;
+
+    /// Xp earned since joining. A soft reset wipes xp, and with it the
+    /// progress towards tiers not yet claimed.
+    pub fn earned_xp(&self, game_xp: u64) -> u64 {
+        game_xp.saturating_sub(self.start_xp)
+    }
+
+    pub fn is_claimed(&self, tier: u8) -> bool {
+        self.claimed_tiers & (1 << tier) != 0
+    }
+}
@@ -0,0 +1,217 @@
+use anchor_lang::prelude::*;
+use clicker_types::seeds;
+use anchor_lang::solana_program::hash::hashv;
+
+/// Append-only Merkle tree of revealed sessions, so history can be proven
+/// without keeping a rent-paying account per session around. Only the right
+/// frontier is stored; indexers rebuild the full tree from SessionArchived
+/// events. Recent roots are kept so a proof built against a slightly stale
+/// root still verifies while other reveals land.
+#[account(zero_copy)]
+pub struct SessionArchive {
+    pub next_index: u64,                                            // 8 bytes
+    pub recent_roots: [[u8; 32]; SessionArchive::ROOT_HISTORY],     // 16 * 32 bytes
+    pub filled_subtrees: [[u8; 32]; SessionArchive::DEPTH],         // 24 * 32 bytes
+    pub zero_hashes: [[u8; 32]; SessionArchive::DEPTH + 1],         // 25 * 32 bytes
+    pub current_root_index: u8,                                     // 1 byte
+    pub bump: u8,                                                   // 1 byte
+    pub _padding: [u8; 6],                                          // 6 bytes
+}
+
+/// The fields hashed into a session's leaf.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ArchivedSession {
+    pub session: Pubkey,
+    pub game: Pubkey,
+    pub player: Pubkey,
+    pub clicks: u32,
+    pub start_time: i64,
+    pub end_time: i64,
+}
+
+impl SessionArchive {
+    pub const SEED: &'static [u8] = seeds::SESSION_ARCHIVE;
+    pub const DEPTH: usize = 24;
+    pub const ROOT_HISTORY: usize = 16;
+    pub const MAXIMUM_SIZE: usize = 8
+        + SessionArchive::ROOT_HISTORY * 32
+        + SessionArchive::DEPTH * 32
+        + (SessionArchive::DEPTH + 1) * 32
+        + 1 + 1 + 6;
+
+    pub fn initialize(&mut self) {
+        for level in 0..Self::DEPTH {
+            self.zero_hashes[level + 1] = hash_pair(&self.zero_hashes[level], &self.zero_hashes[level]);
+        }
+        self.recent_roots[0] = self.zero_hashes[Self::DEPTH];
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.recent_roots[self.current_root_index as usize]
+    }
+
+    pub fn is_known_root(&self, root: &[u8; 32]) -> bool {
+        self.next_index > 0 && self.recent_roots.contains(root)
+    }
+
+    /// Appends a leaf and returns its index, or None once the tree is full.
+    pub fn append(&mut self, leaf: [u8; 32]) -> Option<u64> {
+        let index = self.next_index;
+        if index >= 1 << Self::DEPTH {
+            return None;
+        }
+
+        let mut node = leaf;
+        let mut position = index;
+        for level in 0..Self::DEPTH {
+            node = if position & 1 == 0 {
+                self.filled_subtrees[level] = node;
+                hash_pair(&node, &self.zero_hashes[level])
+            } else {
+                hash_pair(&self.filled_subtrees[level], &node)
+            };
+            position >>= 1;
+        }
+
+        self.current_root_index = ((self.current_root_index as usize + 1) % Self::ROOT_HISTORY) as u8;
+        self.recent_roots[self.current_root_index as usize] = node;
+        self.next_index += 1;
+
+        Some(index)
+    }
+}
+
+impl ArchivedSession {
+    pub fn leaf(&self) -> [u8; 32] {
+        hashv(&[
+            b"clicker-session",
+            self.session.as_ref(),
+            self.game.as_ref(),
+            self.player.as_ref(),
+            &self.clicks.to_le_bytes(),
+            &self.start_time.to_le_bytes(),
+            &self.end_time.to_le_bytes(),
+        ])
+        .to_bytes()
+    }
+}
+
+pub fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    hashv(&[left, right]).to_bytes()
+}
+
+/// Walks a proof from leaf to root. `proof` lists siblings bottom-up.
+pub fn merkle_root_from_proof(leaf: [u8; 32], index: u64, proof: &[[u8; 32]]) -> [u8; 32] {
+    let mut node = leaf;
+    for (level, sibling) in proof.iter().enumerate() {
+        node = if (index >> level) & 1 == 0 {
+            hash_pair(&node, sibling)
+        } else {
+            hash_pair(sibling, &node)
+        };
+    }
+    node
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_archive() -> Box<SessionArchive> {
+        let mut archive: Box<SessionArchive> = Box::new(bytemuck::Zeroable::zeroed());
+        archive.initialize();
+        archive
+    }
+
+    fn leaf(i: u8) -> [u8; 32] {
+        [i + 1; 32]
+    }
+
+    // Builds the whole tree over `leaves`, padded with empty subtrees, and
+    // returns every level from the leaves up to the root
+    fn full_tree(leaves: &[[u8; 32]], zero_hashes: &[[u8; 32]]) -> Vec<Vec<[u8; 32]>> {
+        let mut levels = vec![leaves.to_vec()];
+        for level in 0..SessionArchive::DEPTH {
+            let nodes = &levels[level];
+            let parents = nodes
+                .chunks(2)
+                .map(|pair| hash_pair(&pair[0], pair.get(1).unwrap_or(&zero_hashes[level])))
+                .collect();
+            levels.push(parents);
+        }
+        levels
+    }
+
+    #[test]
+    fn empty_root_is_the_top_zero_hash() {
+        let archive = new_archive();
+
+        assert_eq!(archive.root(), archive.zero_hashes[SessionArchive::DEPTH]);
+        assert!(!archive.is_known_root(&archive.root()));
+    }
+
+    #[test]
+    fn append_matches_the_full_tree() {
+        let mut archive = new_archive();
+        let leaves: Vec<[u8; 32]> = (0..5).map(leaf).collect();
+
+        for (i, leaf) in leaves.iter().enumerate() {
+            assert_eq!(archive.append(*leaf), Some(i as u64));
+
+            let levels = full_tree(&leaves[..=i], &archive.zero_hashes);
+            assert_eq!(archive.root(), levels[SessionArchive::DEPTH][0]);
+            assert!(archive.is_known_root(&archive.root()));
+        }
+        assert_eq!(archive.next_index, 5);
+    }
+
+    #[test]
+    fn proofs_verify_against_the_root() {
+        let mut archive = new_archive();
+        let leaves: Vec<[u8; 32]> = (0..5).map(leaf).collect();
+        for leaf in &leaves {
+            archive.append(*leaf);
+        }
+
+        let levels = full_tree(&leaves, &archive.zero_hashes);
+        for (index, archived) in leaves.iter().enumerate() {
+            let proof: Vec<[u8; 32]> = (0..SessionArchive::DEPTH)
+                .map(|level| {
+                    let sibling = (index >> level) ^ 1;
+                    *levels[level].get(sibling).unwrap_or(&archive.zero_hashes[level])
+                })
+                .collect();
+
+            assert_eq!(merkle_root_from_proof(*archived, index as u64, &proof), archive.root());
+            assert_ne!(merkle_root_from_proof(leaf(9), index as u64, &proof), archive.root());
+        }
+    }
+
+    #[test]
+    fn older_roots_stay_known_for_the_history_window() {
+        let mut archive = new_archive();
+        archive.append(leaf(0));
+        let first_root = archive.root();
+
+        for i in 1..SessionArchive::ROOT_HISTORY as u8 {
+            archive.append(leaf(i));
+        }
+        assert!(archive.is_known_root(&first_root));
+
+        archive.append(leaf(SessionArchive::ROOT_HISTORY as u8));
+        assert!(!archive.is_known_root(&first_root));
+    }
+
+    #[test]
+    fn append_stops_once_the_tree_is_full() {
+        let mut archive = new_archive();
+        archive.next_index = (1 << SessionArchive::DEPTH) - 1;
+
+        assert_eq!(archive.append(leaf(0)), Some((1 << SessionArchive::DEPTH) - 1));
+        let root = archive.root();
+
+        assert_eq!(archive.append(leaf(1)), None);
+        assert_eq!(archive.next_index, 1 << SessionArchive::DEPTH);
+        assert_eq!(archive.root(), root);
+    }
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use clicker_types::seeds;
+
+/// A game's sessions in the order they started, numbered from 0 when the
+/// index was created. Entries are raw session pubkeys after the header, so
+/// an explorer fetches sessions 100 to 120 with a single data slice at
+/// `entry_offset(100)` instead of scanning program accounts. The account
+/// grows by one entry on every start.
+#[account]
+#[derive(Default)]
+pub struct SessionIndex {
+    pub game: Pubkey,       // 32 bytes
+    pub count: u64,         // 8 bytes - entries after the header
+    pub bump: u8,           // 1 byte
+}
+
+impl SessionIndex {
+    pub const SEED: &'static [u8] = seeds::SESSION_INDEX;
+    // The header; entries follow it
+    pub const MAXIMUM_SIZE: usize = 32 + 8 + 1;
+    pub const ENTRY_SIZE: usize = 32;
+
+    /// Offset of entry `number` into the account data, discriminator
+    /// included.
+    pub const fn entry_offset(number: u64) -> usize {
+        8 + Self::MAXIMUM_SIZE + number as usize * Self::ENTRY_SIZE
+    }
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use clicker_types::seeds;
+
+#[zero_copy]
+pub struct ShoutboxMessage {
+    pub game: Pubkey,                               // 32 bytes - default key marks a slot never written
+    pub posted_at: i64,                             // 8 bytes
+    pub len: u8,                                    // 1 byte - bytes of text in use
+    pub text: [u8; ShoutboxMessage::MAX_LEN],       // 100 bytes - UTF-8
+    pub _padding: [u8; 3],                          // 3 bytes
+}
+
+impl ShoutboxMessage {
+    pub const MAX_LEN: usize = 100;
+    pub const SIZE: usize = 32 + 8 + 1 + ShoutboxMessage::MAX_LEN + 3;
+
+    pub fn text(&self) -> &[u8] {
+        &self.text[..self.len as usize]
+    }
+}
+
+/// A season's message board: the last MAX_MESSAGES posts, oldest overwritten
+/// first. Posting costs clicks or gems, whichever the shoutbox prices; a fee
+/// of 0 means that currency isn't accepted. MessagePosted events keep the
+/// full history for indexers.
+#[account(zero_copy)]
+pub struct Shoutbox {
+    pub season: Pubkey,                                         // 32 bytes - the PassSeason it belongs to
+    pub message_count: u64,                                     // 8 bytes - ever posted; the next slot is this mod MAX_MESSAGES
+    pub fee_clicks: u64,                                        // 8 bytes - taken from spendable_clicks
+    pub fee_gems: u64,                                          // 8 bytes
+    pub messages: [ShoutboxMessage; Shoutbox::MAX_MESSAGES],    // 64 * 144 bytes
+    pub bump: u8,                                               // 1 byte
+    pub _padding: [u8; 7],                                      // 7 bytes
+}
+
+impl Shoutbox {
+    pub const SEED: &'static [u8] = seeds::SHOUTBOX;
+    pub const MAX_MESSAGES: usize = 64;
+    // between two posts from the same game
+    pub const POST_COOLDOWN: i64 = 60;
+    pub const MAXIMUM_SIZE: usize =
+        32 + 8 + 8 + 8 + Shoutbox::MAX_MESSAGES * ShoutboxMessage::SIZE + 1 + 7;
+
+    /// Writes over the oldest slot and returns the message's number.
+    pub fn push(&mut self, game: Pubkey, text: &[u8], now: i64) -> u64 {
+        let index = self.message_count;
+        let slot = &mut self.messages[(index % Self::MAX_MESSAGES as u64) as usize];
+        slot.game = game;
+        slot.posted_at = now;
+        slot.len = text.len() as u8;
+        slot.text = [0; ShoutboxMessage::MAX_LEN];
+        slot.text[..text.len()].copy_from_slice(text);
+        self.message_count += 1;
+        index
+    }
+}
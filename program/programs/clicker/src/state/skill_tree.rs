@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use clicker_types::seeds;
+
+/// What unlocking a node does. Magnitudes are in basis points.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SkillEffect {
+    CpsCap,
+    IdleRate,
+    CritChance,
+}
+
+#[zero_copy]
+#[derive(Default)]
+pub struct SkillNode {
+    pub prerequisites: u64,     // 8 bytes - bitmask of node ids that must be unlocked first
+    pub magnitude_bps: u16,     // 2 bytes
+    pub cost: u8,               // 1 byte - skill points
+    pub effect: u8,             // 1 byte - SkillEffect as u8
+    pub defined: u8,            // 1 byte - bool
+    pub unlocked: u8,           // 1 byte - bool, set once any game unlocks the node
+    pub _padding: [u8; 2],      // 2 bytes
+}
+
+/// The shared upgrade DAG. Nodes may only require lower-numbered nodes,
+/// which keeps the graph acyclic without having to walk it.
+#[account(zero_copy)]
+pub struct SkillTree {
+    pub nodes: [SkillNode; SkillTree::MAX_NODES],  // 64 * 16 bytes
+    pub bump: u8,                                  // 1 byte
+    pub _padding: [u8; 7],                         // 7 bytes
+}
+
+impl SkillTree {
+    pub const SEED: &'static [u8] = seeds::SKILL_TREE;
+    pub const MAX_NODES: usize = 64;
+    pub const MAXIMUM_SIZE: usize = SkillTree::MAX_NODES * 16 + 1 + 7;
+    pub const RESPEC_COST_PER_POINT: u64 = 1_000; // clicks
+    pub const RESPEC_COOLDOWN: i64 = 7 * 24 * 60 * 60;
+    pub const MAX_MAGNITUDE_BPS: u16 = 2_500;
+    // the most a game's nodes can add up to for any one effect
+    pub const MAX_BONUS_BPS: u16 = 10_000;
+}
+
+impl SkillEffect {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(SkillEffect::CpsCap),
+            1 => Some(SkillEffect::IdleRate),
+            2 => Some(SkillEffect::CritChance),
+            _ => None,
+        }
+    }
+}
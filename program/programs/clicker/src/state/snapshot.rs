@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use clicker_types::seeds;
+
+/// Write-once summary of a game for a future program version to import.
+/// Being owned by this program is the sign-off: nobody else can create it.
+#[account]
+#[derive(Default)]
+pub struct Snapshot {
+    pub version: u8,                // 1 byte
+    pub game: Pubkey,               // 32 bytes
+    pub player: Pubkey,             // 32 bytes
+    pub clicks: u32,                // 4 bytes
+    pub total_clicks: u64,          // 8 bytes - the game's lifetime_clicks
+    pub last_session_end: i64,      // 8 bytes
+    pub game_hash: [u8; 32],        // 32 bytes - hash of the Game account data at export
+    pub exported_at: i64,           // 8 bytes
+    pub exported_slot: u64,         // 8 bytes
+    pub spendable_clicks: u64,      // 8 bytes - since version 2
+    pub bump: u8,                   // 1 byte
+}
+
+impl Snapshot {
+    pub const SEED: &'static [u8] = seeds::SNAPSHOT;
+    pub const VERSION: u8 = 2;
+    pub const MAXIMUM_SIZE: usize = 1 + 32 + 32 + 4 + 8 + 8 + 32 + 8 + 8 + 8 + 1;
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+use clicker_types::seeds;
+
+/// Program-owned account that collects SOL from premium purchases.
+#[account]
+#[derive(Default)]
+pub struct Treasury {
+    pub total_received: u64,    // 8 bytes - lamports paid in over its lifetime
+    pub bump: u8,               // 1 byte
+}
+
+impl Treasury {
+    pub const SEED: &'static [u8] = seeds::TREASURY;
+    pub const MAXIMUM_SIZE: usize = 8 + 1;
+}
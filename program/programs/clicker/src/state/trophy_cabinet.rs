@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use clicker_types::seeds;
+
+/// An NFT pinned to a cabinet slot. An empty slot has the default mint.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Trophy {
+    pub mint: Pubkey,
+    pub verified_at: i64,       // last time the player was shown to hold it
+}
+
+impl Trophy {
+    pub const SIZE: usize = 32 + 8;
+
+    pub fn is_empty(&self) -> bool {
+        self.mint == Pubkey::default()
+    }
+}
+
+/// The achievement NFTs a player shows off on their profile, in the order
+/// they pinned them to slots. Pins are checked against the wallet when made
+/// and whenever reverify_trophy runs, so frontends can grey out a trophy
+/// whose verified_at has gone stale.
+#[account]
+#[derive(Default)]
+pub struct TrophyCabinet {
+    pub game: Pubkey,           // 32 bytes
+    pub trophies: [Trophy; TrophyCabinet::MAX_TROPHIES],    // 8 * 40 bytes
+    pub bump: u8,               // 1 byte
+}
+
+impl TrophyCabinet {
+    pub const SEED: &'static [u8] = seeds::TROPHY_CABINET;
+    pub const MAX_TROPHIES: usize = 8;
+    pub const MAXIMUM_SIZE: usize = 32 + TrophyCabinet::MAX_TROPHIES * Trophy::SIZE + 1;
+
+    pub fn slot_of(&self, mint: &Pubkey) -> Option<usize> {
+        self.trophies.iter().position(|trophy| !trophy.is_empty() && trophy.mint == *mint)
+    }
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use clicker_types::seeds;
+
+/// How a challenge session is scored.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ChallengeScoring {
+    #[default]
+    Clicks,
+    /// Clicks times the session's longest combo streak, at least 1
+    ClicksTimesStreak,
+}
+
+/// The special rules a weekly challenge plays by. 0 leaves a limit off.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ChallengeRules {
+    pub max_sessions: u8,           // sessions each game may enter
+    pub max_cps: u16,               // clicks per second, on top of the game's usual cap
+    pub scoring: ChallengeScoring,
+}
+
+impl ChallengeRules {
+    pub const SIZE: usize = 1 + 2 + 1;
+
+    pub fn score(&self, clicks: u32, max_combo: u32) -> u64 {
+        match self.scoring {
+            ChallengeScoring::Clicks => clicks as u64,
+            ChallengeScoring::ClicksTimesStreak => clicks as u64 * max_combo.max(1) as u64,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ChallengeScore {
+    pub game: Pubkey,
+    pub score: u64,
+}
+
+impl ChallengeScore {
+    pub const SIZE: usize = 32 + 8;
+}
+
+/// A ruleset that runs for a week. Sessions opt in at start and are held to
+/// its rules at reveal; each game's best score competes on the leaderboard.
+#[account]
+#[derive(Default)]
+pub struct WeeklyChallenge {
+    pub challenge_id: u32,          // 4 bytes
+    pub starts_at: i64,             // 8 bytes
+    pub ends_at: i64,               // 8 bytes - starts_at + DURATION, reveals after it don't score
+    pub rules: ChallengeRules,      // ChallengeRules::SIZE bytes
+    pub leaderboard: [ChallengeScore; WeeklyChallenge::LEADERBOARD_SIZE],  // 10 * 40 bytes - best first, one per game
+    pub bump: u8,                   // 1 byte
+}
+
+impl WeeklyChallenge {
+    pub const SEED: &'static [u8] = seeds::WEEKLY_CHALLENGE;
+    pub const DURATION: i64 = 7 * 24 * 60 * 60;
+    pub const LEADERBOARD_SIZE: usize = 10;
+    pub const MAXIMUM_SIZE: usize =
+        4 + 8 + 8 + ChallengeRules::SIZE + WeeklyChallenge::LEADERBOARD_SIZE * ChallengeScore::SIZE + 1;
+
+    pub fn is_running(&self, now: i64) -> bool {
+        now >= self.starts_at && now < self.ends_at
+    }
+
+    /// Puts `game`'s `score` on the leaderboard if it beats the game's
+    /// standing and the lowest entry, returning its rank from 0.
+    pub fn record(&mut self, game: Pubkey, score: u64) -> Option<u8> {
+        let board = &mut self.leaderboard;
+        // The game's old entry, or failing that the lowest, makes room
+        let slot = board
+            .iter()
+            .position(|entry| entry.game == game)
+            .unwrap_or(board.len() - 1);
+        if score == 0 || score <= board[slot].score {
+            return None;
+        }
+
+        board[slot] = ChallengeScore { game, score };
+        let mut rank = slot;
+        while rank > 0 && board[rank - 1].score < score {
+            board.swap(rank - 1, rank);
+            rank -= 1;
+        }
+        Some(rank as u8)
+    }
+}
+
+/// A game's standing in a weekly challenge.
+#[account]
+#[derive(Default)]
+pub struct ChallengeEntry {
+    pub challenge: Pubkey,          // 32 bytes
+    pub game: Pubkey,               // 32 bytes
+    pub sessions: u8,               // 1 byte - entered so far, up to the rules' max_sessions
+    pub best_score: u64,            // 8 bytes
+    pub bump: u8,                   // 1 byte
+}
+
+impl ChallengeEntry {
+    pub const SEED: &'static [u8] = seeds::CHALLENGE_ENTRY;
+    pub const MAXIMUM_SIZE: usize = 32 + 32 + 1 + 8 + 1;
+}
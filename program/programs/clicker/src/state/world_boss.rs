@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use clicker_types::constants::SECONDS_PER_DAY;
+use clicker_types::seeds;
+
+/// A shared enemy damaged by every joined player's revealed clicks. Once its
+/// HP reaches zero, `loot_clicks` is split among contributors by damage.
+#[account]
+#[derive(Default)]
+pub struct WorldBoss {
+    pub boss_id: u32,           // 4 bytes
+    pub max_hp: u64,            // 8 bytes
+    pub hp: u64,                // 8 bytes
+    pub loot_clicks: u64,       // 8 bytes - split by damage among contributors
+    pub spawned_at: i64,        // 8 bytes
+    pub defeated_at: i64,       // 8 bytes - 0 while alive
+    pub active: bool,           // 1 byte - cleared by despawn
+    pub despawn_at: i64,        // 8 bytes - leaves on its own at this time, 0 stays until despawned
+    pub bump: u8,               // 1 byte
+}
+
+impl WorldBoss {
+    pub const SEED: &'static [u8] = seeds::WORLD_BOSS;
+    pub const MAXIMUM_SIZE: usize = 4 + 8 + 8 + 8 + 8 + 8 + 1 + 8 + 1;
+
+    pub fn spawn(&mut self, boss_id: u32, hp: u64, loot_clicks: u64, now: i64, despawn_at: i64) {
+        self.boss_id = boss_id;
+        self.max_hp = hp;
+        self.hp = hp;
+        self.loot_clicks = loot_clicks;
+        self.spawned_at = now;
+        self.despawn_at = despawn_at;
+        self.active = true;
+    }
+
+    pub fn is_alive(&self, now: i64) -> bool {
+        self.active && self.hp > 0 && (self.despawn_at == 0 || now < self.despawn_at)
+    }
+
+    pub fn is_defeated(&self) -> bool {
+        self.hp == 0
+    }
+
+    /// Applies up to `clicks` damage and returns how much landed.
+    pub fn take_damage(&mut self, clicks: u64) -> u64 {
+        let dealt = clicks.min(self.hp);
+        self.hp -= dealt;
+        dealt
+    }
+
+    /// Loot owed for `damage`. Damage is capped at the remaining HP, so the
+    /// shares of a defeated boss add up to at most `loot_clicks`.
+    pub fn loot_for(&self, damage: u64) -> u64 {
+        (self.loot_clicks as u128 * damage as u128 / self.max_hp as u128) as u64
+    }
+}
+
+/// A game's damage against one boss, and whether its loot was claimed.
+#[account]
+#[derive(Default)]
+pub struct BossContribution {
+    pub boss: Pubkey,           // 32 bytes
+    pub game: Pubkey,           // 32 bytes
+    pub damage: u64,            // 8 bytes
+    pub claimed: bool,          // 1 byte
+    pub last_attack_day: i64,   // 8 bytes - UTC day of the last reveal that hit, one per day
+    pub bump: u8,               // 1 byte
+}
+
+impl BossContribution {
+    pub const SEED: &'static [u8] = seeds::BOSS_CONTRIBUTION;
+    pub const MAXIMUM_SIZE: usize = 32 + 32 + 8 + 1 + 8 + 1;
+}
+
+/// Daily raid that anyone can spawn once its UTC hour has passed, so raids
+/// come at predictable times without an admin online.
+#[account]
+#[derive(Default)]
+pub struct RaidSchedule {
+    pub spawn_hour: u8,         // 1 byte - UTC hour after which the day's boss can spawn
+    pub hp: u64,                // 8 bytes
+    pub loot_clicks: u64,       // 8 bytes
+    pub lifetime: i64,          // 8 bytes - seconds a scheduled boss stays, 0 until despawned
+    pub next_boss_id: u32,      // 4 bytes - scheduled bosses take consecutive ids from here
+    pub last_spawn_day: i64,    // 8 bytes
+    pub enabled: bool,          // 1 byte
+    pub bump: u8,               // 1 byte
+}
+
+impl RaidSchedule {
+    pub const SEED: &'static [u8] = seeds::RAID_SCHEDULE;
+    pub const MAXIMUM_SIZE: usize = 1 + 8 + 8 + 8 + 4 + 8 + 1 + 1;
+
+    /// True if the day's boss is due at `now` and hasn't spawned yet.
+    pub fn is_due(&self, now: i64) -> bool {
+        let day = now.div_euclid(SECONDS_PER_DAY);
+        self.enabled
+            && day > self.last_spawn_day
+            && now.rem_euclid(SECONDS_PER_DAY) >= self.spawn_hour as i64 * 3600
+    }
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+use clicker_types::seeds;
+
+/// Most clicks revealed in a single session by anyone, updated by end_session
+/// whenever a reveal beats it.
+#[account]
+#[derive(Default)]
+pub struct WorldRecord {
+    pub clicks: u32,            // 4 bytes
+    pub game: Pubkey,           // 32 bytes - holder's game, default key until the first record
+    pub player: Pubkey,         // 32 bytes
+    pub session: Pubkey,        // 32 bytes
+    pub set_at: i64,            // 8 bytes
+    pub bump: u8,               // 1 byte
+}
+
+impl WorldRecord {
+    pub const SEED: &'static [u8] = seeds::WORLD_RECORD;
+    pub const MAXIMUM_SIZE: usize = 4 + 32 + 32 + 32 + 8 + 1;
+
+    pub fn has_holder(&self) -> bool {
+        self.game != Pubkey::default()
+    }
+}
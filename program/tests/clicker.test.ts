@@ -23,6 +23,7 @@ describe("clicker", () => {
       .accounts({
         game: gameKeypair.publicKey,
         player: player.publicKey,
+        payer: player.publicKey,
       })
       .signers([gameKeypair])
       .rpc();
@@ -43,6 +44,7 @@ describe("clicker", () => {
       .accounts({
         game: gameKeypair.publicKey,
         player: player.publicKey,
+        payer: player.publicKey,
       })
       .signers([gameKeypair])
       .rpc();
@@ -62,4 +64,114 @@ describe("clicker", () => {
     gameState = await program.account.game.fetch(gameKeypair.publicKey);
     expect(gameState.clicks).to.equal(1);
   });
+
+  it("soft resets only with the typed confirmation", async () => {
+    const gameKeypair = anchor.web3.Keypair.generate();
+    const player = programProvider.wallet;
+
+    await program.methods
+      .initialize()
+      .accounts({
+        game: gameKeypair.publicKey,
+        player: player.publicKey,
+        payer: player.publicKey,
+      })
+      .signers([gameKeypair])
+      .rpc();
+
+    await program.methods
+      .click()
+      .accounts({
+        game: gameKeypair.publicKey,
+      })
+      .rpc();
+
+    const softReset = (confirmation: string) =>
+      program.methods
+        .softResetGame(confirmation)
+        .accounts({
+          game: gameKeypair.publicKey,
+          player: player.publicKey,
+        })
+        .rpc();
+
+    await expect(softReset("reset")).to.be.rejectedWith(/ConfirmationMismatch/);
+    let gameState = await program.account.game.fetch(gameKeypair.publicKey);
+    expect(gameState.clicks).to.equal(1);
+
+    await softReset("reset my progress");
+    gameState = await program.account.game.fetch(gameKeypair.publicKey);
+    expect(gameState.clicks).to.equal(0);
+    expect(gameState.player).to.eql(player.publicKey);
+  });
+
+  it("binds an identity hash and holds it through the cooldown", async () => {
+    const gameKeypair = anchor.web3.Keypair.generate();
+    const player = programProvider.wallet;
+
+    await program.methods
+      .initialize()
+      .accounts({
+        game: gameKeypair.publicKey,
+        player: player.publicKey,
+        payer: player.publicKey,
+      })
+      .signers([gameKeypair])
+      .rpc();
+
+    const accounts = { game: gameKeypair.publicKey, player: player.publicKey };
+    const hash = Array.from({ length: 32 }, (_, i) => i + 1);
+    await program.methods.bindIdentity(hash).accounts(accounts).rpc();
+
+    const gameState = await program.account.game.fetch(gameKeypair.publicKey);
+    expect(gameState.identityHash).to.eql(hash);
+
+    await expect(
+      program.methods.bindIdentity(hash).accounts(accounts).rpc()
+    ).to.be.rejectedWith(/IdentityAlreadyBound/);
+    await expect(
+      program.methods.unbindIdentity().accounts(accounts).rpc()
+    ).to.be.rejectedWith(/IdentityCooldown/);
+  });
+
+  it("attests total clicks as return data and on the attestation account", async () => {
+    const gameKeypair = anchor.web3.Keypair.generate();
+    const player = programProvider.wallet;
+
+    await program.methods
+      .initialize()
+      .accounts({
+        game: gameKeypair.publicKey,
+        player: player.publicKey,
+        payer: player.publicKey,
+      })
+      .signers([gameKeypair])
+      .rpc();
+
+    const [attestation] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("attestation"), gameKeypair.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .initializeAttestation()
+      .accounts({ game: gameKeypair.publicKey, attestation, payer: player.publicKey })
+      .rpc();
+
+    const returned = await program.methods
+      .attestTotalClicks()
+      .accounts({ game: gameKeypair.publicKey, attestation: null })
+      .view();
+    expect(returned.player).to.eql(player.publicKey);
+    expect(returned.totalClicks.toNumber()).to.equal(0);
+    expect(returned.createdAtSlot.toNumber()).to.be.greaterThan(0);
+    expect(returned.firstSessionSlot.toNumber()).to.equal(0);
+
+    await program.methods
+      .attestTotalClicks()
+      .accounts({ game: gameKeypair.publicKey, attestation })
+      .rpc();
+    const recorded = await program.account.attestation.fetch(attestation);
+    expect(recorded.game).to.eql(gameKeypair.publicKey);
+    expect(recorded.slot.toNumber()).to.be.greaterThan(0);
+  });
 });
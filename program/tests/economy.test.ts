@@ -0,0 +1,286 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Clicker } from "../target/types/clicker";
+
+import chai from "chai";
+import chaiAsPromised from "chai-as-promised";
+import { expect } from "chai";
+chai.use(chaiAsPromised);
+
+// Feature bits, mirroring Config in types/src/config.rs
+const FREEZE_ENABLED = 1 << 5;
+const GEMS_ENABLED = 1 << 10;
+const MARKETPLACE_ENABLED = 1 << 18;
+const TROPHY_CABINET_ENABLED = 1 << 24;
+
+// Preloaded from tests/fixtures/trophy-*.json: a token account holding one
+// TROPHY_MINT for the keypair seeded with 32 bytes of 7, and the mint's
+// metadata, verified in TROPHY_COLLECTION
+const trophyHolder = anchor.web3.Keypair.fromSeed(Uint8Array.from(Array(32).fill(7)));
+const TROPHY_MINT = new anchor.web3.PublicKey("3L7GfJmbLBC9h9WqVViCnmomBksW1PFgc7Z94tNJ4U5L");
+const TROPHY_COLLECTION = new anchor.web3.PublicKey("CJXDvyD8MbM64k1kLEfD8gZMddCuKtbaWhfAhFSHtqH");
+const TROPHY_TOKEN_ACCOUNT = new anchor.web3.PublicKey(
+  "EjsNGi5ba6LrGK1EnFs9xtfzg6Y7MZd5eTe7Wp9A5xHP"
+);
+const TROPHY_METADATA = new anchor.web3.PublicKey("Ha28jAwEzfMAygQxYv56ye7MXZ9Dym8D9jQ4iByiesh4");
+
+describe("economy", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.Clicker as Program<Clicker>;
+  const programProvider = program.provider as anchor.AnchorProvider;
+  const player = programProvider.wallet;
+
+  const pda = (...seeds: Buffer[]) =>
+    anchor.web3.PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+  const u64 = (value: number | anchor.BN) => new anchor.BN(value).toArrayLike(Buffer, "le", 8);
+
+  const config = pda(Buffer.from("config"));
+
+  before(async () => {
+    if (!(await programProvider.connection.getAccountInfo(config))) {
+      const [programData] = anchor.web3.PublicKey.findProgramAddressSync(
+        [program.programId.toBuffer()],
+        new anchor.web3.PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+      );
+      await program.methods
+        .initializeConfig(player.publicKey, 1) // Config::CLUSTER_LOCALNET
+        .accountsPartial({
+          admin: player.publicKey,
+          program: program.programId,
+          programData,
+        })
+        .rpc();
+    }
+  });
+
+  // Runs `body` with `features` switched on as well, then puts the config's
+  // features back. The test wallet is the config admin, see before().
+  async function withFeatures(features: number, body: () => Promise<void>) {
+    const setFeatures = (value: anchor.BN) =>
+      program.methods
+        .updateConfig({ features: value } as any)
+        .accountsPartial({ config, admin: player.publicKey })
+        .rpc();
+    const previous = (await program.account.config.fetch(config)).features as anchor.BN;
+    await setFeatures(previous.or(new anchor.BN(features)));
+    try {
+      await body();
+    } finally {
+      await setFeatures(previous);
+    }
+  }
+
+  async function newGame(): Promise<anchor.web3.PublicKey> {
+    const gameKeypair = anchor.web3.Keypair.generate();
+    await program.methods
+      .initialize()
+      .accountsPartial({
+        game: gameKeypair.publicKey,
+        player: player.publicKey,
+        payer: player.publicKey,
+      })
+      .signers([gameKeypair])
+      .rpc();
+    return gameKeypair.publicKey;
+  }
+
+  async function newInventory(game: anchor.web3.PublicKey): Promise<anchor.web3.PublicKey> {
+    const inventory = pda(Buffer.from("inventory"), game.toBuffer());
+    await program.methods
+      .initializeInventory()
+      .accountsPartial({ game, inventory, payer: player.publicKey })
+      .rpc();
+    return inventory;
+  }
+
+  it("only transfers items into an inventory that accepts them", async () => {
+    const game = await newGame();
+    const inventory = await newInventory(game);
+    const otherGame = await newGame();
+    const otherInventory = await newInventory(otherGame);
+    const transfer = () =>
+      program.methods
+        .transferItem(0, 1)
+        .accountsPartial({ game, player: player.publicKey, inventory, toInventory: otherInventory })
+        .rpc();
+
+    await expect(transfer()).to.be.rejectedWith(/TransfersNotAccepted/);
+
+    await program.methods
+      .setAcceptsTransfers(true)
+      .accountsPartial({ game: otherGame, player: player.publicKey, inventory: otherInventory })
+      .rpc();
+    // past the opt-in, the empty slot is what stops it
+    await expect(transfer()).to.be.rejectedWith(/InsufficientItems/);
+  });
+
+  it("keeps a frozen game's items where they are", async () => {
+    const game = await newGame();
+    const inventory = await newInventory(game);
+    const otherInventory = await newInventory(await newGame());
+
+    await withFeatures(FREEZE_ENABLED | MARKETPLACE_ENABLED | GEMS_ENABLED, async () => {
+      await program.methods
+        .freezeGame(false)
+        .accountsPartial({ game, player: player.publicKey, config })
+        .rpc();
+
+      await expect(
+        program.methods
+          .transferItem(0, 1)
+          .accountsPartial({
+            game,
+            player: player.publicKey,
+            inventory,
+            toInventory: otherInventory,
+          })
+          .rpc()
+      ).to.be.rejectedWith(/GameFrozen/);
+
+      await expect(
+        program.methods
+          .listItem(0, 1, new anchor.BN(1))
+          .accountsPartial({
+            game,
+            player: player.publicKey,
+            config,
+            inventory,
+            listing: pda(Buffer.from("listing"), game.toBuffer(), u64(0)),
+          })
+          .rpc()
+      ).to.be.rejectedWith(/GameFrozen/);
+
+      await expect(
+        program.methods
+          .spendGems({ cosmetic: { id: 0 } })
+          .accountsPartial({ game, player: player.publicKey, config })
+          .rpc()
+      ).to.be.rejectedWith(/GameFrozen/);
+    });
+  });
+
+  async function newTrophyCabinet(
+    playerKeypair: anchor.web3.Keypair | null
+  ): Promise<{ game: anchor.web3.PublicKey; trophyCabinet: anchor.web3.PublicKey }> {
+    const gameKeypair = anchor.web3.Keypair.generate();
+    const gamePlayer = playerKeypair ? playerKeypair.publicKey : player.publicKey;
+    const signers = playerKeypair ? [playerKeypair] : [];
+    await program.methods
+      .initialize()
+      .accountsPartial({
+        game: gameKeypair.publicKey,
+        player: gamePlayer,
+        payer: player.publicKey,
+      })
+      .signers([gameKeypair, ...signers])
+      .rpc();
+    const game = gameKeypair.publicKey;
+    const trophyCabinet = pda(Buffer.from("trophy_cabinet"), game.toBuffer());
+    await program.methods
+      .initializeTrophyCabinet()
+      .accountsPartial({ game, player: gamePlayer, trophyCabinet, payer: player.publicKey })
+      .signers(signers)
+      .rpc();
+    return { game, trophyCabinet };
+  }
+
+  const setTrophyCollection = (trophyCollection: anchor.web3.PublicKey) =>
+    program.methods
+      .updateConfig({ trophyCollection } as any)
+      .accountsPartial({ config, admin: player.publicKey })
+      .rpc();
+
+  it("pins, reverifies and unpins a trophy the player holds", async () => {
+    const { game, trophyCabinet } = await newTrophyCabinet(trophyHolder);
+    const trophyAccounts = {
+      trophyCabinet,
+      config,
+      tokenAccount: TROPHY_TOKEN_ACCOUNT,
+      metadata: TROPHY_METADATA,
+    };
+    const pin = (slot: number) =>
+      program.methods
+        .pinTrophy(slot, TROPHY_MINT)
+        .accountsPartial({ game, player: trophyHolder.publicKey, ...trophyAccounts })
+        .signers([trophyHolder])
+        .rpc();
+    const unpin = (slot: number) =>
+      program.methods
+        .unpinTrophy(slot)
+        .accountsPartial({ game, player: trophyHolder.publicKey, trophyCabinet })
+        .signers([trophyHolder])
+        .rpc();
+    // permissionless, it only ever proves ownership
+    const reverify = () =>
+      program.methods
+        .reverifyTrophy(TROPHY_MINT)
+        .accountsPartial({ game, ...trophyAccounts })
+        .rpc();
+
+    await setTrophyCollection(TROPHY_COLLECTION);
+    await expect(pin(0)).to.be.rejectedWith(/FeatureDisabled/);
+
+    await withFeatures(TROPHY_CABINET_ENABLED, async () => {
+      // TrophyCabinet::MAX_TROPHIES
+      await expect(pin(8)).to.be.rejectedWith(/InvalidTrophySlot/);
+
+      await pin(2);
+
+      let cabinet = await program.account.trophyCabinet.fetch(trophyCabinet);
+      expect(cabinet.trophies[2].mint.equals(TROPHY_MINT)).to.be.true;
+      const pinnedAt = cabinet.trophies[2].verifiedAt.toNumber();
+      expect(pinnedAt).to.be.greaterThan(0);
+
+      // a mint sits in one slot only
+      await expect(pin(3)).to.be.rejectedWith(/TrophyAlreadyPinned/);
+
+      await reverify();
+      cabinet = await program.account.trophyCabinet.fetch(trophyCabinet);
+      expect(cabinet.trophies[2].verifiedAt.toNumber()).to.be.at.least(pinnedAt);
+
+      await unpin(2);
+
+      cabinet = await program.account.trophyCabinet.fetch(trophyCabinet);
+      expect(cabinet.trophies[2].mint.equals(anchor.web3.PublicKey.default)).to.be.true;
+      await expect(unpin(2)).to.be.rejectedWith(/TrophySlotEmpty/);
+      await expect(reverify()).to.be.rejectedWith(/TrophySlotEmpty/);
+    });
+  });
+
+  it("only pins trophies held by the player and verified in the collection", async () => {
+    const holderCabinet = await newTrophyCabinet(trophyHolder);
+    const otherCabinet = await newTrophyCabinet(null);
+    const pin = (
+      cabinet: { game: anchor.web3.PublicKey; trophyCabinet: anchor.web3.PublicKey },
+      signer: anchor.web3.Keypair | null
+    ) =>
+      program.methods
+        .pinTrophy(0, TROPHY_MINT)
+        .accountsPartial({
+          game: cabinet.game,
+          player: signer ? signer.publicKey : player.publicKey,
+          trophyCabinet: cabinet.trophyCabinet,
+          config,
+          tokenAccount: TROPHY_TOKEN_ACCOUNT,
+          metadata: TROPHY_METADATA,
+        })
+        .signers(signer ? [signer] : [])
+        .rpc();
+
+    await withFeatures(TROPHY_CABINET_ENABLED, async () => {
+      await setTrophyCollection(TROPHY_COLLECTION);
+      // the token account belongs to trophyHolder, not the test wallet
+      await expect(pin(otherCabinet, null)).to.be.rejectedWith(/NftNotHeld/);
+
+      await setTrophyCollection(anchor.web3.Keypair.generate().publicKey);
+      try {
+        await expect(pin(holderCabinet, trophyHolder)).to.be.rejectedWith(
+          /CollectionNotVerified/
+        );
+      } finally {
+        await setTrophyCollection(TROPHY_COLLECTION);
+      }
+    });
+  });
+});
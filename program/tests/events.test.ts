@@ -0,0 +1,86 @@
+import { readFileSync } from "fs";
+import { join } from "path";
+
+import { expect } from "chai";
+
+// Raw IDL from `anchor build`, before the client camel-cases the names
+const idl = JSON.parse(
+  readFileSync(join(__dirname, "../target/idl/clicker.json"), "utf8")
+);
+
+// Snapshot of every event as of the schema version it records. Regenerate it
+// together with an EVENT_SCHEMA_VERSION bump whenever an event changes shape.
+const schema = JSON.parse(
+  readFileSync(join(__dirname, "fixtures/event-schema.json"), "utf8")
+);
+
+// Borsh layout of a type with nested definitions expanded, in the same shape
+// as the fixture so the two can be compared directly
+function layout(type: any): any {
+  if (typeof type === "string") {
+    return type;
+  }
+  if (type.defined) {
+    const def = idl.types.find((t: any) => t.name === type.defined.name).type;
+    if (def.kind === "struct") {
+      return [
+        "struct",
+        (def.fields ?? []).map((f: any) => [f.name, layout(f.type)]),
+      ];
+    }
+    return [
+      "enum",
+      def.variants.map((v: any) => [
+        v.name,
+        ...(v.fields ?? []).map((f: any) => layout(f.type ?? f)),
+      ]),
+    ];
+  }
+  if (type.option) {
+    return ["option", layout(type.option)];
+  }
+  if (type.vec) {
+    return ["vec", layout(type.vec)];
+  }
+  if (type.array) {
+    return ["array", layout(type.array[0]), type.array[1]];
+  }
+  throw new Error(`unhandled IDL type ${JSON.stringify(type)}`);
+}
+
+describe("event schema", () => {
+  const version = Number(
+    idl.constants.find((c: any) => c.name === "EVENT_SCHEMA_VERSION").value
+  );
+
+  it("starts every event with schema_version", () => {
+    for (const event of idl.events) {
+      const [first] = layout({ defined: { name: event.name } })[1];
+      expect(first, event.name).to.eql(["schema_version", "u8"]);
+    }
+  });
+
+  it("keeps discriminators stable", () => {
+    for (const event of idl.events) {
+      const recorded = schema.events[event.name];
+      if (recorded) {
+        expect(event.discriminator, event.name).to.eql(recorded.discriminator);
+      }
+    }
+  });
+
+  it("bumps EVENT_SCHEMA_VERSION when a layout changes", () => {
+    expect(version).to.be.at.least(schema.schemaVersion);
+    if (version > schema.schemaVersion) {
+      return;
+    }
+
+    for (const event of idl.events) {
+      const recorded = schema.events[event.name];
+      expect(recorded, `${event.name} missing from event-schema.json`).to.exist;
+      expect(layout({ defined: { name: event.name } }), event.name).to.eql(
+        recorded.layout
+      );
+    }
+  });
+});
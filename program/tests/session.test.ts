@@ -0,0 +1,1431 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Clicker } from "../target/types/clicker";
+import { createHash } from "crypto";
+import { readFileSync, writeFileSync } from "fs";
+import { join } from "path";
+
+import chai from "chai";
+import chaiAsPromised from "chai-as-promised";
+import { expect } from "chai";
+chai.use(chaiAsPromised);
+
+type SessionResult = {
+  clicks: number;
+  combos: number;
+  maxCombo: number;
+  maxCpsObserved: number;
+  misses: number;
+  minuteClicks: number[];
+};
+
+const HISTOGRAM_MINUTES = 30;
+
+// Per-minute histogram with the given leading minutes, zero after them
+function minutes(...leading: number[]): number[] {
+  return [...leading, ...Array(HISTOGRAM_MINUTES - leading.length).fill(0)];
+}
+
+// A plain reveal: no combos or misses, peaking at the base 10 CPS cap, all
+// within the first minute
+function result(clicks: number, fields: Partial<SessionResult> = {}): SessionResult {
+  return {
+    clicks,
+    combos: 0,
+    maxCombo: 0,
+    maxCpsObserved: 10,
+    misses: 0,
+    minuteClicks: minutes(clicks),
+    ...fields,
+  };
+}
+
+// sha256(borsh(result) || nonce as u64 LE || player)
+function commitment(
+  result: SessionResult,
+  nonce: anchor.BN,
+  player: anchor.web3.PublicKey
+): number[] {
+  const resultBytes = Buffer.alloc(18 + 2 * HISTOGRAM_MINUTES);
+  resultBytes.writeUInt32LE(result.clicks, 0);
+  resultBytes.writeUInt32LE(result.combos, 4);
+  resultBytes.writeUInt32LE(result.maxCombo, 8);
+  resultBytes.writeUInt16LE(result.maxCpsObserved, 12);
+  resultBytes.writeUInt32LE(result.misses, 14);
+  result.minuteClicks.forEach((clicks, minute) =>
+    resultBytes.writeUInt16LE(clicks, 18 + 2 * minute)
+  );
+  const preimage = Buffer.concat([
+    resultBytes,
+    nonce.toArrayLike(Buffer, "le", 8),
+    player.toBuffer(),
+  ]);
+  return Array.from(createHash("sha256").update(preimage).digest());
+}
+
+// Compute a plain end_session measured on the local validator, self-CPI
+// included. A null entry is recorded by the next run; after that a reveal
+// may use at most CU_HEADROOM more. Set it back to null to re-measure after
+// a change that is meant to cost more.
+const COMPUTE_UNITS_PATH = join(__dirname, "fixtures/compute-units.json");
+const CU_HEADROOM = 1.1;
+
+// Mirrors RevealDiagnostics in diagnostics.rs: "diag" tag, then Borsh fields
+function decodeRevealDiagnostics(data: Buffer) {
+  if (data.subarray(0, 4).toString() !== "diag") {
+    return null;
+  }
+  return {
+    expectedCommitment: Array.from(data.subarray(4, 36)),
+    providedHash: Array.from(data.subarray(36, 68)),
+    clicks: data.readUInt32LE(68),
+    maxClicks: data.readBigUInt64LE(72),
+    sessionDuration: data.readBigInt64LE(80),
+    maxSessionDuration: data.readBigInt64LE(88),
+  };
+}
+
+const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+const u64 = (value: number | anchor.BN) => new anchor.BN(value).toArrayLike(Buffer, "le", 8);
+
+// Feature bits, mirroring Config in types/src/config.rs
+const RAFFLES_ENABLED = 1 << 0;
+const VRF_ENABLED = 1 << 1;
+const GAMBLE_ENABLED = 1 << 22;
+const FREEZE_ENABLED = 1 << 5;
+const GIFTING_ENABLED = 1 << 27;
+const SHOUTBOX_ENABLED = 1 << 28;
+
+describe("sessions", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.Clicker as Program<Clicker>;
+  const programProvider = program.provider as anchor.AnchorProvider;
+  const player = programProvider.wallet;
+
+  const [globalStats] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("global_stats")],
+    program.programId
+  );
+
+  const [config] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+
+  before(async () => {
+    const connection = programProvider.connection;
+
+    if (!(await connection.getAccountInfo(config))) {
+      const [programData] = anchor.web3.PublicKey.findProgramAddressSync(
+        [program.programId.toBuffer()],
+        new anchor.web3.PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+      );
+      await program.methods
+        .initializeConfig(player.publicKey, 1) // Config::CLUSTER_LOCALNET
+        .accountsPartial({
+          admin: player.publicKey,
+          program: program.programId,
+          programData,
+        })
+        .rpc();
+    }
+
+    if (!(await connection.getAccountInfo(globalStats))) {
+      await program.methods
+        .initializeGlobalStats()
+        .accountsPartial({ config, admin: player.publicKey })
+        .rpc();
+    }
+  });
+
+  async function newGame(hardcore = false): Promise<anchor.web3.Keypair> {
+    const gameKeypair = anchor.web3.Keypair.generate();
+    await (hardcore ? program.methods.initializeHardcore() : program.methods.initialize())
+      .accountsPartial({
+        game: gameKeypair.publicKey,
+        player: player.publicKey,
+        payer: player.publicKey,
+      })
+      .signers([gameKeypair])
+      .rpc();
+    return gameKeypair;
+  }
+
+  // Runs `body` with `features` switched on as well, then puts the config's
+  // features back. The test wallet is the config admin, see before().
+  async function withFeatures(features: number, body: () => Promise<void>) {
+    const setFeatures = (value: anchor.BN) =>
+      program.methods
+        .updateConfig({ features: value } as any)
+        .accountsPartial({ config, admin: player.publicKey })
+        .rpc();
+    const previous = (await program.account.config.fetch(config)).features as anchor.BN;
+    await setFeatures(previous.or(new anchor.BN(features)));
+    try {
+      await body();
+    } finally {
+      await setFeatures(previous);
+    }
+  }
+
+  // Starts a session and reveals `clicks` in it, waiting long enough for
+  // the base 10 CPS cap. Tickets go to `raffle` if one is given.
+  async function playSession(
+    game: anchor.web3.PublicKey,
+    clicks: number,
+    raffle: anchor.web3.PublicKey | null = null
+  ): Promise<anchor.web3.PublicKey> {
+    const sessionKeypair = anchor.web3.Keypair.generate();
+    const nonce = new anchor.BN(1);
+    await program.methods
+      .startSession(commitment(result(clicks), nonce, player.publicKey), null)
+      .accountsPartial({
+        game,
+        session: sessionKeypair.publicKey,
+        player: player.publicKey,
+        payer: player.publicKey,
+      })
+      .signers([sessionKeypair])
+      .rpc();
+
+    await sleep(Math.ceil(clicks / 10) * 1000 + 1000);
+
+    await program.methods
+      .endSession(result(clicks), nonce, new anchor.BN(3600))
+      .accountsPartial({
+        game,
+        session: sessionKeypair.publicKey,
+        player: player.publicKey,
+        config,
+        globalStats,
+        raffle,
+        device: null,
+        sessionArchive: null,
+        worldRecord: null,
+        previousRecordHolder: null,
+        mentorship: null,
+        worldBoss: null,
+        bossContribution: null,
+        coopSession: null,
+        goldenWindow: null,
+        partnerCollection: null,
+        season: null,
+        weeklyChallenge: null,
+        challengeEntry: null,
+        instructions: null,
+      })
+      .rpc();
+    return sessionKeypair.publicKey;
+  }
+
+  // The oracle's Ed25519 instruction over `seed` and the random value it
+  // yields as 8 LE bytes, mirroring oracle.rs. The test wallet is the
+  // oracle, see before().
+  function oracleDraw(seed: Buffer) {
+    const oracle = (programProvider.wallet as anchor.Wallet).payer;
+    const ix = anchor.web3.Ed25519Program.createInstructionWithPrivateKey({
+      privateKey: oracle.secretKey,
+      message: seed,
+    });
+    // 16 bytes of offsets, then the public key, then the signature
+    const signature = ix.data.subarray(48, 112);
+    const digest = createHash("sha256")
+      .update(Buffer.concat([Buffer.from("clicker-vrf"), signature]))
+      .digest();
+    return { ix, randomness: digest.subarray(0, 8) };
+  }
+
+  it("credits revealed clicks", async () => {
+    const gameKeypair = await newGame();
+    const sessionKeypair = anchor.web3.Keypair.generate();
+    const nonce = new anchor.BN(42);
+
+    await program.methods
+      .startSession(commitment(result(15), nonce, player.publicKey), null)
+      .accountsPartial({
+        game: gameKeypair.publicKey,
+        session: sessionKeypair.publicKey,
+        player: player.publicKey,
+        payer: player.publicKey,
+      })
+      .signers([sessionKeypair])
+      .rpc();
+
+    await sleep(3000);
+
+    await program.methods
+      .endSession(result(15), nonce, new anchor.BN(3600))
+      .accountsPartial({
+        game: gameKeypair.publicKey,
+        session: sessionKeypair.publicKey,
+        player: player.publicKey,
+        config,
+        globalStats,
+        raffle: null,
+        device: null,
+        sessionArchive: null,
+        worldRecord: null,
+        previousRecordHolder: null,
+        mentorship: null,
+        worldBoss: null,
+        bossContribution: null,
+        coopSession: null,
+        goldenWindow: null,
+        partnerCollection: null,
+        season: null,
+        weeklyChallenge: null,
+        challengeEntry: null,
+        instructions: null,
+      })
+      .rpc();
+
+    const gameState = await program.account.game.fetch(gameKeypair.publicKey);
+    expect(gameState.lifetimeClicks.toNumber()).to.equal(15);
+    // FLAG_ACTIVE_SESSION is cleared and the key zeroed
+    expect(gameState.flags & 1).to.equal(0);
+    expect(gameState.activeSession.equals(anchor.web3.PublicKey.default)).to.be.true;
+    // a first reveal sets every personal record
+    expect(gameState.bestCps).to.equal(10);
+    expect(gameState.bestSessionClicks).to.equal(15);
+    expect(gameState.bestDayClicks.toNumber()).to.equal(15);
+    expect(gameState.reputation).to.equal(1);
+
+    const sessionState = await program.account.session.fetch(
+      sessionKeypair.publicKey
+    );
+    expect(sessionState.result).to.eql(result(15));
+  });
+
+  it("records when the session expires", async () => {
+    const gameKeypair = await newGame();
+    const sessionKeypair = anchor.web3.Keypair.generate();
+
+    await program.methods
+      .startSession(commitment(result(1), new anchor.BN(5), player.publicKey), null)
+      .accountsPartial({
+        game: gameKeypair.publicKey,
+        session: sessionKeypair.publicKey,
+        player: player.publicKey,
+        payer: player.publicKey,
+      })
+      .signers([sessionKeypair])
+      .rpc();
+
+    // start_time + max_session_duration, or i64::MAX when the config has no limit
+    const configState = await program.account.config.fetch(config);
+    const session = await program.account.session.fetch(sessionKeypair.publicKey);
+    const expected = configState.maxSessionDuration.gtn(0)
+      ? session.startTime.add(configState.maxSessionDuration)
+      : new anchor.BN("9223372036854775807");
+    expect(session.deadline.toString()).to.equal(expected.toString());
+  });
+
+  it("keeps paused time out of the session", async () => {
+    const gameKeypair = await newGame();
+    const sessionKeypair = anchor.web3.Keypair.generate();
+    const accounts = {
+      session: sessionKeypair.publicKey,
+      player: player.publicKey,
+    };
+
+    await program.methods
+      .startSession(commitment(result(1), new anchor.BN(6), player.publicKey), null)
+      .accountsPartial({ ...accounts, game: gameKeypair.publicKey, payer: player.publicKey })
+      .signers([sessionKeypair])
+      .rpc();
+
+    await program.methods.pauseSession().accountsPartial(accounts).rpc();
+    await expect(
+      program.methods.pauseSession().accountsPartial(accounts).rpc()
+    ).to.be.rejectedWith(/SessionPaused/);
+
+    await sleep(2000);
+    await program.methods.resumeSession().accountsPartial(accounts).rpc();
+
+    const session = await program.account.session.fetch(sessionKeypair.publicKey);
+    expect(session.pausedAt.toNumber()).to.equal(0);
+    expect(session.pausedTotal.toNumber()).to.be.at.least(1);
+
+    await expect(
+      program.methods.resumeSession().accountsPartial(accounts).rpc()
+    ).to.be.rejectedWith(/SessionNotPaused/);
+
+    // a session that is no longer active can't be paused
+    await program.methods
+      .cancelSession()
+      .accountsPartial({ ...accounts, game: gameKeypair.publicKey })
+      .rpc();
+    await expect(
+      program.methods.pauseSession().accountsPartial(accounts).rpc()
+    ).to.be.rejectedWith(/SessionAlreadyRevealed/);
+  });
+
+  it("lets the admin void a stuck session", async () => {
+    const gameKeypair = await newGame();
+    const sessionKeypair = anchor.web3.Keypair.generate();
+
+    await program.methods
+      .startSession(commitment(result(1), new anchor.BN(11), player.publicKey), null)
+      .accountsPartial({
+        game: gameKeypair.publicKey,
+        session: sessionKeypair.publicKey,
+        player: player.publicKey,
+        payer: player.publicKey,
+      })
+      .signers([sessionKeypair])
+      .rpc();
+
+    // the test wallet is the config admin, see before()
+    await program.methods
+      .adminVoidSession({ clientBug: {} })
+      .accountsPartial({
+        config,
+        admin: player.publicKey,
+        game: gameKeypair.publicKey,
+        session: sessionKeypair.publicKey,
+      })
+      .rpc();
+
+    const gameState = await program.account.game.fetch(gameKeypair.publicKey);
+    expect(gameState.flags & 1).to.equal(0);
+    expect(gameState.activeSession.equals(anchor.web3.PublicKey.default)).to.be.true;
+    const session = await program.account.session.fetch(sessionKeypair.publicKey);
+    expect(session.revealed).to.be.true;
+    expect(session.voidReason).to.eql({ clientBug: {} });
+  });
+
+  it("refunds session rent to the sponsor that paid it", async () => {
+    const connection = programProvider.connection;
+    const gameKeypair = await newGame();
+    const sessionKeypair = anchor.web3.Keypair.generate();
+    const sponsor = anchor.web3.Keypair.generate();
+    await connection.confirmTransaction(
+      await connection.requestAirdrop(sponsor.publicKey, anchor.web3.LAMPORTS_PER_SOL),
+      "confirmed"
+    );
+
+    await program.methods
+      .startSession(commitment(result(1), new anchor.BN(12), player.publicKey), null)
+      .accountsPartial({
+        game: gameKeypair.publicKey,
+        session: sessionKeypair.publicKey,
+        player: player.publicKey,
+        payer: sponsor.publicKey,
+      })
+      .signers([sessionKeypair, sponsor])
+      .rpc();
+    const session = await program.account.session.fetch(sessionKeypair.publicKey);
+    expect(session.rentPayer.equals(sponsor.publicKey)).to.be.true;
+
+    const closeAccounts = {
+      session: sessionKeypair.publicKey,
+      rentPayer: sponsor.publicKey,
+      authority: player.publicKey,
+      raffle: null,
+    };
+    await expect(
+      program.methods.closeSession().accountsPartial(closeAccounts).rpc()
+    ).to.be.rejectedWith(/SessionNotFinished/);
+
+    await program.methods
+      .cancelSession()
+      .accountsPartial({
+        game: gameKeypair.publicKey,
+        session: sessionKeypair.publicKey,
+        player: player.publicKey,
+      })
+      .rpc();
+
+    const rent = await connection.getBalance(sessionKeypair.publicKey);
+    const sponsorBefore = await connection.getBalance(sponsor.publicKey);
+    await program.methods.closeSession().accountsPartial(closeAccounts).rpc();
+
+    expect(await connection.getAccountInfo(sessionKeypair.publicKey)).to.be.null;
+    expect(await connection.getBalance(sponsor.publicKey)).to.equal(sponsorBefore + rent);
+  });
+
+  it("cancels and closes sessions with every feature paused", async () => {
+    // the test wallet is the config admin, see before()
+    const setFeatures = (features: anchor.BN) =>
+      program.methods
+        .updateConfig({ features } as any)
+        .accountsPartial({ config, admin: player.publicKey })
+        .rpc();
+    const { features } = await program.account.config.fetch(config);
+    await setFeatures(new anchor.BN(0));
+
+    try {
+      const gameKeypair = await newGame();
+      const sessionKeypair = anchor.web3.Keypair.generate();
+      await program.methods
+        .startSession(commitment(result(1), new anchor.BN(15), player.publicKey), null)
+        .accountsPartial({
+          game: gameKeypair.publicKey,
+          session: sessionKeypair.publicKey,
+          player: player.publicKey,
+          payer: player.publicKey,
+        })
+        .signers([sessionKeypair])
+        .rpc();
+
+      // the pause-exempt exits
+      await program.methods
+        .cancelSession()
+        .accountsPartial({
+          game: gameKeypair.publicKey,
+          session: sessionKeypair.publicKey,
+          player: player.publicKey,
+        })
+        .rpc();
+      await program.methods
+        .closeSession()
+        .accountsPartial({
+          session: sessionKeypair.publicKey,
+          rentPayer: player.publicKey,
+          authority: player.publicKey,
+          raffle: null,
+        })
+        .rpc();
+
+      expect(await programProvider.connection.getAccountInfo(sessionKeypair.publicKey)).to.be.null;
+    } finally {
+      await setFeatures(features);
+    }
+  });
+
+  it("hands the world record to a bigger reveal", async () => {
+    const [worldRecord] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("world_record")],
+      program.programId
+    );
+    await program.methods
+      .initializeWorldRecord()
+      .accountsPartial({ config, admin: player.publicKey })
+      .rpc();
+
+    async function play(clicks: number, previousRecordHolder: anchor.web3.PublicKey | null) {
+      const gameKeypair = await newGame();
+      const sessionKeypair = anchor.web3.Keypair.generate();
+      const nonce = new anchor.BN(clicks);
+      await program.methods
+        .startSession(commitment(result(clicks), nonce, player.publicKey), null)
+        .accountsPartial({
+          game: gameKeypair.publicKey,
+          session: sessionKeypair.publicKey,
+          player: player.publicKey,
+          payer: player.publicKey,
+        })
+        .signers([sessionKeypair])
+        .rpc();
+      await sleep(1000 + clicks * 100);
+      await program.methods
+        .endSession(result(clicks), nonce, new anchor.BN(3600))
+        .accountsPartial({
+          game: gameKeypair.publicKey,
+          session: sessionKeypair.publicKey,
+          player: player.publicKey,
+          config,
+          globalStats,
+          raffle: null,
+          device: null,
+          sessionArchive: null,
+          worldRecord,
+          previousRecordHolder,
+          mentorship: null,
+          worldBoss: null,
+          bossContribution: null,
+          coopSession: null,
+          goldenWindow: null,
+          partnerCollection: null,
+          season: null,
+          weeklyChallenge: null,
+          challengeEntry: null,
+          instructions: null,
+        })
+        .rpc();
+      return gameKeypair.publicKey;
+    }
+
+    const first = await play(5, null);
+    await expect(play(8, null)).to.be.rejectedWith(/RecordHolderRequired/);
+    const second = await play(8, first);
+
+    const record = await program.account.worldRecord.fetch(worldRecord);
+    expect(record.clicks).to.equal(8);
+    expect(record.game.toBase58()).to.equal(second.toBase58());
+    const firstState = await program.account.game.fetch(first);
+    expect(firstState.formerRecordHolder).to.be.true;
+  });
+
+  it("treats a repeated reveal as a no-op", async () => {
+    const gameKeypair = await newGame();
+    const sessionKeypair = anchor.web3.Keypair.generate();
+    const nonce = new anchor.BN(1234);
+
+    await program.methods
+      .startSession(commitment(result(12), nonce, player.publicKey), null)
+      .accountsPartial({
+        game: gameKeypair.publicKey,
+        session: sessionKeypair.publicKey,
+        player: player.publicKey,
+        payer: player.publicKey,
+      })
+      .signers([sessionKeypair])
+      .rpc();
+
+    await sleep(3000);
+
+    const reveal = () =>
+      program.methods.endSession(result(12), nonce, new anchor.BN(3600)).accountsPartial({
+        game: gameKeypair.publicKey,
+        session: sessionKeypair.publicKey,
+        player: player.publicKey,
+        config,
+        globalStats,
+        raffle: null,
+        device: null,
+        sessionArchive: null,
+        worldRecord: null,
+        previousRecordHolder: null,
+        mentorship: null,
+        worldBoss: null,
+        bossContribution: null,
+        coopSession: null,
+        goldenWindow: null,
+        partnerCollection: null,
+        season: null,
+        weeklyChallenge: null,
+        challengeEntry: null,
+        instructions: null,
+      });
+
+    await reveal().rpc();
+
+    // the retry succeeds and reports the credited clicks as return data
+    const tx = await reveal().transaction();
+    tx.feePayer = player.publicKey;
+    tx.recentBlockhash = (
+      await programProvider.connection.getLatestBlockhash()
+    ).blockhash;
+    const simulation = await programProvider.connection.simulateTransaction(tx);
+    expect(simulation.value.err).to.be.null;
+    const [data] = simulation.value.returnData.data;
+    const credited = new anchor.BN(Buffer.from(data, "base64").subarray(0, 8), "le");
+    expect(credited.toNumber()).to.equal(12);
+
+    const gameState = await program.account.game.fetch(gameKeypair.publicKey);
+    expect(gameState.lifetimeClicks.toNumber()).to.equal(12);
+  });
+
+  it("rejects a reveal that does not match the commitment", async () => {
+    const gameKeypair = await newGame();
+    const sessionKeypair = anchor.web3.Keypair.generate();
+    const nonce = new anchor.BN(7);
+
+    await program.methods
+      .startSession(commitment(result(10), nonce, player.publicKey), null)
+      .accountsPartial({
+        game: gameKeypair.publicKey,
+        session: sessionKeypair.publicKey,
+        player: player.publicKey,
+        payer: player.publicKey,
+      })
+      .signers([sessionKeypair])
+      .rpc();
+
+    const reveal = () =>
+      program.methods.endSession(result(11), nonce, new anchor.BN(3600)).accountsPartial({
+        game: gameKeypair.publicKey,
+        session: sessionKeypair.publicKey,
+        player: player.publicKey,
+        config,
+        globalStats,
+        raffle: null,
+        device: null,
+        sessionArchive: null,
+        worldRecord: null,
+        previousRecordHolder: null,
+        mentorship: null,
+        worldBoss: null,
+        bossContribution: null,
+        coopSession: null,
+        goldenWindow: null,
+        partnerCollection: null,
+        season: null,
+        weeklyChallenge: null,
+        challengeEntry: null,
+        instructions: null,
+      });
+
+    await expect(reveal().rpc()).to.be.rejectedWith(/InvalidCommitment/);
+
+    // the failure leaves diagnostics behind in return data
+    const tx = await reveal().transaction();
+    tx.feePayer = player.publicKey;
+    tx.recentBlockhash = (
+      await programProvider.connection.getLatestBlockhash()
+    ).blockhash;
+    const simulation = await programProvider.connection.simulateTransaction(tx);
+    const [data] = simulation.value.returnData.data;
+    const diagnostics = decodeRevealDiagnostics(Buffer.from(data, "base64"));
+    expect(diagnostics.clicks).to.equal(11);
+    expect(diagnostics.expectedCommitment).to.eql(
+      commitment(result(10), nonce, player.publicKey)
+    );
+    expect(diagnostics.providedHash).to.eql(
+      commitment(result(11), nonce, player.publicKey)
+    );
+  });
+
+  // Starts a session committing to `reveal`, waits, and reveals it
+  async function revealAfterPause(
+    reveal: SessionResult,
+    nonce: anchor.BN,
+    gameKeypair?: anchor.web3.Keypair
+  ) {
+    gameKeypair ??= await newGame();
+    const sessionKeypair = anchor.web3.Keypair.generate();
+
+    await program.methods
+      .startSession(commitment(reveal, nonce, player.publicKey), null)
+      .accountsPartial({
+        game: gameKeypair.publicKey,
+        session: sessionKeypair.publicKey,
+        player: player.publicKey,
+        payer: player.publicKey,
+      })
+      .signers([sessionKeypair])
+      .rpc();
+
+    await sleep(3000);
+
+    return program.methods
+      .endSession(reveal, nonce, new anchor.BN(3600))
+      .accountsPartial({
+        game: gameKeypair.publicKey,
+        session: sessionKeypair.publicKey,
+        player: player.publicKey,
+        config,
+        globalStats,
+        raffle: null,
+        device: null,
+        sessionArchive: null,
+        worldRecord: null,
+        previousRecordHolder: null,
+        mentorship: null,
+        worldBoss: null,
+        bossContribution: null,
+        coopSession: null,
+        goldenWindow: null,
+        partnerCollection: null,
+        season: null,
+        weeklyChallenge: null,
+        challengeEntry: null,
+        instructions: null,
+      })
+      .rpc();
+  }
+
+  it("rejects metrics that contradict each other", async () => {
+    await expect(
+      revealAfterPause(result(5, { combos: 6 }), new anchor.BN(8))
+    ).to.be.rejectedWith(/InvalidSessionResult/);
+
+    await expect(
+      revealAfterPause(result(5, { minuteClicks: minutes(4) }), new anchor.BN(9))
+    ).to.be.rejectedWith(/InvalidSessionResult/);
+
+    await expect(
+      revealAfterPause(result(5, { combos: 2, maxCombo: 3 }), new anchor.BN(13))
+    ).to.be.rejectedWith(/InvalidSessionResult/);
+  });
+
+  it("caps every minute of the histogram", async () => {
+    // within the session-wide cap, but credited to a minute the session
+    // never reached
+    await expect(
+      revealAfterPause(result(6, { minuteClicks: minutes(0, 6) }), new anchor.BN(10))
+    ).to.be.rejectedWith(/UnrealisticClickRate/);
+  });
+
+  it("locks a hardcore game on its first failed reveal", async () => {
+    const gameKeypair = await newGame(true);
+
+    // lands rather than failing, so the fall sticks
+    await revealAfterPause(result(5, { combos: 6 }), new anchor.BN(12), gameKeypair);
+
+    const gameState = await program.account.game.fetch(gameKeypair.publicKey);
+    // FLAG_HARDCORE and FLAG_FALLEN
+    expect(gameState.flags & 0b1100).to.equal(0b1100);
+    expect(gameState.lifetimeClicks.toNumber()).to.equal(0);
+
+    const sessionKeypair = anchor.web3.Keypair.generate();
+    await expect(
+      program.methods
+        .startSession(commitment(result(1), new anchor.BN(13), player.publicKey), null)
+        .accountsPartial({
+          game: gameKeypair.publicKey,
+          session: sessionKeypair.publicKey,
+          player: player.publicKey,
+          payer: player.publicKey,
+        })
+        .signers([sessionKeypair])
+        .rpc()
+    ).to.be.rejectedWith(/GameFallen/);
+  });
+
+  it("appends every session start to the game's index", async () => {
+    const gameKeypair = await newGame();
+    await program.methods
+      .initializeSessionIndex()
+      .accountsPartial({
+        game: gameKeypair.publicKey,
+        player: player.publicKey,
+        payer: player.publicKey,
+      })
+      .rpc();
+    const [sessionIndex] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("session_index"), gameKeypair.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const start = (sessionKeypair: anchor.web3.Keypair, indexed: boolean) =>
+      program.methods
+        .startSession(commitment(result(1), new anchor.BN(14), player.publicKey), null)
+        .accountsPartial({
+          game: gameKeypair.publicKey,
+          session: sessionKeypair.publicKey,
+          player: player.publicKey,
+          payer: player.publicKey,
+          sessionIndex: indexed ? sessionIndex : null,
+        })
+        .signers([sessionKeypair])
+        .rpc();
+
+    const sessionKeypair = anchor.web3.Keypair.generate();
+    await expect(start(sessionKeypair, false)).to.be.rejectedWith(/SessionIndexRequired/);
+    await start(sessionKeypair, true);
+
+    const index = await program.account.sessionIndex.fetch(sessionIndex);
+    expect(index.count.toNumber()).to.equal(1);
+    // entry 0 sits right after the discriminator and 41-byte header
+    const data = (await programProvider.connection.getAccountInfo(sessionIndex))!.data;
+    expect(data.length).to.equal(8 + 41 + 32);
+    expect(new anchor.web3.PublicKey(data.subarray(49, 81)).equals(sessionKeypair.publicKey)).to.be.true;
+  });
+
+  it("accepts a reveal pre-signed with a durable nonce", async () => {
+    const connection = programProvider.connection;
+    const gameKeypair = await newGame();
+    const sessionKeypair = anchor.web3.Keypair.generate();
+    const nonce = new anchor.BN(77);
+
+    const nonceKeypair = anchor.web3.Keypair.generate();
+    await programProvider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.createNonceAccount({
+          fromPubkey: player.publicKey,
+          noncePubkey: nonceKeypair.publicKey,
+          authorizedPubkey: player.publicKey,
+          lamports: await connection.getMinimumBalanceForRentExemption(
+            anchor.web3.NONCE_ACCOUNT_LENGTH
+          ),
+        })
+      ),
+      [nonceKeypair]
+    );
+
+    await program.methods
+      .startSession(commitment(result(5), nonce, player.publicKey), null)
+      .accountsPartial({
+        game: gameKeypair.publicKey,
+        session: sessionKeypair.publicKey,
+        player: player.publicKey,
+        payer: player.publicKey,
+      })
+      .signers([sessionKeypair])
+      .rpc();
+
+    // signed right away, the way a hardware or custodial wallet would
+    const reveal = await program.methods
+      .endSession(result(5), nonce, new anchor.BN(3600))
+      .accountsPartial({
+        game: gameKeypair.publicKey,
+        session: sessionKeypair.publicKey,
+        player: player.publicKey,
+        config,
+        globalStats,
+        raffle: null,
+        device: null,
+        sessionArchive: null,
+        worldRecord: null,
+        previousRecordHolder: null,
+        mentorship: null,
+        worldBoss: null,
+        bossContribution: null,
+        coopSession: null,
+        goldenWindow: null,
+        partnerCollection: null,
+        season: null,
+        weeklyChallenge: null,
+        challengeEntry: null,
+        instructions: null,
+      })
+      .instruction();
+    const tx = new anchor.web3.Transaction({
+      feePayer: player.publicKey,
+      nonceInfo: {
+        nonce: (await connection.getNonce(nonceKeypair.publicKey)).nonce,
+        nonceInstruction: anchor.web3.SystemProgram.nonceAdvance({
+          noncePubkey: nonceKeypair.publicKey,
+          authorizedPubkey: player.publicKey,
+        }),
+      },
+    }).add(reveal);
+    const signed = await player.signTransaction(tx);
+
+    await sleep(1500);
+
+    const signature = await connection.sendRawTransaction(signed.serialize());
+    await connection.confirmTransaction(signature, "confirmed");
+
+    const gameState = await program.account.game.fetch(gameKeypair.publicKey);
+    expect(gameState.lifetimeClicks.toNumber()).to.equal(5);
+  });
+
+  it("reveals within the compute budget", async () => {
+    const gameKeypair = await newGame();
+    const sessionKeypair = anchor.web3.Keypair.generate();
+    const nonce = new anchor.BN(99);
+
+    await program.methods
+      .startSession(commitment(result(20), nonce, player.publicKey), null)
+      .accountsPartial({
+        game: gameKeypair.publicKey,
+        session: sessionKeypair.publicKey,
+        player: player.publicKey,
+        payer: player.publicKey,
+      })
+      .signers([sessionKeypair])
+      .rpc();
+
+    await sleep(3000);
+
+    const tx = await program.methods
+      .endSession(result(20), nonce, new anchor.BN(3600))
+      .accountsPartial({
+        game: gameKeypair.publicKey,
+        session: sessionKeypair.publicKey,
+        player: player.publicKey,
+        config,
+        globalStats,
+        raffle: null,
+        device: null,
+        sessionArchive: null,
+        worldRecord: null,
+        previousRecordHolder: null,
+        mentorship: null,
+        worldBoss: null,
+        bossContribution: null,
+        coopSession: null,
+        goldenWindow: null,
+        partnerCollection: null,
+        season: null,
+        weeklyChallenge: null,
+        challengeEntry: null,
+        instructions: null,
+      })
+      .transaction();
+    tx.feePayer = player.publicKey;
+    tx.recentBlockhash = (
+      await programProvider.connection.getLatestBlockhash()
+    ).blockhash;
+
+    const simulation = await programProvider.connection.simulateTransaction(tx);
+    expect(simulation.value.err).to.be.null;
+    const unitsConsumed = simulation.value.unitsConsumed;
+    console.log(`      end_session used ${unitsConsumed} compute units`);
+
+    const computeUnits = JSON.parse(readFileSync(COMPUTE_UNITS_PATH, "utf8"));
+    if (computeUnits.endSession === null) {
+      computeUnits.endSession = unitsConsumed;
+      writeFileSync(COMPUTE_UNITS_PATH, JSON.stringify(computeUnits, null, 2) + "\n");
+      return;
+    }
+    expect(unitsConsumed).to.be.at.most(
+      Math.floor(computeUnits.endSession * CU_HEADROOM)
+    );
+  });
+
+  it("settles a gamble by the slot it was placed in", async () => {
+    await withFeatures(GAMBLE_ENABLED | VRF_ENABLED, async () => {
+      const outcomes = new Set<boolean>();
+      // each outcome is a coin flip, so play until both have come up
+      for (let round = 0; round < 12 && outcomes.size < 2; round++) {
+        const game = (await newGame()).publicKey;
+        const session = await playSession(game, 15);
+
+        await program.methods
+          .gambleSession()
+          .accountsPartial({ game, player: player.publicKey, session, config })
+          .rpc();
+        let gameState = await program.account.game.fetch(game);
+        expect(gameState.spendableClicks.toNumber()).to.equal(0);
+
+        const settle = (ix: anchor.web3.TransactionInstruction) =>
+          program.methods
+            .settleGamble()
+            .accountsPartial({
+              game,
+              session,
+              config,
+              instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+            })
+            .preInstructions([ix])
+            .rpc();
+
+        // a signature made before the gamble existed doesn't cover its slot
+        const early = Buffer.concat([session.toBuffer(), Buffer.from("gamble")]);
+        await expect(settle(oracleDraw(early).ix)).to.be.rejectedWith(
+          /InvalidSignatureInstruction/
+        );
+
+        const { gambleSlot } = await program.account.session.fetch(session);
+        const seed = Buffer.concat([session.toBuffer(), Buffer.from("gamble"), u64(gambleSlot)]);
+        const { ix, randomness } = oracleDraw(seed);
+        await settle(ix);
+
+        // settle_gamble: an even draw wins
+        const won = (randomness[0] & 1) === 0;
+        gameState = await program.account.game.fetch(game);
+        expect(gameState.spendableClicks.toNumber()).to.equal(won ? 30 : 0);
+        expect(gameState.lifetimeClicks.toNumber()).to.equal(15);
+        const sessionState = await program.account.session.fetch(session);
+        expect(sessionState.gamble).to.eql(won ? { won: {} } : { lost: {} });
+        outcomes.add(won);
+      }
+      expect(outcomes.size).to.equal(2);
+    });
+  });
+
+  it("refuses a gamble after its window", async () => {
+    const setWindow = (seconds: number | anchor.BN) =>
+      program.methods
+        .updateConfig({ gambleWindow: new anchor.BN(seconds) } as any)
+        .accountsPartial({ config, admin: player.publicKey })
+        .rpc();
+    const { gambleWindow } = await program.account.config.fetch(config);
+
+    await withFeatures(GAMBLE_ENABLED | VRF_ENABLED, async () => {
+      await setWindow(1);
+      try {
+        const game = (await newGame()).publicKey;
+        const session = await playSession(game, 15);
+        await sleep(3000);
+
+        await expect(
+          program.methods
+            .gambleSession()
+            .accountsPartial({ game, player: player.publicKey, session, config })
+            .rpc()
+        ).to.be.rejectedWith(/GambleWindowClosed/);
+        const gameState = await program.account.game.fetch(game);
+        expect(gameState.spendableClicks.toNumber()).to.equal(15);
+      } finally {
+        await setWindow(gambleWindow);
+      }
+    });
+  });
+
+  it("credits raffle tickets for revealed clicks", async () => {
+    const period = Math.floor(Date.now() / 1000 / (7 * 24 * 60 * 60));
+    const [raffle] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("raffle"), u64(period)],
+      program.programId
+    );
+
+    await withFeatures(RAFFLES_ENABLED, async () => {
+      if (!(await programProvider.connection.getAccountInfo(raffle))) {
+        await program.methods
+          .openRaffle(new anchor.BN(period))
+          .accountsPartial({ config, raffle, payer: player.publicKey })
+          .rpc();
+      }
+      const ticketsBefore = (await program.account.raffle.fetch(raffle)).totalTickets.toNumber();
+
+      // 100 clicks buy one ticket (Raffle::CLICKS_PER_TICKET)
+      const game = (await newGame()).publicKey;
+      const session = await playSession(game, 250, raffle);
+
+      const sessionState = await program.account.session.fetch(session);
+      expect(sessionState.raffle.toBase58()).to.equal(raffle.toBase58());
+      expect(sessionState.raffleTicketStart.toNumber()).to.equal(ticketsBefore);
+      expect(sessionState.raffleTickets.toNumber()).to.equal(2);
+      const raffleState = await program.account.raffle.fetch(raffle);
+      expect(raffleState.totalTickets.toNumber()).to.equal(ticketsBefore + 2);
+
+      // a reveal without an open raffle still lands, just without tickets
+      const untracked = await playSession(game, 150);
+      expect((await program.account.session.fetch(untracked)).raffleTickets.toNumber()).to.equal(0);
+      // tests/fixtures/raffle.json: period 1, long over
+      const pastRaffle = new anchor.web3.PublicKey("FmDGAd2bk9pkNdqPERryqWU2RoTzW1p5xZMrbK9XWgQ4");
+      const late = await playSession(game, 150, pastRaffle);
+      expect((await program.account.session.fetch(late)).raffleTickets.toNumber()).to.equal(0);
+
+      // tickets keep selling until the period is over
+      await expect(
+        program.methods.closeRaffle().accountsPartial({ config, raffle }).rpc()
+      ).to.be.rejectedWith(/RaffleStillOpen/);
+    });
+  });
+
+  it("draws a closed raffle and pays the winner", async () => {
+    // Preloaded from tests/fixtures/raffle.json: period 1, long over, with a
+    // 1 SOL prize and a single ticket held by the session in
+    // tests/fixtures/raffle-session.json
+    const raffle = new anchor.web3.PublicKey("FmDGAd2bk9pkNdqPERryqWU2RoTzW1p5xZMrbK9XWgQ4");
+    const winningSession = new anchor.web3.PublicKey(
+      "2xkhqZ37J7GTpqxJGhiacymze16YgXtEuDBY8HHtrZHE"
+    );
+    const winner = new anchor.web3.PublicKey("5XmCH546hie9DTff2sv7eVCXzUYFfCFJZwajMHEKKXVY");
+    const connection = programProvider.connection;
+
+    await withFeatures(RAFFLES_ENABLED | VRF_ENABLED, async () => {
+      const draw = (ix: anchor.web3.TransactionInstruction) =>
+        program.methods
+          .drawRaffle()
+          .accountsPartial({
+            config,
+            raffle,
+            winningSession,
+            winner,
+            instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+          })
+          .preInstructions([ix])
+          .rpc();
+
+      // the draw only exists once ticket sales are closed
+      await expect(draw(oracleDraw(raffle.toBuffer()).ix)).to.be.rejectedWith(
+        /RaffleStillOpen/
+      );
+
+      await program.methods.closeRaffle().accountsPartial({ config, raffle }).rpc();
+      const { drawSlot } = await program.account.raffle.fetch(raffle);
+      expect(drawSlot.toNumber()).to.be.greaterThan(0);
+      await expect(
+        program.methods.closeRaffle().accountsPartial({ config, raffle }).rpc()
+      ).to.be.rejectedWith(/RaffleAlreadyClosed/);
+
+      // a signature over the raffle alone was knowable before the close
+      await expect(draw(oracleDraw(raffle.toBuffer()).ix)).to.be.rejectedWith(
+        /InvalidSignatureInstruction/
+      );
+
+      const raffleInfo = await connection.getAccountInfo(raffle);
+      const rentExempt = await connection.getMinimumBalanceForRentExemption(
+        raffleInfo.data.length
+      );
+      const prize = raffleInfo.lamports - rentExempt;
+
+      await draw(oracleDraw(Buffer.concat([raffle.toBuffer(), u64(drawSlot)])).ix);
+
+      const raffleState = await program.account.raffle.fetch(raffle);
+      expect(raffleState.drawn).to.be.true;
+      expect(raffleState.winningTicket.toNumber()).to.equal(0);
+      expect(raffleState.winner.toBase58()).to.equal(winner.toBase58());
+      expect(raffleState.prize.toNumber()).to.equal(prize);
+      expect(await connection.getBalance(winner)).to.equal(prize);
+      expect(await connection.getBalance(raffle)).to.equal(rentExempt);
+
+      await expect(
+        draw(oracleDraw(Buffer.concat([raffle.toBuffer(), u64(drawSlot)])).ix)
+      ).to.be.rejectedWith(/RaffleAlreadyDrawn/);
+    });
+  });
+
+  // Unix time on the validator's clock, which gift expiries are checked against
+  async function chainTime(): Promise<number> {
+    const clock = await programProvider.connection.getAccountInfo(
+      anchor.web3.SYSVAR_CLOCK_PUBKEY
+    );
+    // slot, epoch_start_timestamp, epoch and leader_schedule_epoch come first
+    return Number(clock!.data.readBigInt64LE(32));
+  }
+
+  function offerGift(
+    sender: anchor.web3.PublicKey,
+    recipient: anchor.web3.PublicKey,
+    amount: number,
+    expiresAt: number
+  ) {
+    return program.methods
+      .offerGift(new anchor.BN(amount), new anchor.BN(expiresAt))
+      .accountsPartial({
+        sender,
+        recipient,
+        player: player.publicKey,
+        payer: player.publicKey,
+        config,
+      })
+      .rpc();
+  }
+
+  function acceptGift(gift: anchor.web3.PublicKey, recipient: anchor.web3.PublicKey) {
+    return program.methods
+      .acceptGift()
+      .accountsPartial({
+        recipient,
+        player: player.publicKey,
+        gift,
+        config,
+        rentPayer: player.publicKey,
+      })
+      .rpc();
+  }
+
+  function giftAddress(sender: anchor.web3.PublicKey, recipient: anchor.web3.PublicKey) {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("gift"), sender.toBuffer(), recipient.toBuffer()],
+      program.programId
+    )[0];
+  }
+
+  it("holds a gift in escrow until the recipient accepts it", async () => {
+    await withFeatures(GIFTING_ENABLED, async () => {
+      const sender = (await newGame()).publicKey;
+      const recipient = (await newGame()).publicKey;
+      const gift = giftAddress(sender, recipient);
+      await playSession(sender, 20);
+
+      await offerGift(sender, recipient, 15, (await chainTime()) + 3600);
+      expect((await program.account.game.fetch(sender)).spendableClicks.toNumber()).to.equal(5);
+      expect((await program.account.gift.fetch(gift)).amount.toNumber()).to.equal(15);
+
+      // nothing arrives before it is accepted, and a gift needs a balance
+      const pending = await program.account.game.fetch(recipient);
+      expect(pending.spendableClicks.toNumber()).to.equal(0);
+      await expect(
+        offerGift(recipient, sender, 1, (await chainTime()) + 3600)
+      ).to.be.rejectedWith(/InsufficientClicks/);
+
+      await acceptGift(gift, recipient);
+
+      const accepted = await program.account.game.fetch(recipient);
+      expect(accepted.spendableClicks.toNumber()).to.equal(15);
+      // gifted clicks were never played for
+      expect(accepted.lifetimeClicks.toNumber()).to.equal(0);
+      expect(await programProvider.connection.getAccountInfo(gift)).to.be.null;
+    });
+  });
+
+  it("returns an expired gift to the sender", async () => {
+    await withFeatures(GIFTING_ENABLED, async () => {
+      const sender = (await newGame()).publicKey;
+      const recipient = (await newGame()).publicKey;
+      const gift = giftAddress(sender, recipient);
+      await playSession(sender, 10);
+
+      const expiresAt = (await chainTime()) + 2;
+      await offerGift(sender, recipient, 10, expiresAt);
+      const reclaim = () =>
+        program.methods
+          .reclaimExpiredGift()
+          .accountsPartial({ sender, gift, rentPayer: player.publicKey })
+          .rpc();
+
+      await expect(reclaim()).to.be.rejectedWith(/GiftNotExpired/);
+
+      while ((await chainTime()) < expiresAt) {
+        await sleep(500);
+      }
+      await expect(acceptGift(gift, recipient)).to.be.rejectedWith(/GiftExpired/);
+
+      await reclaim();
+
+      expect((await program.account.game.fetch(sender)).spendableClicks.toNumber()).to.equal(10);
+      expect((await program.account.game.fetch(recipient)).spendableClicks.toNumber()).to.equal(0);
+      expect(await programProvider.connection.getAccountInfo(gift)).to.be.null;
+    });
+  });
+
+  it("refuses gifts from a frozen game", async () => {
+    await withFeatures(GIFTING_ENABLED | FREEZE_ENABLED, async () => {
+      const sender = (await newGame()).publicKey;
+      const recipient = (await newGame()).publicKey;
+      await playSession(sender, 10);
+
+      await program.methods
+        .freezeGame(false)
+        .accountsPartial({ game: sender, player: player.publicKey, config })
+        .rpc();
+
+      await expect(
+        offerGift(sender, recipient, 10, (await chainTime()) + 3600)
+      ).to.be.rejectedWith(/GameFrozen/);
+      expect((await program.account.game.fetch(sender)).spendableClicks.toNumber()).to.equal(10);
+    });
+  });
+
+  // A season running for the next hour, with a shoutbox charging
+  // `feeClicks` per post
+  async function newShoutbox(feeClicks: number) {
+    const seasonId = Math.floor(Math.random() * 0x7fffffff);
+    const seasonIdBytes = Buffer.alloc(4);
+    seasonIdBytes.writeUInt32LE(seasonId);
+    const [season] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("pass_season"), seasonIdBytes],
+      program.programId
+    );
+    const now = await chainTime();
+    const tiers = Array.from({ length: 10 }, () => ({
+      xp: new anchor.BN(0),
+      rewardGems: new anchor.BN(0),
+      rewardClicks: new anchor.BN(0),
+      premiumOnly: false,
+    }));
+    await program.methods
+      .createPassSeason(
+        seasonId,
+        new anchor.BN(now - 60),
+        new anchor.BN(now + 3600),
+        new anchor.BN(0),
+        new anchor.BN(0),
+        tiers
+      )
+      .accountsPartial({ config, season, admin: player.publicKey })
+      .rpc();
+    await program.methods
+      .createShoutbox(seasonId, new anchor.BN(feeClicks), new anchor.BN(0))
+      .accountsPartial({ config, season, admin: player.publicKey })
+      .rpc();
+    const [shoutbox] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("shoutbox"), season.toBuffer()],
+      program.programId
+    );
+    return { seasonId, season, shoutbox };
+  }
+
+  function postMessage(
+    board: { seasonId: number; season: anchor.web3.PublicKey; shoutbox: anchor.web3.PublicKey },
+    game: anchor.web3.PublicKey,
+    text: string,
+    payment: object = { clicks: {} }
+  ) {
+    return program.methods
+      .postMessage(board.seasonId, text, payment as any)
+      .accountsPartial({
+        game,
+        player: player.publicKey,
+        config,
+        season: board.season,
+        shoutbox: board.shoutbox,
+      })
+      .rpc();
+  }
+
+  it("posts to the season's shoutbox for a fee", async () => {
+    await withFeatures(SHOUTBOX_ENABLED, async () => {
+      const board = await newShoutbox(5);
+      const game = (await newGame()).publicKey;
+      await playSession(game, 20);
+
+      // this shoutbox only takes clicks
+      await expect(postMessage(board, game, "gg", { gems: {} })).to.be.rejectedWith(
+        /PaymentNotAccepted/
+      );
+
+      await postMessage(board, game, "gg");
+
+      expect((await program.account.game.fetch(game)).spendableClicks.toNumber()).to.equal(15);
+      const shoutbox = await program.account.shoutbox.fetch(board.shoutbox);
+      expect(shoutbox.messageCount.toNumber()).to.equal(1);
+      const message = shoutbox.messages[0];
+      expect(message.game.equals(game)).to.be.true;
+      expect(Buffer.from(message.text.slice(0, message.len)).toString()).to.equal("gg");
+
+      // Shoutbox::POST_COOLDOWN
+      await expect(postMessage(board, game, "again")).to.be.rejectedWith(/PostCooldown/);
+    });
+  });
+
+  it("limits a message to 1 to 100 bytes", async () => {
+    await withFeatures(SHOUTBOX_ENABLED, async () => {
+      const board = await newShoutbox(1);
+      const game = (await newGame()).publicKey;
+      await playSession(game, 10);
+
+      await expect(postMessage(board, game, "")).to.be.rejectedWith(/InvalidMessage/);
+      await expect(postMessage(board, game, "x".repeat(101))).to.be.rejectedWith(
+        /InvalidMessage/
+      );
+      // the limit is in bytes, not characters
+      await expect(postMessage(board, game, "é".repeat(51))).to.be.rejectedWith(
+        /InvalidMessage/
+      );
+
+      await postMessage(board, game, "é".repeat(50));
+
+      const shoutbox = await program.account.shoutbox.fetch(board.shoutbox);
+      expect(shoutbox.messages[0].len).to.equal(100);
+    });
+  });
+
+  it("refuses posts from a frozen game", async () => {
+    await withFeatures(SHOUTBOX_ENABLED | FREEZE_ENABLED, async () => {
+      const board = await newShoutbox(1);
+      const game = (await newGame()).publicKey;
+      await playSession(game, 10);
+
+      await program.methods
+        .freezeGame(false)
+        .accountsPartial({ game, player: player.publicKey, config })
+        .rpc();
+
+      await expect(postMessage(board, game, "hello")).to.be.rejectedWith(/GameFrozen/);
+      const shoutbox = await program.account.shoutbox.fetch(board.shoutbox);
+      expect(shoutbox.messageCount.toNumber()).to.equal(0);
+    });
+  });
+
+  it("migrates a game from before sessions existed", async () => {
+    // Preloaded from tests/fixtures/legacy-game.json: the original 44-byte
+    // Game { player, clicks: 1234 }
+    const legacyGame = new anchor.web3.PublicKey(
+      "3bSkPkhAAfCMHiowhPjg6FGNRnFURTxn2PZ3rFPYzhpy"
+    );
+    const legacyPlayer = new anchor.web3.PublicKey(
+      "3MSQZjAs7kGEq5Dzm2zzMjMiU1jrfFTkXzJh7LHF4Xwr"
+    );
+
+    await program.methods
+      .migrateGame()
+      .accountsPartial({ game: legacyGame, payer: player.publicKey })
+      .rpc();
+
+    const gameState = await program.account.game.fetch(legacyGame);
+    expect(gameState.player.equals(legacyPlayer)).to.be.true;
+    expect(gameState.clicks).to.equal(1234);
+    expect(gameState.flags & 0x80).to.equal(0x80); // Game::FLAG_FIXED_LAYOUT
+    expect(gameState.activeSession.equals(anchor.web3.PublicKey.default)).to.be.true;
+
+    await expect(
+      program.methods
+        .migrateGame()
+        .accountsPartial({ game: legacyGame, payer: player.publicKey })
+        .rpc()
+    ).to.be.rejectedWith(/GameAlreadyMigrated/);
+
+    // anyone can give a migrated game the LiveState its sessions need
+    await program.methods
+      .initializeLiveState()
+      .accountsPartial({ game: legacyGame, payer: player.publicKey })
+      .rpc();
+    const [liveState] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("live"), legacyGame.toBuffer()],
+      program.programId
+    );
+    expect((await program.account.liveState.fetch(liveState)).clicks).to.equal(1234);
+  });
+});
@@ -0,0 +1,292 @@
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::*;
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_pubkey::Pubkey;
+
+use crate::constants::{BPS_ONE, SECONDS_PER_DAY};
+use crate::math::Fixed;
+use crate::{discriminators, seeds, AccountLayout};
+
+/// A daily bonus window in UTC. `start_hour` > `end_hour` wraps past
+/// midnight; a zero multiplier marks an unused slot.
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HappyHour {
+    pub start_hour: u8,
+    pub end_hour: u8,
+    pub multiplier_bps: u16,
+}
+
+impl HappyHour {
+    pub const SIZE: usize = 1 + 1 + 2;
+
+    pub fn is_valid(&self) -> bool {
+        self.multiplier_bps == 0
+            || (self.start_hour < 24
+                && self.end_hour <= 24
+                && self.start_hour != self.end_hour
+                && self.multiplier_bps >= BPS_ONE)
+    }
+
+    /// Seconds of `[start, end)` that fall inside this window.
+    pub fn overlap(&self, start: i64, end: i64) -> i64 {
+        let window_start = self.start_hour as i64 * 3600;
+        let window_end = self.end_hour as i64 * 3600;
+
+        if window_start < window_end {
+            daily_overlap(start, end, window_start, window_end)
+        } else {
+            daily_overlap(start, end, window_start, SECONDS_PER_DAY)
+                + daily_overlap(start, end, 0, window_end)
+        }
+    }
+}
+
+/// CPS cap bonus for games with at least `min_reputation`; a zero bonus
+/// marks an unused slot.
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ReputationBand {
+    pub min_reputation: u32,
+    pub cps_bonus_bps: u16,
+}
+
+impl ReputationBand {
+    pub const SIZE: usize = 4 + 2;
+}
+
+/// One entry of the lootbox reward table, drawn with probability
+/// `weight` / total weight. A zero weight marks an unused slot.
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LootboxReward {
+    pub weight: u16,
+    pub reward_gems: u64,
+    pub reward_clicks: u64,
+    pub rare: bool,             // what the pity counter guarantees
+}
+
+impl LootboxReward {
+    pub const SIZE: usize = 2 + 8 + 8 + 1;
+}
+
+/// Overlap of `[start, end)` with the daily window `[from, to)` (seconds of
+/// day), in closed form so long sessions don't cost a loop per day.
+fn daily_overlap(start: i64, end: i64, from: i64, to: i64) -> i64 {
+    // seconds inside the window between the epoch and `t`
+    let covered = |t: i64| {
+        t.div_euclid(SECONDS_PER_DAY) * (to - from)
+            + (t.rem_euclid(SECONDS_PER_DAY) - from).clamp(0, to - from)
+    };
+    covered(end) - covered(start)
+}
+
+/// Program-wide settings, stored in a singleton PDA owned by the admin.
+#[cfg_attr(feature = "anchor", account(discriminator = discriminators::CONFIG))]
+#[cfg_attr(not(feature = "anchor"), derive(Clone, BorshSerialize, BorshDeserialize))]
+#[derive(Debug, Default)]
+pub struct Config {
+    pub admin: Pubkey,                  // 32 bytes
+    pub vrf_oracle: Pubkey,             // 32 bytes - trusted key whose Ed25519 signatures seed randomness, see the program's oracle.rs
+    pub target_epoch_clicks: u64,       // 8 bytes - worldwide clicks per epoch before difficulty rises, 0 disables
+    pub attestor: Pubkey,               // 32 bytes - server key that signs session challenges
+    pub require_challenge: bool,        // 1 byte
+    pub device_cosign_threshold: u32,   // 4 bytes - reveals of this many clicks need a device co-signer, 0 disables
+    pub features: u64,                  // 8 bytes - bitmask of the *_ENABLED flags below
+    pub happy_hours: [HappyHour; Config::MAX_HAPPY_HOURS],  // 4 * 4 bytes
+    pub lamports_per_gem: u64,          // 8 bytes - 0 stops gem sales
+    pub cosmetic_gem_price: u64,        // 8 bytes
+    pub refund_window: i64,             // 8 bytes - seconds a SOL purchase stays refundable, 0 disables
+    pub max_gems_per_day: u64,          // 8 bytes - 0 is uncapped
+    pub max_gems_lifetime: u64,         // 8 bytes - 0 is uncapped
+    pub max_session_duration: i64,      // 8 bytes - seconds a session may run before it expires, 0 is unlimited
+    pub extension_gems_per_minute: u64, // 8 bytes - price of extend_session, 0 stops extensions
+    pub mentor_min_level: u32,          // 4 bytes - level a game needs to become a mentor
+    pub mentorship_days: u32,           // 4 bytes - how long a pairing pays out
+    pub mentorship_bonus_bps: u16,      // 2 bytes - on the mentee's revealed clicks, paid to both
+    pub reputation_bands: [ReputationBand; Config::MAX_REPUTATION_BANDS],  // 4 * 6 bytes
+    pub lookup_table: Pubkey,           // 32 bytes - set by create_lookup_table, default until then
+    pub offerwall_oracle: Pubkey,       // 32 bytes - key that signs completed offers
+    pub max_offers_per_day: u32,        // 4 bytes - offers one game may redeem per UTC day, 0 is uncapped
+    pub lootbox_table: [LootboxReward; Config::MAX_LOOTBOX_REWARDS],   // 6 * 19 bytes
+    pub lootbox_pity_threshold: u32,    // 4 bytes - the Nth open without a rare reward is rare, 0 disables
+    pub lootboxes_per_quest: u32,       // 4 bytes - granted by complete_partner_quest
+    pub lootboxes_per_boss: u32,        // 4 bytes - granted by claim_boss_loot
+    pub marketplace_fee_bps: u16,       // 2 bytes - cut of each listing sale paid to the treasury
+    pub hardcore_cps_cap_bps: u16,      // 2 bytes - share of the usual CPS cap hardcore games get, 0 is the usual cap
+    pub time_attack_seconds: i64,       // 8 bytes - how long a time attack lasts, 0 stops them
+    pub time_attack_grace: i64,         // 8 bytes - seconds after the window a time attack may still be revealed
+    pub endurance_optimal_seconds: i64, // 8 bytes - endurance sessions keep full credit up to here
+    pub endurance_decay_step: i64,      // 8 bytes - seconds per decay step past the optimum, 0 disables decay
+    pub endurance_decay_bps: u16,       // 2 bytes - credit lost per step started
+    pub endurance_floor_bps: u16,       // 2 bytes - credit never decays below this
+    pub base_cps: u64,                  // 8 bytes - CPS cap at base difficulty before bonuses, 0 is GlobalStats::BASE_CPS
+    pub coop_cap_bps: u16,              // 2 bytes - a co-op pair's shared click cap as a share of one player's, 0 leaves each their own
+    pub gamble_window: i64,             // 8 bytes - seconds after a reveal its clicks may be gambled
+    pub combo_bonus_bps: u16,           // 2 bytes - bonus when one combo streak spans the whole session, 0 disables
+    pub modifier_season: Pubkey,        // 32 bytes - PassSeason whose modifiers apply to reveals, default if none
+    pub trophy_collection: Pubkey,      // 32 bytes - Metaplex collection of the NFTs a TrophyCabinet can pin
+    pub gatekeeper_network: Pubkey,     // 32 bytes - reward claims need a gateway token from it, default if not required
+    pub cluster: u8,                    // 1 byte - Config::CLUSTER_* the config is deployed on, set once
+    pub bump: u8,                       // 1 byte
+}
+
+impl Config {
+    pub const SEED: &'static [u8] = seeds::CONFIG;
+
+    // Feature flags. Subsystems ship disabled and are switched on per cluster.
+    pub const RAFFLES_ENABLED: u64 = 1 << 0;
+    // Randomness from the trusted vrf_oracle. Named before it was found not
+    // to be a true VRF.
+    pub const VRF_ENABLED: u64 = 1 << 1;
+    pub const DIFFICULTY_ENABLED: u64 = 1 << 2;
+    pub const DEVICES_ENABLED: u64 = 1 << 3;
+    pub const RECOVERY_ENABLED: u64 = 1 << 4;
+    pub const FREEZE_ENABLED: u64 = 1 << 5;
+    pub const SNAPSHOTS_ENABLED: u64 = 1 << 6;
+    pub const PARTNERS_ENABLED: u64 = 1 << 7;
+    pub const QUESTS_ENABLED: u64 = 1 << 8;
+    pub const SKILLS_ENABLED: u64 = 1 << 9;
+    pub const GEMS_ENABLED: u64 = 1 << 10;
+    pub const ARCHIVE_ENABLED: u64 = 1 << 11;
+    pub const MENTORSHIP_ENABLED: u64 = 1 << 12;
+    pub const WORLD_BOSS_ENABLED: u64 = 1 << 13;
+    pub const OFFERS_ENABLED: u64 = 1 << 14;
+    pub const SEASON_PASS_ENABLED: u64 = 1 << 15;
+    pub const LOOTBOXES_ENABLED: u64 = 1 << 16;
+    pub const CRAFTING_ENABLED: u64 = 1 << 17;
+    pub const MARKETPLACE_ENABLED: u64 = 1 << 18;
+    pub const TIME_ATTACK_ENABLED: u64 = 1 << 19;
+    pub const ENDURANCE_ENABLED: u64 = 1 << 20;
+    pub const COOP_ENABLED: u64 = 1 << 21;
+    pub const GAMBLE_ENABLED: u64 = 1 << 22;
+    pub const GOLDEN_WINDOWS_ENABLED: u64 = 1 << 23;
+    pub const TROPHY_CABINET_ENABLED: u64 = 1 << 24;
+    pub const WEEKLY_CHALLENGES_ENABLED: u64 = 1 << 25;
+    pub const REVEAL_GUARD_ENABLED: u64 = 1 << 26;
+    pub const GIFTING_ENABLED: u64 = 1 << 27;
+    pub const SHOUTBOX_ENABLED: u64 = 1 << 28;
+    /// Features paying out real tokens. They count as off unless the config
+    /// is tagged mainnet, or localnet where the tokens are fake anyway.
+    pub const REAL_VALUE_FEATURES: u64 = Self::RAFFLES_ENABLED;
+
+    // Cluster tags. An untagged config is treated like devnet.
+    pub const CLUSTER_UNTAGGED: u8 = 0;
+    pub const CLUSTER_LOCALNET: u8 = 1;
+    pub const CLUSTER_DEVNET: u8 = 2;
+    pub const CLUSTER_TESTNET: u8 = 3;
+    pub const CLUSTER_MAINNET: u8 = 4;
+
+    pub const MAX_HAPPY_HOURS: usize = 4;
+    pub const MAX_REPUTATION_BANDS: usize = 4;
+    pub const MAX_LOOTBOX_REWARDS: usize = 6;
+    pub const MAXIMUM_SIZE: usize =
+        32 + 32 + 8 + 32 + 1 + 4 + 8 + Config::MAX_HAPPY_HOURS * HappyHour::SIZE + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 4 + 4 + 2
+        + Config::MAX_REPUTATION_BANDS * ReputationBand::SIZE + 32 + 32 + 4
+        + Config::MAX_LOOTBOX_REWARDS * LootboxReward::SIZE + 4 + 4 + 4 + 2 + 2 + 8 + 8
+        + 8 + 8 + 2 + 2 + 8 + 2 + 8 + 2 + 32 + 32 + 32 + 1 + 1;
+
+    /// True if every flag in `feature` is switched on and allowed on the
+    /// config's cluster.
+    pub fn has_feature(&self, feature: u64) -> bool {
+        let allowed = if self.pays_real_value() { u64::MAX } else { !Self::REAL_VALUE_FEATURES };
+        self.features & allowed & feature == feature
+    }
+
+    /// Whether REAL_VALUE_FEATURES may run on the config's cluster.
+    pub fn pays_real_value(&self) -> bool {
+        matches!(self.cluster, Self::CLUSTER_LOCALNET | Self::CLUSTER_MAINNET)
+    }
+
+    /// When a session started at `start` expires, i64::MAX if never.
+    pub fn session_deadline(&self, start: i64) -> i64 {
+        if self.max_session_duration > 0 {
+            start.saturating_add(self.max_session_duration)
+        } else {
+            i64::MAX
+        }
+    }
+
+    /// CPS cap bonus of the best band `reputation` qualifies for.
+    pub fn reputation_cps_bonus_bps(&self, reputation: u32) -> u16 {
+        self.reputation_bands
+            .iter()
+            .filter(|band| reputation >= band.min_reputation)
+            .map(|band| band.cps_bonus_bps)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Share of an endurance session's clicks credited after `duration`
+    /// seconds: all of them up to the optimal duration, then
+    /// endurance_decay_bps less for every step started, down to the floor.
+    pub fn endurance_multiplier(&self, duration: i64) -> Fixed {
+        let over = duration - self.endurance_optimal_seconds;
+        if over <= 0 || self.endurance_decay_step <= 0 {
+            return Fixed::ONE;
+        }
+        let steps = (over as u64).div_ceil(self.endurance_decay_step as u64);
+        let bps = (BPS_ONE as u64)
+            .saturating_sub(steps.saturating_mul(self.endurance_decay_bps as u64))
+            .max(self.endurance_floor_bps as u64);
+        Fixed::from_bps(bps as u16)
+    }
+
+    /// Picks a lootbox table slot with `randomness`, among the rare entries
+    /// only if `rare_only`. None if nothing qualifies.
+    pub fn draw_lootbox(&self, randomness: u64, rare_only: bool) -> Option<usize> {
+        let eligible = |reward: &LootboxReward| reward.weight > 0 && (reward.rare || !rare_only);
+        let total: u64 = self.lootbox_table.iter().filter(|r| eligible(r)).map(|r| r.weight as u64).sum();
+        if total == 0 {
+            return None;
+        }
+
+        let mut roll = randomness % total;
+        for (index, reward) in self.lootbox_table.iter().enumerate().filter(|(_, r)| eligible(r)) {
+            if roll < reward.weight as u64 {
+                return Some(index);
+            }
+            roll -= reward.weight as u64;
+        }
+        None
+    }
+
+    /// Multiplier for a session spanning `[start, end)`: each window's bonus
+    /// is weighted by how much of the session it covers.
+    pub fn happy_hour_multiplier(&self, start: i64, end: i64) -> Option<Fixed> {
+        let duration = end - start;
+        if duration <= 0 {
+            return Some(Fixed::ONE);
+        }
+
+        let weighted_bonus: i64 = self
+            .happy_hours
+            .iter()
+            .filter(|window| window.multiplier_bps > BPS_ONE)
+            .map(|window| window.overlap(start, end) * (window.multiplier_bps - BPS_ONE) as i64)
+            .sum();
+
+        let scale = duration as u64 * BPS_ONE as u64;
+        Fixed::from_ratio(scale + weighted_bonus as u64, scale)
+    }
+
+    /// Multiplier for a reveal whose longest combo streak is `max_combo` of
+    /// its `clicks`: the bonus is weighted by the share of clicks the streak
+    /// covers, so only sustained streaks earn it in full.
+    pub fn combo_multiplier(&self, max_combo: u32, clicks: u32) -> Option<Fixed> {
+        if clicks == 0 {
+            return Some(Fixed::ONE);
+        }
+
+        let scale = clicks as u64 * BPS_ONE as u64;
+        Fixed::from_ratio(scale + max_combo as u64 * self.combo_bonus_bps as u64, scale)
+    }
+}
+
+impl AccountLayout for Config {
+    const ACCOUNT_DISCRIMINATOR: &'static [u8] = discriminators::CONFIG;
+}
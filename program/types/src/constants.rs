@@ -0,0 +1,4 @@
+/// Basis points: 10_000 is a multiplier of 1x.
+pub const BPS_ONE: u16 = 10_000;
+
+pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
@@ -0,0 +1,6 @@
+//! Account discriminators, pinned to the values Anchor derived from the
+//! original struct names (sha256("account:<Name>")[..8]).
+
+pub const GAME: &[u8] = &[27, 90, 166, 125, 74, 100, 121, 18];
+pub const SESSION: &[u8] = &[243, 81, 72, 115, 214, 188, 72, 144];
+pub const CONFIG: &[u8] = &[155, 12, 170, 224, 30, 250, 204, 130];
@@ -0,0 +1,368 @@
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::*;
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_pubkey::Pubkey;
+
+use crate::math::Fixed;
+use crate::{discriminators, AccountLayout, SessionResult};
+
+#[cfg_attr(feature = "anchor", account(discriminator = discriminators::GAME))]
+#[cfg_attr(not(feature = "anchor"), derive(Clone, BorshSerialize, BorshDeserialize))]
+#[derive(Debug, Default)]
+pub struct Game {
+    pub player: Pubkey,                    // 32 bytes
+    pub clicks: u32,                       // 4 bytes
+    pub lifetime_clicks: u64,              // 8 bytes - every click credited, never spent; the score leaderboards use
+    pub last_session_end: i64,             // 8 bytes
+    pub flags: u8,                         // 1 byte - Game::FLAG_* bits
+    pub active_session: Pubkey,            // 32 bytes - meaningful with FLAG_ACTIVE_SESSION, zeroed otherwise
+    pub devices: [Pubkey; Game::MAX_DEVICES],   // 4 * 32 bytes - default key marks a free slot
+    pub guardians: [Pubkey; 3],            // 3 * 32 bytes - default key marks a free slot
+    pub guardian_threshold: u8,            // 1 byte - 0 means recovery is disabled
+    pub recovery_owner: Pubkey,            // 32 bytes - pending new owner with FLAG_RECOVERY_PENDING, zeroed otherwise
+    pub recovery_unlock_time: i64,         // 8 bytes
+    pub frozen: bool,                      // 1 byte
+    pub unfreeze_requires_guardian: bool,  // 1 byte
+    pub partner_mint: Pubkey,              // 32 bytes - NFT behind the partner multiplier
+    pub partner_multiplier_bps: u16,       // 2 bytes
+    pub partner_multiplier_expires_at: i64,    // 8 bytes
+    pub xp: u64,                           // 8 bytes
+    pub level: u32,                        // 4 bytes
+    pub unlocked_skills: u64,              // 8 bytes - bitmask of SkillTree node ids
+    pub skill_points_spent: u32,           // 4 bytes
+    pub skill_cps_bonus_bps: u16,          // 2 bytes - summed effects of unlocked nodes
+    pub skill_idle_bonus_bps: u16,         // 2 bytes
+    pub skill_crit_chance_bps: u16,        // 2 bytes
+    pub last_respec_at: i64,               // 8 bytes
+    pub gems: u64,                         // 8 bytes - premium currency, bought with SOL only
+    pub cosmetics: u64,                    // 8 bytes - bitmask of owned cosmetic ids
+    pub purchase_count: u64,               // 8 bytes - seeds the next Receipt
+    pub gem_purchase_day: i64,             // 8 bytes - UTC day the daily counter belongs to
+    pub gems_bought_today: u64,            // 8 bytes
+    pub gems_bought_lifetime: u64,         // 8 bytes
+    pub best_cps: u16,                     // 2 bytes - highest max_cps_observed revealed
+    pub best_session_clicks: u32,          // 4 bytes
+    pub best_day_clicks: u64,              // 8 bytes - most clicks revealed in one UTC day
+    pub click_day: i64,                    // 8 bytes - UTC day clicks_today belongs to
+    pub clicks_today: u64,                 // 8 bytes
+    pub former_record_holder: bool,        // 1 byte - once held the world record and lost it
+    pub is_mentor: bool,                   // 1 byte - set by become_mentor
+    pub reputation: u32,                   // 4 bytes - earned by clean reveals, lost to expired and voided sessions
+    pub offer_boost_bps: u16,              // 2 bytes - from redeem_offer, stacks with the partner multiplier
+    pub offer_boost_expires_at: i64,       // 8 bytes
+    pub offer_day: i64,                    // 8 bytes - UTC day offers_today belongs to
+    pub offers_today: u32,                 // 4 bytes
+    pub identity_hash: [u8; 32],           // 32 bytes - salted hash of an off-chain identity, zero if none
+    pub identity_bound_at: i64,            // 8 bytes
+    pub lootboxes_opened: u64,             // 8 bytes - seeds the next open's randomness
+    pub lootbox_pity: u32,                 // 4 bytes - opens since the last rare reward
+    pub listing_count: u64,                // 8 bytes - seeds the next Listing
+    pub best_time_attack_clicks: u32,      // 4 bytes - most clicks revealed in a time attack
+    pub best_endurance_clicks: u32,        // 4 bytes - most clicks an endurance session kept after decay
+    pub last_gamble_day: i64,              // 8 bytes - UTC day of the last gamble_session
+    pub created_at_slot: u64,              // 8 bytes - slot initialize ran in, 0 for games created before it was kept
+    pub first_session_slot: u64,           // 8 bytes - slot the first session started in, 0 until then
+    pub spendable_clicks: u64,             // 8 bytes - balance respecs and gambles spend, see credit_clicks
+    pub last_post_at: i64,                 // 8 bytes - last post_message, for Shoutbox::POST_COOLDOWN
+    pub lootbox_request_slot: u64,         // 8 bytes - slot a pending open_lootbox landed in, 0 when none
+    pub partner_collection: Pubkey,        // 32 bytes - collection partner_mint was verified against
+}
+
+impl Game {
+    pub const MAX_DEVICES: usize = 4;
+    pub const RECOVERY_DELAY: i64 = 3 * 24 * 60 * 60;
+    // level L needs XP_PER_LEVEL * L^2 xp in total
+    pub const XP_PER_LEVEL: u64 = 100;
+    pub const SKILL_POINTS_PER_LEVEL: u32 = 1;
+    /// Must be typed out to soft reset a game.
+    pub const SOFT_RESET_CONFIRMATION: &'static str = "reset my progress";
+    pub const REPUTATION_PER_REVEAL: u32 = 1;
+    // cancelling a session only after it expired
+    pub const EXPIRED_SESSION_PENALTY: u32 = 10;
+    // an admin voiding a session as SuspectedCheating
+    pub const CHEATING_PENALTY: u32 = 100;
+    pub const IDENTITY_UNBIND_COOLDOWN: i64 = 7 * 24 * 60 * 60;
+    pub const FLAG_ACTIVE_SESSION: u8 = 1 << 0;
+    pub const FLAG_RECOVERY_PENDING: u8 = 1 << 1;
+    // Created by initialize_hardcore: a tighter CPS cap, no boosts, and one
+    // failed reveal sets FLAG_FALLEN for good
+    pub const FLAG_HARDCORE: u8 = 1 << 2;
+    pub const FLAG_FALLEN: u8 = 1 << 3;
+    // Set by initialize_session_index; every session start must then append
+    // to the game's SessionIndex
+    pub const FLAG_SESSION_INDEX: u8 = 1 << 4;
+    // Set on every game with this layout. The Option tags of the old layout
+    // sat where flags is and were 0 or 1, so migrate_game can tell them apart.
+    pub const FLAG_FIXED_LAYOUT: u8 = 1 << 7;
+    /// Offsets into the account data, discriminator included, for memcmp
+    /// filters. lifetime_clicks sits where the first releases kept the
+    /// score, so leaderboards reading it there keep ranking by score.
+    pub const LIFETIME_CLICKS_OFFSET: usize = 8 + 32 + 4;
+    pub const FLAGS_OFFSET: usize = 8 + 32 + 4 + 8 + 8;
+    pub const ACTIVE_SESSION_OFFSET: usize = Game::FLAGS_OFFSET + 1;
+    pub const MAXIMUM_SIZE: usize =
+        32 + 4 + 8 + 8 + 1 + 32 + Game::MAX_DEVICES * 32 + 3 * 32 + 1 + 32 + 8 + 1 + 1 + 32 + 2 + 8
+        + 8 + 4 + 8 + 4 + 2 + 2 + 2 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 2 + 4 + 8 + 8 + 8 + 1 + 1 + 4
+        + 2 + 8 + 8 + 4 + 32 + 8 + 8 + 4 + 8 + 4 + 4 + 8 + 8 + 8 + 8 + 8 + 8 + 32;
+
+    pub fn level_for_xp(xp: u64) -> u32 {
+        integer_sqrt(xp / Self::XP_PER_LEVEL) as u32
+    }
+
+    /// Wipes click progress (clicks and the spendable balance, xp and level,
+    /// skill unlocks) for a fresh start. lifetime_clicks is the leaderboard
+    /// score and is kept, as are purchases, cosmetics, partner boosts,
+    /// personal records and the security setup (devices, guardians, freeze).
+    pub fn soft_reset(&mut self) {
+        self.clicks = 0;
+        self.spendable_clicks = 0;
+        self.xp = 0;
+        self.level = 0;
+        self.unlocked_skills = 0;
+        self.skill_points_spent = 0;
+        self.skill_cps_bonus_bps = 0;
+        self.skill_idle_bonus_bps = 0;
+        self.skill_crit_chance_bps = 0;
+    }
+
+    /// Credits clicks the game earned, to both its score and its balance.
+    /// Every change to either goes through here, spend_clicks or
+    /// return_clicks, so the two can't drift apart.
+    pub fn credit_clicks(&mut self, clicks: u64) {
+        self.lifetime_clicks += clicks;
+        self.spendable_clicks += clicks;
+    }
+
+    /// Takes `clicks` from the balance, leaving the score alone. False, with
+    /// nothing taken, if the balance is short.
+    pub fn spend_clicks(&mut self, clicks: u64) -> bool {
+        if self.spendable_clicks < clicks {
+            return false;
+        }
+        self.spendable_clicks -= clicks;
+        true
+    }
+
+    /// Pays clicks into the balance that weren't earned by play, such as
+    /// gamble winnings and gifts, so the score doesn't move.
+    pub fn return_clicks(&mut self, clicks: u64) {
+        self.spendable_clicks += clicks;
+    }
+
+    /// Adds xp and returns the new level if it went up.
+    pub fn award_xp(&mut self, xp: u64) -> Option<u32> {
+        self.xp += xp;
+        let level = Self::level_for_xp(self.xp);
+        if level > self.level {
+            self.level = level;
+            Some(level)
+        } else {
+            None
+        }
+    }
+
+    /// Folds a revealed session into the personal records and returns the
+    /// ones it beat as (record, previous, new). Raw clicks count, before
+    /// partner and happy hour multipliers.
+    pub fn update_records(
+        &mut self,
+        result: &SessionResult,
+        day: i64,
+    ) -> [Option<(PersonalRecord, u64, u64)>; 3] {
+        if self.click_day != day {
+            self.click_day = day;
+            self.clicks_today = 0;
+        }
+        self.clicks_today += result.clicks as u64;
+
+        let mut beaten = [None; 3];
+        if result.max_cps_observed > self.best_cps {
+            beaten[0] = Some((PersonalRecord::Cps, self.best_cps as u64, result.max_cps_observed as u64));
+            self.best_cps = result.max_cps_observed;
+        }
+        if result.clicks > self.best_session_clicks {
+            beaten[1] = Some((PersonalRecord::SessionClicks, self.best_session_clicks as u64, result.clicks as u64));
+            self.best_session_clicks = result.clicks;
+        }
+        if self.clicks_today > self.best_day_clicks {
+            beaten[2] = Some((PersonalRecord::DayClicks, self.best_day_clicks, self.clicks_today));
+            self.best_day_clicks = self.clicks_today;
+        }
+        beaten
+    }
+
+    pub fn active_session(&self) -> Option<Pubkey> {
+        (self.flags & Self::FLAG_ACTIVE_SESSION != 0).then_some(self.active_session)
+    }
+
+    pub fn set_active_session(&mut self, session: Option<Pubkey>) {
+        self.set_flag(Self::FLAG_ACTIVE_SESSION, session.is_some());
+        self.active_session = session.unwrap_or_default();
+    }
+
+    pub fn recovery_owner(&self) -> Option<Pubkey> {
+        (self.flags & Self::FLAG_RECOVERY_PENDING != 0).then_some(self.recovery_owner)
+    }
+
+    pub fn set_recovery_owner(&mut self, owner: Option<Pubkey>) {
+        self.set_flag(Self::FLAG_RECOVERY_PENDING, owner.is_some());
+        self.recovery_owner = owner.unwrap_or_default();
+    }
+
+    pub fn is_hardcore(&self) -> bool {
+        self.flags & Self::FLAG_HARDCORE != 0
+    }
+
+    pub fn has_fallen(&self) -> bool {
+        self.flags & Self::FLAG_FALLEN != 0
+    }
+
+    pub fn has_session_index(&self) -> bool {
+        self.flags & Self::FLAG_SESSION_INDEX != 0
+    }
+
+    pub fn set_session_index(&mut self) {
+        self.set_flag(Self::FLAG_SESSION_INDEX, true);
+    }
+
+    /// Locks a hardcore game for good.
+    pub fn fall(&mut self) {
+        self.set_flag(Self::FLAG_FALLEN, true);
+        self.set_active_session(None);
+    }
+
+    fn set_flag(&mut self, flag: u8, on: bool) {
+        if on {
+            self.flags |= flag;
+        } else {
+            self.flags &= !flag;
+        }
+    }
+
+    pub fn lose_reputation(&mut self, penalty: u32) {
+        self.reputation = self.reputation.saturating_sub(penalty);
+    }
+
+    pub fn available_skill_points(&self) -> u32 {
+        (self.level * Self::SKILL_POINTS_PER_LEVEL).saturating_sub(self.skill_points_spent)
+    }
+
+    pub fn gems_bought_on(&self, day: i64) -> u64 {
+        if self.gem_purchase_day == day {
+            self.gems_bought_today
+        } else {
+            0
+        }
+    }
+
+    pub fn offers_redeemed_on(&self, day: i64) -> u32 {
+        if self.offer_day == day {
+            self.offers_today
+        } else {
+            0
+        }
+    }
+
+    pub fn has_identity(&self) -> bool {
+        self.identity_hash != [0u8; 32]
+    }
+
+    pub fn has_device(&self, device: &Pubkey) -> bool {
+        *device != Pubkey::default() && self.devices.contains(device)
+    }
+
+    /// Multiplier applied to revealed clicks: the partner and offer boosts,
+    /// each while it lasts. The partner boost also needs `partner_active`,
+    /// whether its collection is still active. Hardcore games take none.
+    pub fn click_multiplier(&self, now: i64, partner_active: bool) -> Option<Fixed> {
+        if self.is_hardcore() {
+            return Some(Fixed::ONE);
+        }
+        let partner = if partner_active
+            && self.partner_multiplier_bps > 0
+            && now < self.partner_multiplier_expires_at
+        {
+            Fixed::from_bps(self.partner_multiplier_bps)
+        } else {
+            Fixed::ONE
+        };
+        let offer = if self.offer_boost_bps > 0 && now < self.offer_boost_expires_at {
+            Fixed::from_bps(self.offer_boost_bps)
+        } else {
+            Fixed::ONE
+        };
+        partner.checked_mul(offer)
+    }
+
+    pub fn is_guardian(&self, key: &Pubkey) -> bool {
+        *key != Pubkey::default() && self.guardians.contains(key)
+    }
+}
+
+// Newton's method; u64::isqrt is newer than the SBF toolchain's rustc
+fn integer_sqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = n;
+    let mut y = (x + n / x) / 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+/// A personal best tracked on Game.
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PersonalRecord {
+    Cps,
+    SessionClicks,
+    DayClicks,
+    TimeAttackClicks,
+    EnduranceClicks,
+}
+
+impl AccountLayout for Game {
+    const ACCOUNT_DISCRIMINATOR: &'static [u8] = discriminators::GAME;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memcmp_offsets_match_the_layout() {
+        let game = Game {
+            lifetime_clicks: 0x0102_0304_0506_0708,
+            flags: Game::FLAG_FIXED_LAYOUT,
+            active_session: Pubkey::new_from_array([7; 32]),
+            ..Game::default()
+        };
+        let mut data = discriminators::GAME.to_vec();
+        data.extend(borsh::BorshSerialize::try_to_vec(&game).unwrap());
+
+        assert_eq!(
+            data[Game::LIFETIME_CLICKS_OFFSET..][..8],
+            0x0102_0304_0506_0708u64.to_le_bytes()
+        );
+        assert_eq!(data[Game::FLAGS_OFFSET], Game::FLAG_FIXED_LAYOUT);
+        assert_eq!(data[Game::ACTIVE_SESSION_OFFSET..][..32], [7; 32]);
+        assert_eq!(data.len(), 8 + Game::MAXIMUM_SIZE);
+    }
+
+    #[test]
+    fn soft_reset_keeps_the_lifetime_score() {
+        let mut game = Game::default();
+        game.credit_clicks(500);
+        game.clicks = 12;
+        game.soft_reset();
+
+        assert_eq!(game.lifetime_clicks, 500);
+        assert_eq!(game.spendable_clicks, 0);
+        assert_eq!(game.clicks, 0);
+    }
+}
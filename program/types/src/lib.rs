@@ -0,0 +1,46 @@
+//! Account layouts, PDA seeds and shared constants of the clicker program,
+//! for indexers and bots that read its accounts without building the
+//! program.
+//!
+//! With the `anchor` feature the account types are real Anchor accounts,
+//! which is how the program uses them. Without it they are plain Borsh
+//! structs, and [`AccountLayout::try_from_account_data`] decodes raw account
+//! data.
+//!
+//! Events stay in the program crate, because Anchor only lists events in the
+//! IDL when the program declares them itself.
+
+use borsh::BorshDeserialize;
+pub use solana_pubkey::Pubkey;
+
+pub mod constants;
+pub mod discriminators;
+pub mod math;
+pub mod seeds;
+
+mod config;
+mod game;
+mod session;
+
+pub use config::*;
+pub use game::*;
+pub use session::*;
+
+// Must match declare_id! in the program; #[account] checks owners against it
+#[cfg(feature = "anchor")]
+anchor_lang::declare_id!("GwY9aAMD8nxhZxuTtPBbsFfgiqsVGkRTeA5fRyDjNkdM");
+#[cfg(not(feature = "anchor"))]
+pub const ID: Pubkey = Pubkey::from_str_const("GwY9aAMD8nxhZxuTtPBbsFfgiqsVGkRTeA5fRyDjNkdM");
+
+/// A program account type and the 8-byte discriminator its data starts with.
+pub trait AccountLayout: BorshDeserialize {
+    const ACCOUNT_DISCRIMINATOR: &'static [u8];
+
+    /// Decodes raw account data, or None if it isn't this type of account.
+    /// Trailing bytes (accounts are allocated at their maximum size) are
+    /// ignored.
+    fn try_from_account_data(data: &[u8]) -> Option<Self> {
+        let mut body = data.strip_prefix(Self::ACCOUNT_DISCRIMINATOR)?;
+        Self::deserialize(&mut body).ok()
+    }
+}
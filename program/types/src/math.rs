@@ -0,0 +1,154 @@
+//! Q32.32 fixed-point numbers for multipliers.
+//!
+//! Boosts are configured in basis points, but chaining them in basis points
+//! rounds after every factor. A [`Fixed`] keeps 32 fractional bits and
+//! rounds by one rule: multipliers round up, by less than 2^-32 per step,
+//! and the amounts they scale round down. A ratio that is exact in basis
+//! points therefore never costs a click, and a player can never be credited
+//! a fraction of one.
+//!
+//! Every operation that can overflow is checked and returns None.
+
+use crate::constants::BPS_ONE;
+
+/// An unsigned Q32.32 number: the value is `bits / 2^32`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed(u64);
+
+impl Fixed {
+    pub const FRACTIONAL_BITS: u32 = 32;
+    pub const ZERO: Fixed = Fixed(0);
+    pub const ONE: Fixed = Fixed(1 << Self::FRACTIONAL_BITS);
+
+    pub const fn from_bits(bits: u64) -> Self {
+        Fixed(bits)
+    }
+
+    pub const fn to_bits(self) -> u64 {
+        self.0
+    }
+
+    pub const fn from_int(n: u32) -> Self {
+        Fixed((n as u64) << Self::FRACTIONAL_BITS)
+    }
+
+    /// `bps` basis points, rounded up. Any u16 fits.
+    pub fn from_bps(bps: u16) -> Self {
+        Fixed(((bps as u64) << Self::FRACTIONAL_BITS).div_ceil(BPS_ONE as u64))
+    }
+
+    /// `numerator / denominator`, rounded up. None dividing by zero or if the
+    /// integer part passes u32::MAX.
+    pub fn from_ratio(numerator: u64, denominator: u64) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let bits = ((numerator as u128) << Self::FRACTIONAL_BITS).div_ceil(denominator as u128);
+        u64::try_from(bits).ok().map(Fixed)
+    }
+
+    /// Product of two multipliers, rounded up.
+    pub fn checked_mul(self, rhs: Fixed) -> Option<Self> {
+        let bits = (self.0 as u128 * rhs.0 as u128).div_ceil(1 << Self::FRACTIONAL_BITS);
+        u64::try_from(bits).ok().map(Fixed)
+    }
+
+    /// Quotient of two multipliers, rounded up. None dividing by zero.
+    pub fn checked_div(self, rhs: Fixed) -> Option<Self> {
+        if rhs.0 == 0 {
+            return None;
+        }
+        let bits = ((self.0 as u128) << Self::FRACTIONAL_BITS).div_ceil(rhs.0 as u128);
+        u64::try_from(bits).ok().map(Fixed)
+    }
+
+    /// `amount` scaled by this multiplier, rounded down.
+    pub fn apply(self, amount: u64) -> Option<u64> {
+        u64::try_from((amount as u128 * self.0 as u128) >> Self::FRACTIONAL_BITS).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Fixed::from_int(3).to_bits(), 3 << 32);
+        assert_eq!(Fixed::from_bits(12345).to_bits(), 12345);
+        assert_eq!(Fixed::from_int(0), Fixed::ZERO);
+        assert_eq!(Fixed::from_int(1), Fixed::ONE);
+        assert_eq!(Fixed::from_bps(BPS_ONE), Fixed::ONE);
+        assert_eq!(Fixed::from_bps(15_000), Fixed::from_bits(3 << 31));
+        // 2^32 / 10_000 = 429_496.7296
+        assert_eq!(Fixed::from_bps(1).to_bits(), 429_497);
+        assert!(Fixed::from_bps(u16::MAX) > Fixed::from_int(6));
+        assert!(Fixed::from_bps(u16::MAX) < Fixed::from_int(7));
+    }
+
+    #[test]
+    fn from_ratio_rounds_up_and_checks_range() {
+        assert_eq!(Fixed::from_ratio(3, 2), Some(Fixed::from_bps(15_000)));
+        // 2^32 / 3 = 1_431_655_765.33
+        assert_eq!(
+            Fixed::from_ratio(1, 3),
+            Some(Fixed::from_bits(1_431_655_766))
+        );
+        assert_eq!(Fixed::from_ratio(1, 0), None);
+        assert_eq!(
+            Fixed::from_ratio(u32::MAX as u64, 1),
+            Some(Fixed::from_int(u32::MAX))
+        );
+        assert_eq!(Fixed::from_ratio(u32::MAX as u64 + 1, 1), None);
+    }
+
+    #[test]
+    fn checked_mul() {
+        assert_eq!(
+            Fixed::from_int(2).checked_mul(Fixed::from_bps(15_000)),
+            Some(Fixed::from_int(3))
+        );
+        assert_eq!(Fixed::ONE.checked_mul(Fixed::ZERO), Some(Fixed::ZERO));
+        // 2^-64 rounds up to the smallest step
+        assert_eq!(
+            Fixed::from_bits(1).checked_mul(Fixed::from_bits(1)),
+            Some(Fixed::from_bits(1))
+        );
+        assert_eq!(
+            Fixed::from_int(u32::MAX).checked_mul(Fixed::ONE),
+            Some(Fixed::from_int(u32::MAX))
+        );
+        assert_eq!(
+            Fixed::from_int(1 << 16).checked_mul(Fixed::from_int(1 << 16)),
+            None
+        );
+    }
+
+    #[test]
+    fn checked_div() {
+        assert_eq!(
+            Fixed::from_int(3).checked_div(Fixed::from_int(2)),
+            Some(Fixed::from_bps(15_000))
+        );
+        assert_eq!(
+            Fixed::ONE.checked_div(Fixed::from_int(3)),
+            Fixed::from_ratio(1, 3)
+        );
+        assert_eq!(Fixed::ONE.checked_div(Fixed::ZERO), None);
+        assert_eq!(
+            Fixed::from_int(u32::MAX).checked_div(Fixed::from_bits(1)),
+            None
+        );
+    }
+
+    #[test]
+    fn apply_rounds_down_and_checks_range() {
+        assert_eq!(Fixed::from_bps(15_000).apply(3), Some(4));
+        assert_eq!(Fixed::ZERO.apply(u64::MAX), Some(0));
+        assert_eq!(Fixed::ONE.apply(u64::MAX), Some(u64::MAX));
+        assert_eq!(Fixed::from_int(2).apply(u64::MAX), None);
+        // a ratio exact in basis points never costs a click
+        assert_eq!(Fixed::from_bps(1).apply(10_000), Some(1));
+        assert_eq!(Fixed::from_bps(12_500).apply(8), Some(10));
+    }
+}
@@ -0,0 +1,69 @@
+//! Seed prefixes of the program's PDAs. Seeds after the prefix are noted
+//! per constant; integers are little-endian.
+
+pub const CONFIG: &[u8] = b"config";
+pub const GLOBAL_STATS: &[u8] = b"global_stats";
+/// + period (u64)
+pub const RAFFLE: &[u8] = b"raffle";
+/// + game
+pub const SNAPSHOT: &[u8] = b"snapshot";
+/// + collection mint
+pub const PARTNER_COLLECTION: &[u8] = b"partner";
+/// + NFT mint
+pub const PARTNER_NFT: &[u8] = b"partner_nft";
+/// + quest_id (u32)
+pub const PARTNER_QUEST: &[u8] = b"quest";
+/// + quest + game
+pub const QUEST_COMPLETION: &[u8] = b"quest_done";
+/// + chain_id (u32)
+pub const QUEST_CHAIN: &[u8] = b"quest_chain";
+/// + chain + game
+pub const QUEST_CHAIN_PROGRESS: &[u8] = b"quest_chain_progress";
+pub const SKILL_TREE: &[u8] = b"skill_tree";
+pub const TREASURY: &[u8] = b"treasury";
+/// + game + purchase index (u64)
+pub const RECEIPT: &[u8] = b"receipt";
+pub const SESSION_ARCHIVE: &[u8] = b"session_archive";
+pub const WORLD_RECORD: &[u8] = b"world_record";
+/// + mentee game
+pub const MENTORSHIP: &[u8] = b"mentorship";
+/// + boss_id (u32)
+pub const WORLD_BOSS: &[u8] = b"world_boss";
+/// + boss + game
+pub const BOSS_CONTRIBUTION: &[u8] = b"boss_damage";
+pub const RAID_SCHEDULE: &[u8] = b"raid_schedule";
+/// + offer_id (u32)
+pub const OFFER: &[u8] = b"offer";
+/// + offer + game
+pub const OFFER_REDEMPTION: &[u8] = b"offer_redeemed";
+/// + season_id (u32)
+pub const PASS_SEASON: &[u8] = b"pass_season";
+/// + season + game
+pub const SEASON_PASS: &[u8] = b"season_pass";
+/// + game
+pub const LIVE_STATE: &[u8] = b"live";
+/// + game
+pub const ATTESTATION: &[u8] = b"attestation";
+/// + game
+pub const INVENTORY: &[u8] = b"inventory";
+/// + recipe_id (u32)
+pub const RECIPE: &[u8] = b"recipe";
+/// + game + listing index (u64)
+pub const LISTING: &[u8] = b"listing";
+/// + host session
+pub const COOP_SESSION: &[u8] = b"coop";
+/// + game
+pub const SESSION_INDEX: &[u8] = b"session_index";
+pub const ADMIN_RECORD: &[u8] = b"admin_record";
+/// + window_id (u32)
+pub const GOLDEN_WINDOW: &[u8] = b"golden_window";
+/// + game
+pub const TROPHY_CABINET: &[u8] = b"trophy_cabinet";
+/// + challenge_id (u32)
+pub const WEEKLY_CHALLENGE: &[u8] = b"weekly_challenge";
+/// + challenge + game
+pub const CHALLENGE_ENTRY: &[u8] = b"challenge_entry";
+/// + sender game + recipient game
+pub const GIFT: &[u8] = b"gift";
+/// + season
+pub const SHOUTBOX: &[u8] = b"shoutbox";
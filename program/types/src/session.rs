@@ -0,0 +1,140 @@
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::*;
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_pubkey::Pubkey;
+
+use crate::{discriminators, AccountLayout};
+
+#[cfg_attr(feature = "anchor", account(discriminator = discriminators::SESSION))]
+#[cfg_attr(not(feature = "anchor"), derive(Clone, BorshSerialize, BorshDeserialize))]
+#[derive(Debug, Default)]
+pub struct Session {
+    pub player: Pubkey,             // 32 bytes
+    pub game: Pubkey,               // 32 bytes
+    pub commitment: [u8; 32],       // 32 bytes - hash of (result, nonce, player[, challenge])
+    pub start_time: i64,            // 8 bytes
+    pub end_time: i64,              // 8 bytes
+    pub actual_clicks: u32,         // 4 bytes
+    pub revealed: bool,             // 1 byte
+    pub raffle: Pubkey,             // 32 bytes - raffle credited at reveal, default if none
+    pub raffle_ticket_start: u64,   // 8 bytes - first ticket number owned by this session
+    pub raffle_tickets: u64,        // 8 bytes
+    pub challenge: Option<[u8; 32]>,    // 1 + 32 bytes - attestor-issued, part of the reveal preimage
+    pub deadline: i64,              // 8 bytes - expires after this, snapshot of the config at start
+    pub result: SessionResult,      // SessionResult::LEN bytes - revealed metrics, actual_clicks repeats result.clicks
+    pub paused_at: i64,             // 8 bytes - start of the current pause, 0 if running
+    pub paused_total: i64,          // 8 bytes - seconds of finished pauses, at most MAX_PAUSED_SECONDS
+    pub extended_seconds: i64,      // 8 bytes - bought with gems, already added to deadline
+    pub void_reason: Option<VoidReason>,    // 1 + 1 bytes - set when an admin voided the session
+    pub rent_payer: Pubkey,         // 32 bytes - paid for this account, refunded by close_session
+    pub time_attack_seconds: i64,   // 8 bytes - window of a time attack, 0 for a regular session
+    pub endurance: bool,            // 1 byte - credit decays past Config.endurance_optimal_seconds
+    pub coop: Pubkey,               // 32 bytes - CoopSession it belongs to, default if played alone
+    pub credited_clicks: u64,       // 8 bytes - added to the game's total at reveal, boosts included
+    pub gamble: Option<GambleState>,    // 1 + 1 bytes - set once gamble_session stakes the credit
+    pub weekly_challenge: Pubkey,   // 32 bytes - WeeklyChallenge the session entered, default if none
+    pub gamble_slot: u64,           // 8 bytes - slot gamble_session landed in, part of the draw's seed
+}
+
+impl Session {
+    pub const MAXIMUM_SIZE: usize = 32 + 32 + 32 + 8 + 8 + 4 + 1 + 32 + 8 + 8 + 1 + 32 + 8 + SessionResult::LEN + 8 + 8 + 8 + 1 + 1 + 32 + 8 + 1 + 32 + 8 + 1 + 1 + 32 + 8;
+    pub const MAX_PAUSED_SECONDS: i64 = 30 * 60;
+    pub const MAX_EXTENSION_SECONDS: i64 = 4 * 60 * 60;
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        now > self.deadline
+    }
+
+    pub fn is_time_attack(&self) -> bool {
+        self.time_attack_seconds > 0
+    }
+
+    pub fn is_coop(&self) -> bool {
+        self.coop != Pubkey::default()
+    }
+
+    pub fn in_weekly_challenge(&self) -> bool {
+        self.weekly_challenge != Pubkey::default()
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused_at != 0
+    }
+
+    /// Seconds excluded from the rate checks as of `now`, counting a pause
+    /// still in progress, up to MAX_PAUSED_SECONDS.
+    pub fn paused_seconds(&self, now: i64) -> i64 {
+        let current = if self.is_paused() { now - self.paused_at } else { 0 };
+        (self.paused_total + current).min(Self::MAX_PAUSED_SECONDS)
+    }
+}
+
+/// Why support voided a session with admin_void_session.
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VoidReason {
+    ClientBug,
+    StuckSession,
+    SuspectedCheating,
+    PlayerRequest,
+    Other,
+}
+
+/// Where a session's double-or-nothing gamble stands.
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GambleState {
+    Pending,
+    Won,
+    Lost,
+}
+
+/// What a player reveals in end_session. Its Borsh encoding opens the
+/// commitment from start_session.
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SessionResult {
+    pub clicks: u32,
+    pub combos: u32,            // at most one per click
+    pub max_combo: u32,         // longest run of consecutive combos, at most combos
+    pub max_cps_observed: u16,  // fastest second of the session
+    pub misses: u32,            // taps that missed; they count toward the rate cap
+    pub minute_clicks: [u16; SessionResult::HISTOGRAM_MINUTES],  // clicks per minute, the last bucket takes the rest
+}
+
+impl SessionResult {
+    pub const HISTOGRAM_MINUTES: usize = clicker_commitment::HISTOGRAM_MINUTES;
+    pub const LEN: usize = clicker_commitment::RESULT_LEN;
+
+    pub fn histogram_total(&self) -> u64 {
+        self.minute_clicks.iter().map(|&clicks| clicks as u64).sum()
+    }
+
+    /// Each histogram bucket with the seconds of a `duration`-second session
+    /// it covers: 60 for full minutes, less for the one the session ended
+    /// in, 0 after that. The last bucket covers everything from its start.
+    pub fn minutes(&self, duration: i64) -> impl Iterator<Item = (u16, i64)> + '_ {
+        let last = Self::HISTOGRAM_MINUTES - 1;
+        self.minute_clicks.iter().enumerate().map(move |(minute, &clicks)| {
+            let remaining = (duration - minute as i64 * 60).max(0);
+            let seconds = if minute == last { remaining } else { remaining.min(60) };
+            (clicks, seconds)
+        })
+    }
+
+    /// The Borsh encoding, without allocating.
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut bytes = [0u8; Self::LEN];
+        self.serialize(&mut &mut bytes[..])
+            .expect("SessionResult encodes to exactly LEN bytes");
+        bytes
+    }
+}
+
+impl AccountLayout for Session {
+    const ACCOUNT_DISCRIMINATOR: &'static [u8] = discriminators::SESSION;
+}